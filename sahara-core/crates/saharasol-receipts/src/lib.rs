@@ -0,0 +1,475 @@
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::AccountDeserialize;
+use saharasol_core::state::{DonationRecord, FundPool, NGO};
+use saharasol_core::utils::civil_date;
+use serde::Serialize;
+use std::fmt;
+
+#[derive(Debug)]
+pub enum ReceiptError {
+    NotIssued,
+    PoolMismatch,
+    NgoMismatch,
+    InvalidAccountData(String),
+}
+
+impl fmt::Display for ReceiptError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReceiptError::NotIssued => write!(f, "No receipt has been issued for this donation"),
+            ReceiptError::PoolMismatch => write!(f, "Donation was not made to this pool"),
+            ReceiptError::NgoMismatch => write!(f, "Pool is not managed by this NGO"),
+            ReceiptError::InvalidAccountData(err) => write!(f, "Invalid account data: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for ReceiptError {}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Receipt {
+    pub receipt_number: String,
+    pub fiscal_year: u16,
+    pub issued_at: i64,
+    pub issued_on: String,
+
+    pub ngo: String,
+    pub ngo_name: String,
+    pub ngo_tax_id: String,
+    pub ngo_registration_number: String,
+    pub ngo_address: String,
+    pub ngo_email: String,
+
+    pub is_anonymous: bool,
+    pub donor: Option<String>,
+    pub donor_commitment: Option<String>,
+    pub donor_name: Option<String>,
+    pub donor_email: Option<String>,
+
+    pub donation_record: String,
+    pub disaster_id: String,
+    pub pool: String,
+    pub pool_name: String,
+    pub token_mint: String,
+    pub token_decimals: u8,
+    pub amount: u64,
+    pub platform_fee: u64,
+    pub net_amount: u64,
+    pub refunded_amount: u64,
+    pub receipt_amount: u64,
+    pub donated_at: i64,
+    pub donated_on: String,
+    pub message: String,
+}
+
+impl Receipt {
+    pub fn from_accounts(
+        donation_record_key: Pubkey,
+        donation_record: &DonationRecord,
+        ngo: &NGO,
+        pool: &FundPool,
+        token_decimals: u8,
+    ) -> Result<Self, ReceiptError> {
+        let (true, Some(receipt_number), Some(ngo_tax_id), Some(fiscal_year), Some(issued_at)) = (
+            donation_record.receipt_sent,
+            donation_record.receipt_number.clone(),
+            donation_record.receipt_tax_id.clone(),
+            donation_record.receipt_fiscal_year,
+            donation_record.receipt_issued_at,
+        ) else {
+            return Err(ReceiptError::NotIssued);
+        };
+
+        let (pool_key, _) = Pubkey::find_program_address(
+            &[
+                b"pool",
+                pool.disaster_id.as_bytes(),
+                pool.pool_id.as_bytes(),
+            ],
+            &saharasol_core::ID,
+        );
+        if donation_record.pool != Some(pool_key) {
+            return Err(ReceiptError::PoolMismatch);
+        }
+
+        if pool.authority != ngo.authority {
+            return Err(ReceiptError::NgoMismatch);
+        }
+        let (ngo_key, _) =
+            Pubkey::find_program_address(&[b"ngo", ngo.authority.as_ref()], &saharasol_core::ID);
+
+        let receipt_amount = donation_record
+            .net_amount
+            .saturating_sub(donation_record.refunded_amount);
+
+        Ok(Self {
+            receipt_number,
+            fiscal_year,
+            issued_at,
+            issued_on: format_date(issued_at),
+            ngo: ngo_key.to_string(),
+            ngo_name: ngo.name.clone(),
+            ngo_tax_id,
+            ngo_registration_number: ngo.registration_number.clone(),
+            ngo_address: ngo.address.clone(),
+            ngo_email: ngo.email.clone(),
+            is_anonymous: donation_record.is_anonymous,
+            donor: (!donation_record.is_anonymous).then(|| donation_record.donor.to_string()),
+            donor_commitment: donation_record.donor_commitment.map(|c| to_hex(&c)),
+            donor_name: donation_record.donor_name.clone(),
+            donor_email: donation_record.donor_email.clone(),
+            donation_record: donation_record_key.to_string(),
+            disaster_id: donation_record.disaster_id.clone(),
+            pool: pool_key.to_string(),
+            pool_name: pool.name.clone(),
+            token_mint: donation_record.token_mint.to_string(),
+            token_decimals,
+            amount: donation_record.amount,
+            platform_fee: donation_record.platform_fee,
+            net_amount: donation_record.net_amount,
+            refunded_amount: donation_record.refunded_amount,
+            receipt_amount,
+            donated_at: donation_record.timestamp,
+            donated_on: format_date(donation_record.timestamp),
+            message: donation_record.message.clone(),
+        })
+    }
+
+    pub fn from_account_data(
+        donation_record_key: Pubkey,
+        donation_record_data: &[u8],
+        ngo_data: &[u8],
+        pool_data: &[u8],
+        token_decimals: u8,
+    ) -> Result<Self, ReceiptError> {
+        let donation_record = deserialize::<DonationRecord>(donation_record_data)?;
+        let ngo = deserialize::<NGO>(ngo_data)?;
+        let pool = deserialize::<FundPool>(pool_data)?;
+
+        Self::from_accounts(
+            donation_record_key,
+            &donation_record,
+            &ngo,
+            &pool,
+            token_decimals,
+        )
+    }
+
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut lines = vec![
+            "DONATION RECEIPT".to_string(),
+            format!("Receipt number: {}", self.receipt_number),
+            format!("Fiscal year: {}", self.fiscal_year),
+            format!("Issued on: {}", self.issued_on),
+            String::new(),
+            "Issued by".to_string(),
+            format!("  {}", self.ngo_name),
+            format!("  Tax ID: {}", self.ngo_tax_id),
+            format!("  Registration number: {}", self.ngo_registration_number),
+        ];
+
+        if !self.ngo_address.is_empty() {
+            lines.push(format!("  {}", self.ngo_address));
+        }
+        if !self.ngo_email.is_empty() {
+            lines.push(format!("  {}", self.ngo_email));
+        }
+
+        lines.push(String::new());
+        lines.push("Donor".to_string());
+        match (&self.donor_name, &self.donor) {
+            (Some(name), _) => lines.push(format!("  {}", name)),
+            (None, Some(donor)) => lines.push(format!("  Wallet: {}", donor)),
+            (None, None) => lines.push("  Anonymous donor".to_string()),
+        }
+        if let Some(email) = &self.donor_email {
+            lines.push(format!("  {}", email));
+        }
+        if let (Some(_), Some(donor)) = (&self.donor_name, &self.donor) {
+            lines.push(format!("  Wallet: {}", donor));
+        }
+        if let Some(commitment) = &self.donor_commitment {
+            lines.push(format!("  Donor commitment: {}", commitment));
+        }
+
+        lines.push(String::new());
+        lines.push("Donation".to_string());
+        lines.push(format!("  Date: {}", self.donated_on));
+        lines.push(format!("  Disaster: {}", self.disaster_id));
+        lines.push(format!("  Pool: {}", self.pool_name));
+        lines.push(format!("  Token mint: {}", self.token_mint));
+        lines.push(format!("  Amount donated: {}", self.format(self.amount)));
+        lines.push(format!(
+            "  Platform fee: {}",
+            self.format(self.platform_fee)
+        ));
+        if self.refunded_amount > 0 {
+            lines.push(format!("  Refunded: {}", self.format(self.refunded_amount)));
+        }
+        lines.push(format!(
+            "  Receipt amount: {}",
+            self.format(self.receipt_amount)
+        ));
+        if !self.message.is_empty() {
+            lines.push(format!("  Message: {}", self.message));
+        }
+
+        lines.push(String::new());
+        lines.push(format!("Donation record: {}", self.donation_record));
+
+        lines.join("\n") + "\n"
+    }
+
+    fn format(&self, amount: u64) -> String {
+        format_amount(amount, self.token_decimals)
+    }
+}
+
+pub fn format_amount(amount: u64, decimals: u8) -> String {
+    if decimals == 0 {
+        return amount.to_string();
+    }
+
+    let scale = 10u128.pow(decimals as u32);
+    let whole = amount as u128 / scale;
+    let fraction = amount as u128 % scale;
+    format!("{}.{:0width$}", whole, fraction, width = decimals as usize)
+}
+
+pub fn format_date(timestamp: i64) -> String {
+    let (year, month, day) = civil_date(timestamp);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn deserialize<T: AccountDeserialize>(data: &[u8]) -> Result<T, ReceiptError> {
+    let mut data = data;
+    T::try_deserialize(&mut data).map_err(|err| ReceiptError::InvalidAccountData(err.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::{AccountSerialize, Discriminator};
+    use saharasol_core::state::{DistributionType, DonationType};
+
+    const DISASTER_ID: &str = "FLOOD-2023";
+    const POOL_ID: &str = "RELIEF-1";
+    const ISSUED_AT: i64 = 1_700_000_000;
+
+    fn ngo(authority: Pubkey) -> NGO {
+        NGO {
+            authority,
+            name: "Relief Nepal".to_string(),
+            registration_number: "REG-001".to_string(),
+            email: "contact@reliefnepal.org".to_string(),
+            phone_number: String::new(),
+            website: String::new(),
+            description: String::new(),
+            address: "Kathmandu".to_string(),
+            is_verified: true,
+            is_active: true,
+            field_workers_count: 0,
+            beneficiaries_registered: 0,
+            pools_created: 1,
+            total_aid_distributed: 0,
+            verification_documents: String::new(),
+            operating_districts: vec![],
+            focus_areas: vec![],
+            registered_at: ISSUED_AT,
+            verified_at: Some(ISSUED_AT),
+            verified_by: None,
+            last_activity_at: ISSUED_AT,
+            contact_person_name: String::new(),
+            contact_person_role: String::new(),
+            bank_account_info: String::new(),
+            tax_id: "TAX-123".to_string(),
+            notes: String::new(),
+            is_blacklisted: false,
+            blacklist_reason: String::new(),
+            blacklisted_at: None,
+            blacklisted_by: None,
+            bump: 255,
+            activity_count: 0,
+        }
+    }
+
+    fn pool(authority: Pubkey, token_mint: Pubkey) -> FundPool {
+        FundPool {
+            pool_id: POOL_ID.to_string(),
+            disaster_id: DISASTER_ID.to_string(),
+            name: "Flood Relief".to_string(),
+            authority,
+            token_mint,
+            token_account: Pubkey::new_unique(),
+            distribution_type: DistributionType::Equal,
+            total_deposited: 10_500_000,
+            total_distributed: 0,
+            total_claimed: 0,
+            beneficiary_count: 0,
+            total_allocation_weight: 0,
+            donor_count: 1,
+            time_lock_duration: None,
+            distribution_percentage_immediate: 100,
+            distribution_percentage_locked: 0,
+            eligibility_criteria: String::new(),
+            is_active: true,
+            is_distributed: false,
+            created_at: ISSUED_AT,
+            distributed_at: None,
+            closed_at: None,
+            minimum_family_size: None,
+            minimum_damage_severity: None,
+            target_amount: None,
+            description: String::new(),
+            registration_locked: false,
+            expected_beneficiary_count: None,
+            registered_beneficiary_count: 0,
+            bump: 255,
+            reclaimed_amount: 0,
+            expired_allocation_weight: 0,
+            redistribution_round: 0,
+            redistribution_amount: 0,
+            redistribution_claimed: 0,
+            redistribution_weight: 0,
+            donor_refund_total: 0,
+            donor_refunded: 0,
+            total_transferred_in: 0,
+            total_transferred_out: 0,
+            claim_window_seconds: 0,
+            vesting_schedule: vec![],
+            activity_count: 0,
+            total_deposited_usd: 0,
+            total_claimed_usd: 0,
+        }
+    }
+
+    fn donation_record(donor: Pubkey, pool: Pubkey, token_mint: Pubkey) -> DonationRecord {
+        DonationRecord {
+            donor,
+            recipient: pool,
+            donation_type: DonationType::Pool,
+            amount: 11_000_000,
+            token_mint,
+            disaster_id: DISASTER_ID.to_string(),
+            pool: Some(pool),
+            transaction_signature: String::new(),
+            timestamp: ISSUED_AT,
+            is_anonymous: false,
+            message: "Stay strong".to_string(),
+            platform_fee: 500_000,
+            net_amount: 10_500_000,
+            donor_name: Some("Asha".to_string()),
+            donor_email: None,
+            receipt_sent: true,
+            bump: 255,
+            refunded_amount: 0,
+            is_refunded: false,
+            receipt_number: Some("REG-001-2023-000001".to_string()),
+            receipt_tax_id: Some("TAX-123".to_string()),
+            receipt_fiscal_year: Some(2023),
+            receipt_issued_at: Some(ISSUED_AT),
+            donor_commitment: None,
+            activity_count: 1,
+        }
+    }
+
+    fn pool_key() -> Pubkey {
+        Pubkey::find_program_address(
+            &[b"pool", DISASTER_ID.as_bytes(), POOL_ID.as_bytes()],
+            &saharasol_core::ID,
+        )
+        .0
+    }
+
+    fn serialize<T: AccountSerialize>(account: &T) -> Vec<u8> {
+        let mut data = Vec::new();
+        account.try_serialize(&mut data).unwrap();
+        data
+    }
+
+    #[test]
+    fn renders_issued_receipt() {
+        let ngo_authority = Pubkey::new_unique();
+        let donor = Pubkey::new_unique();
+        let token_mint = Pubkey::new_unique();
+        let record_key = Pubkey::new_unique();
+
+        let receipt = Receipt::from_account_data(
+            record_key,
+            &serialize(&donation_record(donor, pool_key(), token_mint)),
+            &serialize(&ngo(ngo_authority)),
+            &serialize(&pool(ngo_authority, token_mint)),
+            6,
+        )
+        .unwrap();
+
+        assert_eq!(receipt.receipt_number, "REG-001-2023-000001");
+        assert_eq!(receipt.issued_on, "2023-11-14");
+        assert_eq!(receipt.ngo_tax_id, "TAX-123");
+        assert_eq!(receipt.pool, pool_key().to_string());
+        assert_eq!(receipt.donor, Some(donor.to_string()));
+        assert_eq!(receipt.receipt_amount, 10_500_000);
+
+        let text = receipt.to_text();
+        assert!(text.starts_with("DONATION RECEIPT\n"));
+        assert!(text.contains("  Relief Nepal\n"));
+        assert!(text.contains("  Asha\n"));
+        assert!(text.contains(&format!("  Wallet: {}\n", donor)));
+        assert!(text.contains("  Amount donated: 11.000000\n"));
+        assert!(text.contains("  Receipt amount: 10.500000\n"));
+        assert!(text.ends_with(&format!("Donation record: {}\n", record_key)));
+
+        let json: serde_json::Value = serde_json::from_str(&receipt.to_json().unwrap()).unwrap();
+        assert_eq!(json["receipt_number"], "REG-001-2023-000001");
+        assert_eq!(json["receipt_amount"], 10_500_000);
+    }
+
+    #[test]
+    fn rejects_receipt_from_another_ngo() {
+        let token_mint = Pubkey::new_unique();
+        let record = donation_record(Pubkey::new_unique(), pool_key(), token_mint);
+        let pool = pool(Pubkey::new_unique(), token_mint);
+
+        let result = Receipt::from_accounts(
+            Pubkey::new_unique(),
+            &record,
+            &ngo(Pubkey::new_unique()),
+            &pool,
+            6,
+        );
+
+        assert!(matches!(result, Err(ReceiptError::NgoMismatch)));
+    }
+
+    #[test]
+    fn rejects_ngo_data_with_bad_discriminator() {
+        let ngo_authority = Pubkey::new_unique();
+        let token_mint = Pubkey::new_unique();
+
+        let mut ngo_data = serialize(&ngo(ngo_authority));
+        assert_eq!(&ngo_data[..8], NGO::DISCRIMINATOR);
+        ngo_data[..8].copy_from_slice(&[0; 8]);
+
+        let result = Receipt::from_account_data(
+            Pubkey::new_unique(),
+            &serialize(&donation_record(
+                Pubkey::new_unique(),
+                pool_key(),
+                token_mint,
+            )),
+            &ngo_data,
+            &serialize(&pool(ngo_authority, token_mint)),
+            6,
+        );
+
+        assert!(matches!(result, Err(ReceiptError::InvalidAccountData(_))));
+    }
+}
@@ -364,4 +364,102 @@ pub enum ErrorCode {
 
     #[msg("NGO has reached beneficiary registration limit")]
     BeneficiaryLimitReached,
+
+    #[msg("Pool has no reclaimed funds to route")]
+    NoReclaimedFunds,
+
+    #[msg("Destination pool is invalid for reclaimed funds")]
+    InvalidDestinationPool,
+
+    #[msg("No redistribution round available to claim")]
+    NoRedistributionAvailable,
+
+    #[msg("Only beneficiaries who claimed their distribution can join redistribution")]
+    NotEligibleForRedistribution,
+
+    #[msg("No refund available for this donation")]
+    NoRefundAvailable,
+
+    #[msg("Claim window is outside the platform bounds")]
+    InvalidClaimWindow,
+
+    #[msg("Claim deadline extension exceeds the maximum claim window")]
+    ClaimExtensionTooLong,
+
+    #[msg("Donation has already been refunded")]
+    DonationAlreadyRefunded,
+
+    #[msg("Invalid vesting schedule (tranches must sum to the locked percentage with ascending unlock offsets)")]
+    InvalidVestingSchedule,
+
+    #[msg("Another member of this household is already registered for the pool")]
+    HouseholdAlreadyRegisteredForPool,
+
+    #[msg("Field worker cannot verify a beneficiary they registered")]
+    SelfVerificationNotAllowed,
+
+    #[msg("Verification period has not expired yet")]
+    VerificationNotExpired,
+
+    #[msg("Account is still in use and cannot be closed")]
+    AccountStillInUse,
+
+    #[msg("A price feed is required to value this token in USD")]
+    PriceFeedRequired,
+
+    #[msg("Price feed is not active")]
+    PriceFeedInactive,
+
+    #[msg("Oracle account does not match the price feed")]
+    OracleAccountMismatch,
+
+    #[msg("Oracle price is stale")]
+    StalePriceFeed,
+
+    #[msg("Invalid oracle price")]
+    InvalidOraclePrice,
+
+    #[msg("Aid card already issued for this beneficiary")]
+    AidCardAlreadyIssued,
+
+    #[msg("Aid card account does not match the beneficiary")]
+    AidCardAccountMismatch,
+
+    #[msg("Aid card accounts are required to revoke a beneficiary holding a card")]
+    AidCardAccountsRequired,
+
+    #[msg("Receipt already issued for this donation")]
+    ReceiptAlreadyIssued,
+
+    #[msg("NGO has no tax ID on record")]
+    NGOTaxIdMissing,
+
+    #[msg("Invalid receipt number")]
+    InvalidReceiptNumber,
+
+    #[msg("Fiscal year does not match the donation date")]
+    InvalidFiscalYear,
+
+    #[msg("Donor details cannot be recorded on an anonymous donation")]
+    AnonymousDonorDetails,
+
+    #[msg(
+        "Anonymous donations require a donor commitment and only anonymous donations may carry one"
+    )]
+    DonorCommitmentMismatch,
+
+    #[msg("Donor proof does not match the donation commitment")]
+    InvalidDonorProof,
+
+    #[msg("Token mint has an extension that fund pools do not support")]
+    UnsupportedMintExtension,
+
+    #[msg("Lamport donations require a wrapped SOL destination account")]
+    NativeDonationNotSupported,
+
+    #[msg("Minimum verifier NGOs cannot exceed the verification threshold")]
+    InvalidVerifierNgoThreshold,
+
+    #[msg("Remaining approval slots are reserved for verifiers from other NGOs")]
+    IndependentVerifierRequired,
 }
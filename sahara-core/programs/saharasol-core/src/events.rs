@@ -0,0 +1,642 @@
+use crate::instructions::UpdatePlatformConfigParams;
+use crate::state::{
+    AdminActionType, DisasterType, DistributionType, DonationType, ReclaimDestination,
+    VerificationStatus,
+};
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct NgoVerified {
+    pub ngo: Pubkey,
+    pub name: String,
+    pub admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct NgoVerificationRevoked {
+    pub ngo: Pubkey,
+    pub name: String,
+    pub admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct NgoStatusUpdated {
+    pub ngo: Pubkey,
+    pub name: String,
+    pub is_active: bool,
+    pub admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct NgoBlacklisted {
+    pub ngo: Pubkey,
+    pub name: String,
+    pub reason: String,
+    pub admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct NgoBlacklistRemoved {
+    pub ngo: Pubkey,
+    pub name: String,
+    pub admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminTransferInitiated {
+    pub current_admin: Pubkey,
+    pub pending_admin: Pubkey,
+    pub expires_in: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminTransferCompleted {
+    pub previous_admin: Pubkey,
+    pub new_admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminTransferCancelled {
+    pub admin: Pubkey,
+    pub pending_admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PlatformInitialized {
+    pub config: Pubkey,
+    pub admin: Pubkey,
+    pub platform_fee_percentage: u16,
+    pub verification_threshold: u8,
+    pub max_verifiers: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PlatformConfigUpdated {
+    pub config: Pubkey,
+    pub action_type: AdminActionType,
+    pub params: UpdatePlatformConfigParams,
+    pub admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AllowedTokenAdded {
+    pub token_mint: Pubkey,
+    pub total_allowed_tokens: u8,
+    pub reason: String,
+    pub admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AllowedTokenRemoved {
+    pub token_mint: Pubkey,
+    pub total_allowed_tokens: u8,
+    pub reason: String,
+    pub admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ManagerAdded {
+    pub manager: Pubkey,
+    pub total_managers: u8,
+    pub reason: String,
+    pub admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ManagerRemoved {
+    pub manager: Pubkey,
+    pub total_managers: u8,
+    pub reason: String,
+    pub admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct NgoRegistered {
+    pub ngo: Pubkey,
+    pub authority: Pubkey,
+    pub name: String,
+    pub registration_number: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct NgoUpdated {
+    pub ngo: Pubkey,
+    pub name: String,
+    pub verification_removed: bool,
+    pub actor: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FieldWorkerRegistered {
+    pub field_worker: Pubkey,
+    pub authority: Pubkey,
+    pub name: String,
+    pub organization: String,
+    pub ngo: Pubkey,
+    pub ngo_name: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FieldWorkerStatusUpdated {
+    pub field_worker: Pubkey,
+    pub name: String,
+    pub is_active: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FieldWorkerUpdated {
+    pub field_worker: Pubkey,
+    pub name: String,
+    pub ngo: Pubkey,
+    pub actor: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FieldWorkerAssignedToDisaster {
+    pub field_worker: Pubkey,
+    pub name: String,
+    pub ngo: Pubkey,
+    pub disaster_id: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FieldWorkerAssignmentRevoked {
+    pub field_worker: Pubkey,
+    pub name: String,
+    pub ngo: Pubkey,
+    pub disaster_id: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DisasterCreated {
+    pub disaster: Pubkey,
+    pub event_id: String,
+    pub name: String,
+    pub event_type: DisasterType,
+    pub severity: u8,
+    pub estimated_affected_population: u32,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DisasterUpdated {
+    pub disaster: Pubkey,
+    pub event_id: String,
+    pub name: String,
+    pub severity: u8,
+    pub is_active: bool,
+    pub estimated_affected_population: u32,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DisasterClosed {
+    pub disaster: Pubkey,
+    pub event_id: String,
+    pub total_beneficiaries: u32,
+    pub verified_beneficiaries: u32,
+    pub total_aid_distributed: u64,
+    pub closed_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DisasterForceCloseProcessed {
+    pub disaster: Pubkey,
+    pub event_id: String,
+    pub pools_closed: u32,
+    pub pools_remaining: u32,
+    pub released_for_refunds: u64,
+    pub reason: String,
+    pub admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DisasterReviewDelegationUpdated {
+    pub disaster: Pubkey,
+    pub event_id: String,
+    pub declaring_authority: Pubkey,
+    pub enabled: bool,
+    pub admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PoolClosed {
+    pub pool: Pubkey,
+    pub disaster_id: String,
+    pub pool_id: String,
+    pub name: String,
+    pub total_deposited: u64,
+    pub total_distributed: u64,
+    pub total_claimed: u64,
+    pub released_for_refunds: u64,
+    pub closed_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FundPoolCreated {
+    pub pool: Pubkey,
+    pub disaster_id: String,
+    pub pool_id: String,
+    pub name: String,
+    pub authority: Pubkey,
+    pub token_mint: Pubkey,
+    pub distribution_type: DistributionType,
+    pub distribution_percentage_immediate: u8,
+    pub distribution_percentage_locked: u8,
+    pub claim_window_seconds: i64,
+    pub vesting_tranches: u8,
+    pub target_amount: Option<u64>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PoolConfigUpdated {
+    pub pool: Pubkey,
+    pub name: String,
+    pub is_active: bool,
+    pub target_amount: Option<u64>,
+    pub claim_window_seconds: i64,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DonationMade {
+    pub donation_record: Pubkey,
+    pub donor: Pubkey,
+    pub recipient: Pubkey,
+    pub donation_type: DonationType,
+    pub disaster_id: String,
+    pub pool: Option<Pubkey>,
+    pub token_mint: Pubkey,
+    pub amount: u64,
+    pub platform_fee: u64,
+    pub net_amount: u64,
+    pub usd_value: u64,
+    pub is_anonymous: bool,
+    pub timestamp: i64,
+    pub donor_commitment: Option<[u8; 32]>,
+}
+
+#[event]
+pub struct DonationRefunded {
+    pub donation_record: Pubkey,
+    pub donor: Pubkey,
+    pub pool: Pubkey,
+    pub refund_amount: u64,
+    pub total_refunded: u64,
+    pub is_fully_refunded: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BeneficiaryRegistered {
+    pub beneficiary: Pubkey,
+    pub authority: Pubkey,
+    pub disaster_id: String,
+    pub name: String,
+    pub family_size: u8,
+    pub damage_severity: u8,
+    pub verification_status: VerificationStatus,
+    pub field_worker: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BeneficiaryUpdated {
+    pub beneficiary: Pubkey,
+    pub authority: Pubkey,
+    pub disaster_id: String,
+    pub name: String,
+    pub family_size: u8,
+    pub damage_severity: u8,
+    pub field_worker: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BeneficiaryPhoneChanged {
+    pub beneficiary: Pubkey,
+    pub name: String,
+    pub old_phone_number: String,
+    pub new_phone_number: String,
+    pub field_worker: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BeneficiaryNationalIdCorrected {
+    pub beneficiary: Pubkey,
+    pub name: String,
+    pub old_national_id: String,
+    pub new_national_id: String,
+    pub admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PhoneRegistryClosed {
+    pub registry: Pubkey,
+    pub disaster_id: String,
+    pub rent_payer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct NationalIdRegistryClosed {
+    pub registry: Pubkey,
+    pub disaster_id: String,
+    pub rent_payer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VerificationApprovalAdded {
+    pub beneficiary: Pubkey,
+    pub field_worker: Pubkey,
+    pub field_worker_name: String,
+    pub verifier_ngo: Option<Pubkey>,
+    pub approval_count: u8,
+    pub verification_threshold: u8,
+    pub distinct_ngos: u8,
+    pub min_verifier_ngos: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BeneficiaryVerified {
+    pub beneficiary: Pubkey,
+    pub authority: Pubkey,
+    pub disaster_id: String,
+    pub name: String,
+    pub approval_count: u8,
+    pub distinct_ngos: u8,
+    pub verified_at: i64,
+}
+
+#[event]
+pub struct BeneficiaryVerificationExpired {
+    pub beneficiary: Pubkey,
+    pub name: String,
+    pub approval_count: u8,
+    pub registered_at: i64,
+    pub deadline: i64,
+    pub caller: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BeneficiaryFlagged {
+    pub beneficiary: Pubkey,
+    pub name: String,
+    pub reason: String,
+    pub flagged_by: Pubkey,
+    pub field_worker_name: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FlaggedBeneficiaryReviewed {
+    pub beneficiary: Pubkey,
+    pub name: String,
+    pub approved: bool,
+    pub verification_status: VerificationStatus,
+    pub flagged_reason: String,
+    pub reviewer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BeneficiaryVerificationRevoked {
+    pub beneficiary: Pubkey,
+    pub name: String,
+    pub disaster_id: String,
+    pub approvals_revoked: u8,
+    pub reason: String,
+    pub admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BeneficiaryRegisteredForPool {
+    pub pool: Pubkey,
+    pub beneficiary: Pubkey,
+    pub name: String,
+    pub allocation_weight: u64,
+    pub pool_total_weight: u64,
+    pub registered_count: u32,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PoolRegistrationLocked {
+    pub pool: Pubkey,
+    pub name: String,
+    pub total_allocation_weight: u64,
+    pub registered_count: u32,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PoolRegistrationClosed {
+    pub pool_registration: Pubkey,
+    pub pool: Pubkey,
+    pub beneficiary: Pubkey,
+    pub rent_payer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DistributionCreated {
+    pub distribution: Pubkey,
+    pub pool: Pubkey,
+    pub beneficiary: Pubkey,
+    pub amount_allocated: u64,
+    pub amount_immediate: u64,
+    pub amount_locked: u64,
+    pub unlock_time: Option<i64>,
+    pub claim_deadline: Option<i64>,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FundsClaimed {
+    pub distribution: Pubkey,
+    pub pool: Pubkey,
+    pub beneficiary: Pubkey,
+    pub amount: u64,
+    pub usd_value: u64,
+    pub is_usd_priced: bool,
+    pub total_claimed: u64,
+    pub is_fully_claimed: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ExpiredDistributionReclaimed {
+    pub distribution: Pubkey,
+    pub pool: Pubkey,
+    pub beneficiary: Pubkey,
+    pub amount: u64,
+    pub pending_reclaimed: u64,
+    pub original_deadline: Option<i64>,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReclaimedFundsRouted {
+    pub pool: Pubkey,
+    pub destination: ReclaimDestination,
+    pub target: Pubkey,
+    pub amount: u64,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RedistributionClaimed {
+    pub distribution: Pubkey,
+    pub pool: Pubkey,
+    pub beneficiary: Pubkey,
+    pub round: u8,
+    pub amount: u64,
+    pub usd_value: u64,
+    pub is_usd_priced: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ClaimDeadlineExtended {
+    pub distribution: Pubkey,
+    pub pool: Pubkey,
+    pub beneficiary: Pubkey,
+    pub extension_seconds: i64,
+    pub previous_deadline: i64,
+    pub new_deadline: i64,
+    pub reason: String,
+    pub field_worker: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DistributionClosed {
+    pub distribution: Pubkey,
+    pub pool: Pubkey,
+    pub beneficiary: Pubkey,
+    pub was_expired: bool,
+    pub rent_payer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ActivityArchiveInitialized {
+    pub archive: Pubkey,
+    pub actor: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ActivityLogArchived {
+    pub archive: Pubkey,
+    pub activity_log: Pubkey,
+    pub actor: Pubkey,
+    pub leaf: [u8; 32],
+    pub root: [u8; 32],
+    pub archived_count: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct StandInOraclePriceSet {
+    pub price_oracle: Pubkey,
+    pub token_mint: Pubkey,
+    pub price: i64,
+    pub exponent: i32,
+    pub publish_time: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PriceFeedRegistered {
+    pub price_feed: Pubkey,
+    pub token_mint: Pubkey,
+    pub oracle: Pubkey,
+    pub token_decimals: u8,
+    pub max_staleness_seconds: i64,
+    pub admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PriceFeedUpdated {
+    pub price_feed: Pubkey,
+    pub token_mint: Pubkey,
+    pub oracle: Pubkey,
+    pub max_staleness_seconds: i64,
+    pub is_active: bool,
+    pub admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AidCardIssued {
+    pub beneficiary: Pubkey,
+    pub authority: Pubkey,
+    pub mint: Pubkey,
+    pub token_account: Pubkey,
+    pub disaster_id: String,
+    pub verified_at: i64,
+}
+
+#[event]
+pub struct AidCardFrozen {
+    pub beneficiary: Pubkey,
+    pub mint: Pubkey,
+    pub token_account: Pubkey,
+    pub admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DonationReceiptIssued {
+    pub donation_record: Pubkey,
+    pub donor: Pubkey,
+    pub pool: Pubkey,
+    pub ngo: Pubkey,
+    pub receipt_number: String,
+    pub tax_id: String,
+    pub fiscal_year: u16,
+    pub amount: u64,
+    pub timestamp: i64,
+}
@@ -1,4 +1,8 @@
 use crate::errors::ErrorCode;
+use crate::events::{
+    AdminTransferCancelled, AdminTransferCompleted, AdminTransferInitiated, NgoBlacklistRemoved,
+    NgoBlacklisted, NgoStatusUpdated, NgoVerificationRevoked, NgoVerified,
+};
 use crate::state::{AdminAction, AdminActionType, PlatformConfig, NGO};
 use anchor_lang::prelude::*;
 
@@ -8,7 +12,7 @@ pub struct VerifyNGOParams {
 }
 
 #[derive(Accounts)]
-#[instruction(ngo_authority: Pubkey, params: VerifyNGOParams, action_id: u64)]
+#[instruction(ngo_authority: Pubkey, params: VerifyNGOParams)]
 pub struct VerifyNGO<'info> {
     #[account(
         mut,
@@ -18,6 +22,7 @@ pub struct VerifyNGO<'info> {
     pub ngo: Account<'info, NGO>,
 
     #[account(
+        mut,
         seeds = [b"config"],
         bump = config.bump,
         constraint = config.is_admin_or_manager(&admin.key()) @ ErrorCode::UnauthorizedAdminOrManager
@@ -31,7 +36,7 @@ pub struct VerifyNGO<'info> {
         seeds = [
             b"admin-action",
             admin.key().as_ref(),
-            &action_id.to_le_bytes()
+            &config.admin_action_count.to_le_bytes()
         ],
         bump
     )]
@@ -47,7 +52,6 @@ pub fn verify_ngo_handler(
     ctx: Context<VerifyNGO>,
     _ngo_authority: Pubkey,
     params: VerifyNGOParams,
-    _action_id: u64,
 ) -> Result<()> {
     let ngo = &mut ctx.accounts.ngo;
     let admin_action = &mut ctx.accounts.admin_action;
@@ -72,6 +76,14 @@ pub fn verify_ngo_handler(
     admin_action.timestamp = clock.unix_timestamp;
     admin_action.metadata = String::new();
     admin_action.bump = ctx.bumps.admin_action;
+    admin_action.record_sequence(&mut ctx.accounts.config.admin_action_count)?;
+
+    emit!(NgoVerified {
+        ngo: ngo.key(),
+        name: ngo.name.clone(),
+        admin: ctx.accounts.admin.key(),
+        timestamp: clock.unix_timestamp,
+    });
 
     msg!("NGO verified successfully");
     msg!("NGO: {}", ngo.name);
@@ -86,7 +98,7 @@ pub struct RevokeVerificationParams {
 }
 
 #[derive(Accounts)]
-#[instruction(ngo_authority: Pubkey, params: RevokeVerificationParams, action_id: u64)]
+#[instruction(ngo_authority: Pubkey, params: RevokeVerificationParams)]
 pub struct RevokeVerification<'info> {
     #[account(
         mut,
@@ -96,6 +108,7 @@ pub struct RevokeVerification<'info> {
     pub ngo: Account<'info, NGO>,
 
     #[account(
+        mut,
         seeds = [b"config"],
         bump = config.bump,
         constraint = config.is_admin_or_manager(&admin.key()) @ ErrorCode::UnauthorizedAdminOrManager
@@ -109,7 +122,7 @@ pub struct RevokeVerification<'info> {
         seeds = [
             b"admin-action",
             admin.key().as_ref(),
-            &action_id.to_le_bytes()
+            &config.admin_action_count.to_le_bytes()
         ],
         bump
     )]
@@ -125,7 +138,6 @@ pub fn revoke_verification_handler(
     ctx: Context<RevokeVerification>,
     _ngo_authority: Pubkey,
     params: RevokeVerificationParams,
-    _action_id: u64,
 ) -> Result<()> {
     let ngo = &mut ctx.accounts.ngo;
     let admin_action = &mut ctx.accounts.admin_action;
@@ -148,6 +160,14 @@ pub fn revoke_verification_handler(
     admin_action.timestamp = clock.unix_timestamp;
     admin_action.metadata = String::new();
     admin_action.bump = ctx.bumps.admin_action;
+    admin_action.record_sequence(&mut ctx.accounts.config.admin_action_count)?;
+
+    emit!(NgoVerificationRevoked {
+        ngo: ngo.key(),
+        name: ngo.name.clone(),
+        admin: ctx.accounts.admin.key(),
+        timestamp: clock.unix_timestamp,
+    });
 
     msg!("NGO verification revoked");
     msg!("NGO: {}", ngo.name);
@@ -163,7 +183,7 @@ pub struct UpdateNGOStatusParams {
 }
 
 #[derive(Accounts)]
-#[instruction(ngo_authority: Pubkey, params: UpdateNGOStatusParams, action_id: u64)]
+#[instruction(ngo_authority: Pubkey, params: UpdateNGOStatusParams)]
 pub struct UpdateNGOStatus<'info> {
     #[account(
         mut,
@@ -173,6 +193,7 @@ pub struct UpdateNGOStatus<'info> {
     pub ngo: Account<'info, NGO>,
 
     #[account(
+        mut,
         seeds = [b"config"],
         bump = config.bump,
         constraint = config.is_admin_or_manager(&admin.key()) @ ErrorCode::UnauthorizedAdminOrManager
@@ -186,7 +207,7 @@ pub struct UpdateNGOStatus<'info> {
         seeds = [
             b"admin-action",
             admin.key().as_ref(),
-            &action_id.to_le_bytes()
+            &config.admin_action_count.to_le_bytes()
         ],
         bump
     )]
@@ -202,7 +223,6 @@ pub fn update_ngo_status_handler(
     ctx: Context<UpdateNGOStatus>,
     _ngo_authority: Pubkey,
     params: UpdateNGOStatusParams,
-    _action_id: u64,
 ) -> Result<()> {
     let ngo = &mut ctx.accounts.ngo;
     let admin_action = &mut ctx.accounts.admin_action;
@@ -227,6 +247,15 @@ pub fn update_ngo_status_handler(
     admin_action.timestamp = clock.unix_timestamp;
     admin_action.metadata = String::new();
     admin_action.bump = ctx.bumps.admin_action;
+    admin_action.record_sequence(&mut ctx.accounts.config.admin_action_count)?;
+
+    emit!(NgoStatusUpdated {
+        ngo: ngo.key(),
+        name: ngo.name.clone(),
+        is_active: params.is_active,
+        admin: ctx.accounts.admin.key(),
+        timestamp: clock.unix_timestamp,
+    });
 
     msg!(
         "NGO status updated to: {}",
@@ -247,7 +276,7 @@ pub struct BlacklistNGOParams {
 }
 
 #[derive(Accounts)]
-#[instruction(ngo_authority: Pubkey, params: BlacklistNGOParams, action_id: u64)]
+#[instruction(ngo_authority: Pubkey, params: BlacklistNGOParams)]
 pub struct BlacklistNGO<'info> {
     #[account(
         mut,
@@ -257,6 +286,7 @@ pub struct BlacklistNGO<'info> {
     pub ngo: Account<'info, NGO>,
 
     #[account(
+        mut,
         seeds = [b"config"],
         bump = config.bump,
         constraint = config.is_admin_or_manager(&admin.key()) @ ErrorCode::UnauthorizedAdminOrManager
@@ -270,7 +300,7 @@ pub struct BlacklistNGO<'info> {
         seeds = [
             b"admin-action",
             admin.key().as_ref(),
-            &action_id.to_le_bytes()
+            &config.admin_action_count.to_le_bytes()
         ],
         bump
     )]
@@ -286,7 +316,6 @@ pub fn blacklist_ngo_handler(
     ctx: Context<BlacklistNGO>,
     _ngo_authority: Pubkey,
     params: BlacklistNGOParams,
-    _action_id: u64,
 ) -> Result<()> {
     let ngo = &mut ctx.accounts.ngo;
     let admin_action = &mut ctx.accounts.admin_action;
@@ -313,6 +342,15 @@ pub fn blacklist_ngo_handler(
     admin_action.timestamp = clock.unix_timestamp;
     admin_action.metadata = String::new();
     admin_action.bump = ctx.bumps.admin_action;
+    admin_action.record_sequence(&mut ctx.accounts.config.admin_action_count)?;
+
+    emit!(NgoBlacklisted {
+        ngo: ngo.key(),
+        name: ngo.name.clone(),
+        reason: ngo.blacklist_reason.clone(),
+        admin: ctx.accounts.admin.key(),
+        timestamp: clock.unix_timestamp,
+    });
 
     msg!("NGO blacklisted");
     msg!("NGO: {}", ngo.name);
@@ -326,7 +364,7 @@ pub struct RemoveBlacklistParams {
 }
 
 #[derive(Accounts)]
-#[instruction(ngo_authority: Pubkey, params: RemoveBlacklistParams, action_id: u64)]
+#[instruction(ngo_authority: Pubkey, params: RemoveBlacklistParams)]
 pub struct RemoveBlacklist<'info> {
     #[account(
         mut,
@@ -336,6 +374,7 @@ pub struct RemoveBlacklist<'info> {
     pub ngo: Account<'info, NGO>,
 
     #[account(
+        mut,
         seeds = [b"config"],
         bump = config.bump,
         constraint = config.is_admin_or_manager(&admin.key()) @ ErrorCode::UnauthorizedAdminOrManager
@@ -349,7 +388,7 @@ pub struct RemoveBlacklist<'info> {
         seeds = [
             b"admin-action",
             admin.key().as_ref(),
-            &action_id.to_le_bytes()
+            &config.admin_action_count.to_le_bytes()
         ],
         bump
     )]
@@ -365,7 +404,6 @@ pub fn remove_blacklist_handler(
     ctx: Context<RemoveBlacklist>,
     _ngo_authority: Pubkey,
     params: RemoveBlacklistParams,
-    _action_id: u64,
 ) -> Result<()> {
     let ngo = &mut ctx.accounts.ngo;
     let admin_action = &mut ctx.accounts.admin_action;
@@ -389,6 +427,14 @@ pub fn remove_blacklist_handler(
     admin_action.timestamp = clock.unix_timestamp;
     admin_action.metadata = String::new();
     admin_action.bump = ctx.bumps.admin_action;
+    admin_action.record_sequence(&mut ctx.accounts.config.admin_action_count)?;
+
+    emit!(NgoBlacklistRemoved {
+        ngo: ngo.key(),
+        name: ngo.name.clone(),
+        admin: ctx.accounts.admin.key(),
+        timestamp: clock.unix_timestamp,
+    });
 
     msg!("NGO blacklist removed");
     msg!("NGO: {}", ngo.name);
@@ -403,7 +449,7 @@ pub struct InitiateAdminTransferParams {
 }
 
 #[derive(Accounts)]
-#[instruction(params: InitiateAdminTransferParams, action_id: u64)]
+#[instruction(params: InitiateAdminTransferParams)]
 pub struct InitiateAdminTransfer<'info> {
     #[account(
         mut,
@@ -420,7 +466,7 @@ pub struct InitiateAdminTransfer<'info> {
         seeds = [
             b"admin-action",
             admin.key().as_ref(),
-            &action_id.to_le_bytes()
+            &config.admin_action_count.to_le_bytes()
         ],
         bump
     )]
@@ -435,7 +481,6 @@ pub struct InitiateAdminTransfer<'info> {
 pub fn initiate_admin_transfer_handler(
     ctx: Context<InitiateAdminTransfer>,
     params: InitiateAdminTransferParams,
-    _action_id: u64,
 ) -> Result<()> {
     let config = &mut ctx.accounts.config;
     let admin_action = &mut ctx.accounts.admin_action;
@@ -467,6 +512,14 @@ pub fn initiate_admin_transfer_handler(
     admin_action.timestamp = clock.unix_timestamp;
     admin_action.metadata = String::new();
     admin_action.bump = ctx.bumps.admin_action;
+    admin_action.record_sequence(&mut config.admin_action_count)?;
+
+    emit!(AdminTransferInitiated {
+        current_admin: ctx.accounts.admin.key(),
+        pending_admin: params.new_admin,
+        expires_in: config.admin_transfer_timeout,
+        timestamp: clock.unix_timestamp,
+    });
 
     msg!("Admin transfer initiated");
     msg!("Current admin: {}", ctx.accounts.admin.key());
@@ -482,7 +535,7 @@ pub struct AcceptAdminTransferParams {
 }
 
 #[derive(Accounts)]
-#[instruction(params: AcceptAdminTransferParams, action_id: u64)]
+#[instruction(params: AcceptAdminTransferParams)]
 pub struct AcceptAdminTransfer<'info> {
     #[account(
         mut,
@@ -498,7 +551,7 @@ pub struct AcceptAdminTransfer<'info> {
         seeds = [
             b"admin-action",
             new_admin.key().as_ref(),
-            &action_id.to_le_bytes()
+            &config.admin_action_count.to_le_bytes()
         ],
         bump
     )]
@@ -513,7 +566,6 @@ pub struct AcceptAdminTransfer<'info> {
 pub fn accept_admin_transfer_handler(
     ctx: Context<AcceptAdminTransfer>,
     params: AcceptAdminTransferParams,
-    _action_id: u64,
 ) -> Result<()> {
     let config = &mut ctx.accounts.config;
     let admin_action = &mut ctx.accounts.admin_action;
@@ -552,6 +604,13 @@ pub fn accept_admin_transfer_handler(
     admin_action.timestamp = clock.unix_timestamp;
     admin_action.metadata = format!("Previous admin: {}", old_admin);
     admin_action.bump = ctx.bumps.admin_action;
+    admin_action.record_sequence(&mut config.admin_action_count)?;
+
+    emit!(AdminTransferCompleted {
+        previous_admin: old_admin,
+        new_admin: ctx.accounts.new_admin.key(),
+        timestamp: clock.unix_timestamp,
+    });
 
     msg!("Admin transfer completed");
     msg!("Previous admin: {}", old_admin);
@@ -566,7 +625,7 @@ pub struct CancelAdminTransferParams {
 }
 
 #[derive(Accounts)]
-#[instruction(params: CancelAdminTransferParams, action_id: u64)]
+#[instruction(params: CancelAdminTransferParams)]
 pub struct CancelAdminTransfer<'info> {
     #[account(
         mut,
@@ -583,7 +642,7 @@ pub struct CancelAdminTransfer<'info> {
         seeds = [
             b"admin-action",
             admin.key().as_ref(),
-            &action_id.to_le_bytes()
+            &config.admin_action_count.to_le_bytes()
         ],
         bump
     )]
@@ -598,7 +657,6 @@ pub struct CancelAdminTransfer<'info> {
 pub fn cancel_admin_transfer_handler(
     ctx: Context<CancelAdminTransfer>,
     params: CancelAdminTransferParams,
-    _action_id: u64,
 ) -> Result<()> {
     let config = &mut ctx.accounts.config;
     let admin_action = &mut ctx.accounts.admin_action;
@@ -624,6 +682,13 @@ pub fn cancel_admin_transfer_handler(
     admin_action.timestamp = clock.unix_timestamp;
     admin_action.metadata = String::new();
     admin_action.bump = ctx.bumps.admin_action;
+    admin_action.record_sequence(&mut config.admin_action_count)?;
+
+    emit!(AdminTransferCancelled {
+        admin: ctx.accounts.admin.key(),
+        pending_admin,
+        timestamp: clock.unix_timestamp,
+    });
 
     msg!("Admin transfer cancelled");
     msg!("Pending admin was: {}", pending_admin);
@@ -0,0 +1,335 @@
+use crate::errors::ErrorCode;
+use crate::events::AidCardIssued;
+use crate::state::{ActivityLog, ActivityType, Beneficiary, VerificationStatus};
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, CreateAccount};
+use anchor_spl::associated_token::{
+    self, get_associated_token_address_with_program_id, AssociatedToken,
+};
+use anchor_spl::token_2022::spl_token_2022::extension::ExtensionType;
+use anchor_spl::token_2022::spl_token_2022::state::Mint as MintState;
+use anchor_spl::token_2022::{self, FreezeAccount, InitializeMint2, MintTo, Token2022};
+use anchor_spl::token_interface::spl_pod::optional_keys::OptionalNonZeroPubkey;
+use anchor_spl::token_interface::spl_token_metadata_interface::state::{Field, TokenMetadata};
+use anchor_spl::token_interface::{
+    metadata_pointer_initialize, non_transferable_mint_initialize, token_metadata_initialize,
+    token_metadata_update_field, MetadataPointerInitialize, NonTransferableMintInitialize,
+    TokenMetadataInitialize, TokenMetadataUpdateField,
+};
+
+pub const AID_CARD_NAME: &str = "SaharaSol Aid Card";
+pub const AID_CARD_SYMBOL: &str = "SAID";
+
+pub(crate) struct AidCardAccounts<'a, 'info> {
+    pub mint: &'a AccountInfo<'info>,
+    pub token_account: &'a AccountInfo<'info>,
+    pub owner: &'a AccountInfo<'info>,
+    pub payer: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+    pub associated_token_program: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+}
+
+pub(crate) fn mint_aid_card<'info>(
+    accounts: AidCardAccounts<'_, 'info>,
+    beneficiary: &mut Account<'info, Beneficiary>,
+    mint_bump: u8,
+    verified_at: i64,
+) -> Result<()> {
+    require!(
+        beneficiary.nft_mint.is_none(),
+        ErrorCode::AidCardAlreadyIssued
+    );
+
+    require!(
+        accounts.owner.key() == beneficiary.authority,
+        ErrorCode::AidCardAccountMismatch
+    );
+
+    let mint_key = accounts.mint.key();
+    require!(
+        accounts.token_account.key()
+            == get_associated_token_address_with_program_id(
+                &beneficiary.authority,
+                &mint_key,
+                &token_2022::ID
+            ),
+        ErrorCode::AidCardAccountMismatch
+    );
+
+    let beneficiary_key = beneficiary.key();
+    let seeds: &[&[u8]] = &[b"aid-card", beneficiary_key.as_ref(), &[mint_bump]];
+    let signer_seeds = &[seeds];
+
+    let metadata = TokenMetadata {
+        update_authority: OptionalNonZeroPubkey::try_from(Some(mint_key))?,
+        mint: mint_key,
+        name: AID_CARD_NAME.to_string(),
+        symbol: AID_CARD_SYMBOL.to_string(),
+        uri: String::new(),
+        additional_metadata: vec![
+            ("disaster_id".to_string(), beneficiary.disaster_id.clone()),
+            ("verified_at".to_string(), verified_at.to_string()),
+        ],
+    };
+
+    let mint_space = ExtensionType::try_calculate_account_len::<MintState>(&[
+        ExtensionType::NonTransferable,
+        ExtensionType::MetadataPointer,
+    ])?;
+    let lamports = Rent::get()?.minimum_balance(mint_space + metadata.tlv_size_of()?);
+
+    system_program::create_account(
+        CpiContext::new_with_signer(
+            accounts.system_program.clone(),
+            CreateAccount {
+                from: accounts.payer.clone(),
+                to: accounts.mint.clone(),
+            },
+            signer_seeds,
+        ),
+        lamports,
+        mint_space as u64,
+        &token_2022::ID,
+    )?;
+
+    non_transferable_mint_initialize(CpiContext::new(
+        accounts.token_program.clone(),
+        NonTransferableMintInitialize {
+            token_program_id: accounts.token_program.clone(),
+            mint: accounts.mint.clone(),
+        },
+    ))?;
+
+    metadata_pointer_initialize(
+        CpiContext::new(
+            accounts.token_program.clone(),
+            MetadataPointerInitialize {
+                token_program_id: accounts.token_program.clone(),
+                mint: accounts.mint.clone(),
+            },
+        ),
+        Some(mint_key),
+        Some(mint_key),
+    )?;
+
+    token_2022::initialize_mint2(
+        CpiContext::new(
+            accounts.token_program.clone(),
+            InitializeMint2 {
+                mint: accounts.mint.clone(),
+            },
+        ),
+        0,
+        &mint_key,
+        Some(&mint_key),
+    )?;
+
+    token_metadata_initialize(
+        CpiContext::new_with_signer(
+            accounts.token_program.clone(),
+            TokenMetadataInitialize {
+                program_id: accounts.token_program.clone(),
+                metadata: accounts.mint.clone(),
+                update_authority: accounts.mint.clone(),
+                mint_authority: accounts.mint.clone(),
+                mint: accounts.mint.clone(),
+            },
+            signer_seeds,
+        ),
+        metadata.name,
+        metadata.symbol,
+        metadata.uri,
+    )?;
+
+    for (key, value) in metadata.additional_metadata {
+        token_metadata_update_field(
+            CpiContext::new_with_signer(
+                accounts.token_program.clone(),
+                TokenMetadataUpdateField {
+                    program_id: accounts.token_program.clone(),
+                    metadata: accounts.mint.clone(),
+                    update_authority: accounts.mint.clone(),
+                },
+                signer_seeds,
+            ),
+            Field::Key(key),
+            value,
+        )?;
+    }
+
+    associated_token::create(CpiContext::new(
+        accounts.associated_token_program.clone(),
+        associated_token::Create {
+            payer: accounts.payer.clone(),
+            associated_token: accounts.token_account.clone(),
+            authority: accounts.owner.clone(),
+            mint: accounts.mint.clone(),
+            system_program: accounts.system_program.clone(),
+            token_program: accounts.token_program.clone(),
+        },
+    ))?;
+
+    token_2022::mint_to(
+        CpiContext::new_with_signer(
+            accounts.token_program.clone(),
+            MintTo {
+                mint: accounts.mint.clone(),
+                to: accounts.token_account.clone(),
+                authority: accounts.mint.clone(),
+            },
+            signer_seeds,
+        ),
+        1,
+    )?;
+
+    beneficiary.nft_mint = Some(mint_key);
+
+    emit!(AidCardIssued {
+        beneficiary: beneficiary_key,
+        authority: beneficiary.authority,
+        mint: mint_key,
+        token_account: accounts.token_account.key(),
+        disaster_id: beneficiary.disaster_id.clone(),
+        verified_at,
+    });
+
+    msg!("Aid card issued: {}", mint_key);
+
+    Ok(())
+}
+
+pub(crate) fn freeze_aid_card<'info>(
+    mint: &AccountInfo<'info>,
+    token_account: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    beneficiary: &Account<'info, Beneficiary>,
+    mint_bump: u8,
+) -> Result<()> {
+    require!(
+        beneficiary.nft_mint == Some(mint.key()),
+        ErrorCode::AidCardAccountMismatch
+    );
+
+    require!(
+        token_account.key()
+            == get_associated_token_address_with_program_id(
+                &beneficiary.authority,
+                &mint.key(),
+                &token_2022::ID
+            ),
+        ErrorCode::AidCardAccountMismatch
+    );
+
+    let beneficiary_key = beneficiary.key();
+    let seeds: &[&[u8]] = &[b"aid-card", beneficiary_key.as_ref(), &[mint_bump]];
+
+    token_2022::freeze_account(CpiContext::new_with_signer(
+        token_program.clone(),
+        FreezeAccount {
+            account: token_account.clone(),
+            mint: mint.clone(),
+            authority: mint.clone(),
+        },
+        &[seeds],
+    ))
+}
+
+#[derive(Accounts)]
+#[instruction(beneficiary_authority: Pubkey, disaster_id: String)]
+pub struct IssueAidCard<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"beneficiary",
+            beneficiary_authority.as_ref(),
+            disaster_id.as_bytes()
+        ],
+        bump = beneficiary.bump,
+        constraint = beneficiary.verification_status == VerificationStatus::Verified @ ErrorCode::BeneficiaryNotVerified
+    )]
+    pub beneficiary: Account<'info, Beneficiary>,
+
+    /// CHECK: Created and initialized as a Token-2022 mint in the handler
+    #[account(
+        mut,
+        seeds = [b"aid-card", beneficiary.key().as_ref()],
+        bump
+    )]
+    pub aid_card_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Checked against the beneficiary's associated token address in the handler
+    #[account(mut)]
+    pub aid_card_token_account: UncheckedAccount<'info>,
+
+    /// CHECK: Must be the beneficiary authority that receives the card
+    #[account(address = beneficiary_authority @ ErrorCode::AidCardAccountMismatch)]
+    pub beneficiary_wallet: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = ActivityLog::SPACE,
+        seeds = [
+            b"activity",
+            beneficiary.key().as_ref(),
+            &beneficiary.activity_count.to_le_bytes()
+        ],
+        bump
+    )]
+    pub activity_log: Account<'info, ActivityLog>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub token_2022_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn issue_aid_card_handler(
+    ctx: Context<IssueAidCard>,
+    _beneficiary_authority: Pubkey,
+    _disaster_id: String,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    let beneficiary = &mut ctx.accounts.beneficiary;
+    let verified_at = beneficiary
+        .verified_at
+        .ok_or(ErrorCode::BeneficiaryNotVerified)?;
+
+    mint_aid_card(
+        AidCardAccounts {
+            mint: &ctx.accounts.aid_card_mint.to_account_info(),
+            token_account: &ctx.accounts.aid_card_token_account.to_account_info(),
+            owner: &ctx.accounts.beneficiary_wallet.to_account_info(),
+            payer: &ctx.accounts.payer.to_account_info(),
+            token_program: &ctx.accounts.token_2022_program.to_account_info(),
+            associated_token_program: &ctx.accounts.associated_token_program.to_account_info(),
+            system_program: &ctx.accounts.system_program.to_account_info(),
+        },
+        beneficiary,
+        ctx.bumps.aid_card_mint,
+        verified_at,
+    )?;
+
+    let activity_log = &mut ctx.accounts.activity_log;
+    activity_log.action_type = ActivityType::AidCardIssued;
+    activity_log.actor = ctx.accounts.payer.key();
+    activity_log.target = beneficiary.key();
+    activity_log.amount = None;
+    activity_log.timestamp = clock.unix_timestamp;
+    activity_log.metadata = format!(
+        "Beneficiary: {} | Aid card: {}",
+        beneficiary.name,
+        ctx.accounts.aid_card_mint.key()
+    );
+    activity_log.rent_payer = ctx.accounts.payer.key();
+    activity_log.bump = ctx.bumps.activity_log;
+    activity_log.record_sequence(beneficiary.key(), &mut beneficiary.activity_count)?;
+
+    msg!("Beneficiary: {}", beneficiary.name);
+
+    Ok(())
+}
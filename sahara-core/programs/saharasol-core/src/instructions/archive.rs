@@ -0,0 +1,107 @@
+use crate::errors::ErrorCode;
+use crate::events::{ActivityArchiveInitialized, ActivityLogArchived};
+use crate::state::{ActivityArchive, ActivityLog, PlatformConfig};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::{hashv, Hash};
+
+#[derive(Accounts)]
+pub struct InitializeActivityArchive<'info> {
+    #[account(
+        init,
+        payer = actor,
+        space = ActivityArchive::SPACE,
+        seeds = [b"activity-archive", actor.key().as_ref()],
+        bump
+    )]
+    pub archive: Account<'info, ActivityArchive>,
+
+    #[account(mut)]
+    pub actor: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_activity_archive_handler(ctx: Context<InitializeActivityArchive>) -> Result<()> {
+    let clock = Clock::get()?;
+    let archive = &mut ctx.accounts.archive;
+
+    archive.actor = ctx.accounts.actor.key();
+    archive.root = [0u8; 32];
+    archive.archived_count = 0;
+    archive.created_at = clock.unix_timestamp;
+    archive.last_archived_at = 0;
+    archive.bump = ctx.bumps.archive;
+
+    emit!(ActivityArchiveInitialized {
+        archive: archive.key(),
+        actor: archive.actor,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Activity archive initialized");
+    msg!("Actor: {}", archive.actor);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ArchiveActivityLog<'info> {
+    #[account(
+        mut,
+        close = actor,
+        constraint = activity_log.rent_payer == actor.key() @ ErrorCode::UnauthorizedModification
+    )]
+    pub activity_log: Account<'info, ActivityLog>,
+
+    #[account(
+        mut,
+        seeds = [b"activity-archive", actor.key().as_ref()],
+        bump = archive.bump,
+    )]
+    pub archive: Account<'info, ActivityArchive>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.activity_archival_enabled @ ErrorCode::OperationNotAllowed
+    )]
+    pub config: Account<'info, PlatformConfig>,
+
+    #[account(mut)]
+    pub actor: Signer<'info>,
+}
+
+pub fn archive_activity_log_handler(ctx: Context<ArchiveActivityLog>) -> Result<()> {
+    let clock = Clock::get()?;
+
+    let activity_log_info = ctx.accounts.activity_log.to_account_info();
+    let leaf = {
+        let data = activity_log_info.try_borrow_data()?;
+        hashv(&[activity_log_info.key.as_ref(), &data[..]])
+    };
+
+    let archive = &mut ctx.accounts.archive;
+    archive.root = hashv(&[&archive.root, leaf.as_ref()]).to_bytes();
+    archive.archived_count = archive
+        .archived_count
+        .checked_add(1)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    archive.last_archived_at = clock.unix_timestamp;
+
+    emit!(ActivityLogArchived {
+        archive: archive.key(),
+        activity_log: activity_log_info.key(),
+        actor: archive.actor,
+        leaf: leaf.to_bytes(),
+        root: archive.root,
+        archived_count: archive.archived_count,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Activity log archived: {}", activity_log_info.key);
+    msg!("Leaf: {}", leaf);
+    msg!("New root: {}", Hash::new_from_array(archive.root));
+    msg!("Archived count: {}", archive.archived_count);
+
+    Ok(())
+}
@@ -1,6 +1,12 @@
 use crate::errors::ErrorCode;
+use crate::events::{
+    BeneficiaryNationalIdCorrected, BeneficiaryPhoneChanged, BeneficiaryRegistered,
+    BeneficiaryUpdated, NationalIdRegistryClosed, PhoneRegistryClosed,
+};
+use crate::instructions::distribution::create_pda_account;
 use crate::state::{
-    ActivityLog, ActivityType, Beneficiary, DisasterEvent, FieldWorker, Location,
+    ActivityLog, ActivityType, AdminAction, AdminActionType, Beneficiary, DisasterEvent,
+    FieldWorker, FieldWorkerAssignment, HouseholdRegistry, Location, LocationRegistry,
     NationalIdRegistry, PhoneRegistry, PlatformConfig, VerificationStatus,
 };
 use anchor_lang::prelude::*;
@@ -24,7 +30,7 @@ pub struct RegisterBeneficiaryParams {
 }
 
 #[derive(Accounts)]
-#[instruction(params: RegisterBeneficiaryParams, timestamp: i64)]
+#[instruction(params: RegisterBeneficiaryParams)]
 pub struct RegisterBeneficiary<'info> {
     #[account(
         init,
@@ -81,6 +87,16 @@ pub struct RegisterBeneficiary<'info> {
     )]
     pub national_id_registry: Box<Account<'info, NationalIdRegistry>>,
 
+    /// CHECK: Household registry PDA, validated and created in the handler when household_id is set
+    #[account(mut)]
+    pub household_registry: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Location registry PDA keyed by geohash, validated and created in the handler
+    #[account(mut)]
+    pub location_registry: Option<UncheckedAccount<'info>>,
+
+    pub field_worker_assignment: Option<Box<Account<'info, FieldWorkerAssignment>>>,
+
     #[account(
         mut,
         seeds = [b"config"],
@@ -94,8 +110,8 @@ pub struct RegisterBeneficiary<'info> {
         space = ActivityLog::SPACE,
         seeds = [
             b"activity",
-            field_worker_authority.key().as_ref(),
-            &timestamp.to_le_bytes()
+            beneficiary.key().as_ref(),
+            &0u64.to_le_bytes()
         ],
         bump
     )]
@@ -114,7 +130,6 @@ pub struct RegisterBeneficiary<'info> {
 pub fn handler<'info>(
     ctx: Context<'_, '_, 'info, 'info, RegisterBeneficiary<'info>>,
     params: RegisterBeneficiaryParams,
-    _timestamp: i64,
 ) -> Result<()> {
     let clock = Clock::get()?;
 
@@ -132,17 +147,19 @@ pub fn handler<'info>(
     phone_registry.beneficiary = ctx.accounts.authority.key();
     phone_registry.registered_at = clock.unix_timestamp;
     phone_registry.bump = ctx.bumps.phone_registry;
+    phone_registry.rent_payer = ctx.accounts.payer.key();
 
     national_id_registry.disaster_id = params.disaster_id.clone();
     national_id_registry.national_id = params.national_id.clone();
     national_id_registry.beneficiary = ctx.accounts.authority.key();
     national_id_registry.registered_at = clock.unix_timestamp;
     national_id_registry.bump = ctx.bumps.national_id_registry;
+    national_id_registry.rent_payer = ctx.accounts.payer.key();
 
     if let Some(ngo_key) = field_worker.ngo {
         let ngo_account = ctx
             .remaining_accounts
-            .get(0)
+            .first()
             .ok_or(ErrorCode::ResourceNotFound)?;
 
         require!(ngo_account.key() == ngo_key, ErrorCode::InvalidAccountOwner);
@@ -220,6 +237,78 @@ pub fn handler<'info>(
             household_id.len() <= Beneficiary::MAX_HOUSEHOLD_ID_LEN,
             ErrorCode::StringTooLong
         );
+
+        let registry_info = ctx
+            .accounts
+            .household_registry
+            .as_ref()
+            .ok_or(ErrorCode::ResourceNotFound)?
+            .to_account_info();
+
+        let (registry_pda, registry_bump) = Pubkey::find_program_address(
+            &[
+                b"household-registry",
+                params.disaster_id.as_bytes(),
+                household_id.as_bytes(),
+            ],
+            ctx.program_id,
+        );
+
+        require!(
+            registry_info.key() == registry_pda,
+            ErrorCode::AccountDataMismatch
+        );
+
+        let mut household_registry = if registry_info.data_is_empty() {
+            create_pda_account(
+                &registry_info,
+                &ctx.accounts.payer.to_account_info(),
+                &ctx.accounts.system_program.to_account_info(),
+                &[
+                    b"household-registry",
+                    params.disaster_id.as_bytes(),
+                    household_id.as_bytes(),
+                    &[registry_bump],
+                ],
+                Rent::get()?.minimum_balance(HouseholdRegistry::SPACE),
+                HouseholdRegistry::SPACE,
+                ctx.program_id,
+            )?;
+
+            HouseholdRegistry {
+                disaster_id: params.disaster_id.clone(),
+                household_id: household_id.clone(),
+                members: Vec::new(),
+                pools: Vec::new(),
+                created_at: clock.unix_timestamp,
+                bump: registry_bump,
+            }
+        } else {
+            require!(
+                registry_info.owner == ctx.program_id,
+                ErrorCode::InvalidAccountOwner
+            );
+            let data = registry_info.try_borrow_data()?;
+            HouseholdRegistry::try_deserialize(&mut &data[..])?
+        };
+
+        require!(
+            household_registry.members.len() < HouseholdRegistry::MAX_MEMBERS,
+            ErrorCode::HouseholdMaxMembersReached
+        );
+
+        household_registry
+            .members
+            .push(ctx.accounts.authority.key());
+
+        let mut data = registry_info.try_borrow_mut_data()?;
+        household_registry.try_serialize(&mut &mut data[..])?;
+
+        msg!(
+            "Household {} members: {}",
+            household_id,
+            household_registry.members.len()
+        );
     }
 
     require!(
@@ -244,6 +333,96 @@ pub fn handler<'info>(
         ErrorCode::InvalidLocationCoordinates
     );
 
+    require_field_worker_coverage(
+        field_worker,
+        ctx.accounts.field_worker_assignment.as_deref(),
+        disaster,
+        &params.location,
+    )?;
+
+    let mut density_flag: Option<String> = None;
+
+    if let Some(ref location_registry_account) = ctx.accounts.location_registry {
+        let registry_info = location_registry_account.to_account_info();
+        let geohash = params.location.geohash(Location::GEOHASH_PRECISION);
+
+        let (registry_pda, registry_bump) = Pubkey::find_program_address(
+            &[
+                b"location-registry",
+                params.disaster_id.as_bytes(),
+                geohash.as_bytes(),
+            ],
+            ctx.program_id,
+        );
+
+        require!(
+            registry_info.key() == registry_pda,
+            ErrorCode::AccountDataMismatch
+        );
+
+        let mut location_registry = if registry_info.data_is_empty() {
+            create_pda_account(
+                &registry_info,
+                &ctx.accounts.payer.to_account_info(),
+                &ctx.accounts.system_program.to_account_info(),
+                &[
+                    b"location-registry",
+                    params.disaster_id.as_bytes(),
+                    geohash.as_bytes(),
+                    &[registry_bump],
+                ],
+                Rent::get()?.minimum_balance(LocationRegistry::SPACE),
+                LocationRegistry::SPACE,
+                ctx.program_id,
+            )?;
+
+            LocationRegistry {
+                disaster_id: params.disaster_id.clone(),
+                geohash: geohash.clone(),
+                registration_count: 0,
+                created_at: clock.unix_timestamp,
+                last_registration_at: clock.unix_timestamp,
+                bump: registry_bump,
+            }
+        } else {
+            require!(
+                registry_info.owner == ctx.program_id,
+                ErrorCode::InvalidAccountOwner
+            );
+            let data = registry_info.try_borrow_data()?;
+            LocationRegistry::try_deserialize(&mut &data[..])?
+        };
+
+        location_registry.registration_count = location_registry
+            .registration_count
+            .checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        location_registry.last_registration_at = clock.unix_timestamp;
+
+        let mut data = registry_info.try_borrow_mut_data()?;
+        location_registry.try_serialize(&mut &mut data[..])?;
+
+        if config.location_density_threshold > 0
+            && location_registry.registration_count > config.location_density_threshold
+        {
+            density_flag = Some(format!(
+                "Too many registrations from same location ({} in cell {})",
+                location_registry.registration_count, geohash
+            ));
+        }
+
+        msg!(
+            "Location cell {} registrations: {}",
+            geohash,
+            location_registry.registration_count
+        );
+    } else {
+        require!(
+            config.location_density_threshold == 0,
+            ErrorCode::ResourceNotFound
+        );
+    }
+
     beneficiary.authority = ctx.accounts.authority.key();
     beneficiary.disaster_id = params.disaster_id;
     beneficiary.name = params.name;
@@ -253,6 +432,7 @@ pub fn handler<'info>(
     beneficiary.damage_severity = params.damage_severity;
     beneficiary.verification_status = VerificationStatus::Pending;
     beneficiary.verifier_approvals = Vec::new();
+    beneficiary.verifier_ngos = Vec::new();
     beneficiary.ipfs_document_hash = params.ipfs_document_hash;
     beneficiary.household_id = params.household_id;
     beneficiary.national_id = params.national_id;
@@ -273,7 +453,15 @@ pub fn handler<'info>(
     beneficiary.flagged_at = None;
     beneficiary.admin_notes = None;
 
+    if let Some(reason) = density_flag {
+        beneficiary.verification_status = VerificationStatus::Flagged;
+        beneficiary.flagged_reason = Some(reason);
+        beneficiary.flagged_by = Some(ctx.accounts.field_worker_authority.key());
+        beneficiary.flagged_at = Some(clock.unix_timestamp);
+    }
+
     beneficiary.bump = ctx.bumps.beneficiary;
+    beneficiary.activity_count = 0;
 
     disaster.total_beneficiaries = disaster
         .total_beneficiaries
@@ -303,14 +491,56 @@ pub fn handler<'info>(
         "Beneficiary: {} | Disaster: {} | Family: {}",
         beneficiary.name, beneficiary.disaster_id, beneficiary.family_size
     );
+    activity_log.rent_payer = ctx.accounts.payer.key();
     activity_log.bump = ctx.bumps.activity_log;
+    activity_log.record_sequence(beneficiary.key(), &mut beneficiary.activity_count)?;
+
+    emit!(BeneficiaryRegistered {
+        beneficiary: beneficiary.key(),
+        authority: beneficiary.authority,
+        disaster_id: beneficiary.disaster_id.clone(),
+        name: beneficiary.name.clone(),
+        family_size: beneficiary.family_size,
+        damage_severity: beneficiary.damage_severity,
+        verification_status: beneficiary.verification_status.clone(),
+        field_worker: ctx.accounts.field_worker_authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
 
     msg!("Beneficiary registered successfully");
     msg!("Name: {}", beneficiary.name);
     msg!("Disaster: {}", beneficiary.disaster_id);
     msg!("Family size: {}", beneficiary.family_size);
     msg!("Damage severity: {}/10", beneficiary.damage_severity);
-    msg!("Status: Pending verification");
+    if beneficiary.verification_status == VerificationStatus::Flagged {
+        msg!("⚠ Status: Flagged for review (location density exceeded)");
+    } else {
+        msg!("Status: Pending verification");
+    }
+
+    Ok(())
+}
+
+pub(crate) fn require_field_worker_coverage(
+    field_worker: &FieldWorker,
+    assignment: Option<&Account<FieldWorkerAssignment>>,
+    disaster: &DisasterEvent,
+    location: &Location,
+) -> Result<()> {
+    if field_worker.covers_location(location) {
+        return Ok(());
+    }
+
+    let assigned_to_disaster = assignment.is_some_and(|assignment| {
+        assignment.field_worker == field_worker.authority
+            && assignment.disaster_id == disaster.event_id
+            && field_worker.ngo == Some(assignment.ngo)
+    });
+
+    require!(
+        assigned_to_disaster && disaster.covers_location(location),
+        ErrorCode::FieldWorkerNotAssignedToDistrict
+    );
 
     Ok(())
 }
@@ -318,7 +548,6 @@ pub fn handler<'info>(
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct UpdateBeneficiaryParams {
     pub name: Option<String>,
-    pub phone_number: Option<String>,
     pub location: Option<Location>,
     pub family_size: Option<u8>,
     pub damage_severity: Option<u8>,
@@ -361,6 +590,7 @@ pub fn update_beneficiary_handler(
     _disaster_id: String,
     params: UpdateBeneficiaryParams,
 ) -> Result<()> {
+    let clock = Clock::get()?;
     let beneficiary = &mut ctx.accounts.beneficiary;
 
     require!(
@@ -377,15 +607,6 @@ pub fn update_beneficiary_handler(
         msg!("Beneficiary name updated");
     }
 
-    if let Some(phone_number) = params.phone_number {
-        require!(
-            phone_number.len() <= Beneficiary::MAX_PHONE_LEN,
-            ErrorCode::StringTooLong
-        );
-        beneficiary.phone_number = phone_number;
-        msg!("Beneficiary phone number updated");
-    }
-
     if let Some(location) = params.location {
         require!(
             location.latitude >= -90.0 && location.latitude <= 90.0,
@@ -401,7 +622,7 @@ pub fn update_beneficiary_handler(
 
     if let Some(family_size) = params.family_size {
         require!(
-            family_size >= 1 && family_size <= 50,
+            (1..=50).contains(&family_size),
             ErrorCode::InvalidFamilySize
         );
         beneficiary.family_size = family_size;
@@ -410,7 +631,7 @@ pub fn update_beneficiary_handler(
 
     if let Some(damage_severity) = params.damage_severity {
         require!(
-            damage_severity >= 1 && damage_severity <= 10,
+            (1..=10).contains(&damage_severity),
             ErrorCode::InvalidDamageSeverity
         );
         beneficiary.damage_severity = damage_severity;
@@ -471,7 +692,406 @@ pub fn update_beneficiary_handler(
         msg!("Beneficiary special needs updated");
     }
 
+    emit!(BeneficiaryUpdated {
+        beneficiary: beneficiary.key(),
+        authority: beneficiary.authority,
+        disaster_id: beneficiary.disaster_id.clone(),
+        name: beneficiary.name.clone(),
+        family_size: beneficiary.family_size,
+        damage_severity: beneficiary.damage_severity,
+        field_worker: ctx.accounts.field_worker_authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
     msg!("Beneficiary profile updated successfully");
 
     Ok(())
 }
+
+#[derive(Accounts)]
+#[instruction(beneficiary_authority: Pubkey, disaster_id: String, new_phone_number: String)]
+pub struct ChangeBeneficiaryPhone<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"beneficiary",
+            beneficiary_authority.as_ref(),
+            disaster_id.as_bytes()
+        ],
+        bump = beneficiary.bump,
+        constraint = beneficiary.registered_by == field_worker_authority.key() @ ErrorCode::UnauthorizedFieldWorker
+    )]
+    pub beneficiary: Box<Account<'info, Beneficiary>>,
+
+    #[account(
+        mut,
+        seeds = [b"field-worker", field_worker_authority.key().as_ref()],
+        bump = field_worker.bump,
+        constraint = field_worker.is_active @ ErrorCode::FieldWorkerNotActive
+    )]
+    pub field_worker: Box<Account<'info, FieldWorker>>,
+
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [
+            b"phone-registry",
+            disaster_id.as_bytes(),
+            beneficiary.phone_number.as_bytes()
+        ],
+        bump = old_phone_registry.bump,
+        constraint = old_phone_registry.beneficiary == beneficiary_authority @ ErrorCode::AccountDataMismatch
+    )]
+    pub old_phone_registry: Box<Account<'info, PhoneRegistry>>,
+
+    #[account(
+        init,
+        payer = field_worker_authority,
+        space = PhoneRegistry::SPACE,
+        seeds = [
+            b"phone-registry",
+            disaster_id.as_bytes(),
+            new_phone_number.as_bytes()
+        ],
+        bump
+    )]
+    pub new_phone_registry: Box<Account<'info, PhoneRegistry>>,
+
+    /// CHECK: Must be the account that paid rent for the old phone registry
+    #[account(
+        mut,
+        address = old_phone_registry.rent_payer @ ErrorCode::AccountDataMismatch
+    )]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = field_worker_authority,
+        space = ActivityLog::SPACE,
+        seeds = [
+            b"activity",
+            beneficiary.key().as_ref(),
+            &beneficiary.activity_count.to_le_bytes()
+        ],
+        bump
+    )]
+    pub activity_log: Account<'info, ActivityLog>,
+
+    #[account(mut)]
+    pub field_worker_authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn change_beneficiary_phone_handler(
+    ctx: Context<ChangeBeneficiaryPhone>,
+    beneficiary_authority: Pubkey,
+    disaster_id: String,
+    new_phone_number: String,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    let beneficiary = &mut ctx.accounts.beneficiary;
+    let field_worker = &mut ctx.accounts.field_worker;
+    let new_phone_registry = &mut ctx.accounts.new_phone_registry;
+
+    require!(
+        beneficiary.verification_status != VerificationStatus::Flagged,
+        ErrorCode::BeneficiaryFlagged
+    );
+
+    require!(
+        new_phone_number.len() <= Beneficiary::MAX_PHONE_LEN,
+        ErrorCode::StringTooLong
+    );
+
+    require!(!new_phone_number.is_empty(), ErrorCode::InvalidPhoneNumber);
+
+    let old_phone_number =
+        std::mem::replace(&mut beneficiary.phone_number, new_phone_number.clone());
+
+    new_phone_registry.disaster_id = disaster_id;
+    new_phone_registry.phone_number = new_phone_number;
+    new_phone_registry.beneficiary = beneficiary_authority;
+    new_phone_registry.registered_at = clock.unix_timestamp;
+    new_phone_registry.bump = ctx.bumps.new_phone_registry;
+    new_phone_registry.rent_payer = ctx.accounts.field_worker_authority.key();
+
+    field_worker.last_activity_at = clock.unix_timestamp;
+
+    let activity_log = &mut ctx.accounts.activity_log;
+    activity_log.action_type = ActivityType::BeneficiaryUpdated;
+    activity_log.actor = ctx.accounts.field_worker_authority.key();
+    activity_log.target = beneficiary.key();
+    activity_log.amount = None;
+    activity_log.timestamp = clock.unix_timestamp;
+    activity_log.metadata = format!(
+        "Phone changed | Beneficiary: {} | Old: {} | New: {}",
+        beneficiary.name, old_phone_number, beneficiary.phone_number
+    );
+    activity_log.rent_payer = ctx.accounts.field_worker_authority.key();
+    activity_log.bump = ctx.bumps.activity_log;
+    activity_log.record_sequence(beneficiary.key(), &mut beneficiary.activity_count)?;
+
+    emit!(BeneficiaryPhoneChanged {
+        beneficiary: beneficiary.key(),
+        name: beneficiary.name.clone(),
+        old_phone_number: old_phone_number.clone(),
+        new_phone_number: beneficiary.phone_number.clone(),
+        field_worker: ctx.accounts.field_worker_authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Beneficiary phone number changed successfully");
+    msg!("Beneficiary: {}", beneficiary.name);
+    msg!("Old phone registry closed: {}", old_phone_number);
+
+    Ok(())
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CorrectNationalIdParams {
+    pub new_national_id: String,
+    pub reason: String,
+}
+
+#[derive(Accounts)]
+#[instruction(beneficiary_authority: Pubkey, disaster_id: String, params: CorrectNationalIdParams)]
+pub struct CorrectBeneficiaryNationalId<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"beneficiary",
+            beneficiary_authority.as_ref(),
+            disaster_id.as_bytes()
+        ],
+        bump = beneficiary.bump,
+    )]
+    pub beneficiary: Box<Account<'info, Beneficiary>>,
+
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [
+            b"national-id-registry",
+            disaster_id.as_bytes(),
+            beneficiary.national_id.as_bytes()
+        ],
+        bump = old_national_id_registry.bump,
+        constraint = old_national_id_registry.beneficiary == beneficiary_authority @ ErrorCode::AccountDataMismatch
+    )]
+    pub old_national_id_registry: Box<Account<'info, NationalIdRegistry>>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = NationalIdRegistry::SPACE,
+        seeds = [
+            b"national-id-registry",
+            disaster_id.as_bytes(),
+            params.new_national_id.as_bytes()
+        ],
+        bump
+    )]
+    pub new_national_id_registry: Box<Account<'info, NationalIdRegistry>>,
+
+    /// CHECK: Must be the account that paid rent for the old national ID registry
+    #[account(
+        mut,
+        address = old_national_id_registry.rent_payer @ ErrorCode::AccountDataMismatch
+    )]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.is_admin_or_manager(&admin.key()) @ ErrorCode::UnauthorizedAdminOrManager
+    )]
+    pub config: Box<Account<'info, PlatformConfig>>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = AdminAction::SPACE,
+        seeds = [
+            b"admin-action",
+            admin.key().as_ref(),
+            &config.admin_action_count.to_le_bytes()
+        ],
+        bump
+    )]
+    pub admin_action: Account<'info, AdminAction>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn correct_beneficiary_national_id_handler(
+    ctx: Context<CorrectBeneficiaryNationalId>,
+    beneficiary_authority: Pubkey,
+    disaster_id: String,
+    params: CorrectNationalIdParams,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    let beneficiary = &mut ctx.accounts.beneficiary;
+    let new_national_id_registry = &mut ctx.accounts.new_national_id_registry;
+    let admin_action = &mut ctx.accounts.admin_action;
+
+    require!(
+        params.new_national_id.len() <= Beneficiary::MAX_NATIONAL_ID_LEN,
+        ErrorCode::StringTooLong
+    );
+
+    require!(
+        !params.new_national_id.is_empty(),
+        ErrorCode::InvalidNationalId
+    );
+
+    require!(
+        params.reason.len() <= AdminAction::MAX_REASON_LEN,
+        ErrorCode::StringTooLong
+    );
+
+    require!(!params.reason.is_empty(), ErrorCode::InvalidInput);
+
+    let old_national_id =
+        std::mem::replace(&mut beneficiary.national_id, params.new_national_id.clone());
+
+    new_national_id_registry.disaster_id = disaster_id;
+    new_national_id_registry.national_id = params.new_national_id;
+    new_national_id_registry.beneficiary = beneficiary_authority;
+    new_national_id_registry.registered_at = clock.unix_timestamp;
+    new_national_id_registry.bump = ctx.bumps.new_national_id_registry;
+    new_national_id_registry.rent_payer = ctx.accounts.admin.key();
+
+    admin_action.action_type = AdminActionType::CorrectNationalId;
+    admin_action.target = beneficiary.key();
+    admin_action.admin = ctx.accounts.admin.key();
+    admin_action.reason = params.reason;
+    admin_action.timestamp = clock.unix_timestamp;
+    admin_action.metadata = format!(
+        "Beneficiary: {} | Old national ID: {} | New national ID: {}",
+        beneficiary.name, old_national_id, beneficiary.national_id
+    );
+    admin_action.bump = ctx.bumps.admin_action;
+    admin_action.record_sequence(&mut ctx.accounts.config.admin_action_count)?;
+
+    emit!(BeneficiaryNationalIdCorrected {
+        beneficiary: beneficiary.key(),
+        name: beneficiary.name.clone(),
+        old_national_id: old_national_id.clone(),
+        new_national_id: beneficiary.national_id.clone(),
+        admin: ctx.accounts.admin.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Beneficiary national ID corrected");
+    msg!("Beneficiary: {}", beneficiary.name);
+    msg!("Old national ID registry closed: {}", old_national_id);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(disaster_id: String, phone_number: String)]
+pub struct ClosePhoneRegistry<'info> {
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [
+            b"phone-registry",
+            disaster_id.as_bytes(),
+            phone_number.as_bytes()
+        ],
+        bump = phone_registry.bump,
+    )]
+    pub phone_registry: Account<'info, PhoneRegistry>,
+
+    #[account(
+        seeds = [b"disaster", disaster_id.as_bytes()],
+        bump = disaster.bump,
+        constraint = disaster.closed_at.is_some() @ ErrorCode::AccountStillInUse
+    )]
+    pub disaster: Account<'info, DisasterEvent>,
+
+    #[account(
+        mut,
+        address = phone_registry.rent_payer @ ErrorCode::AccountDataMismatch
+    )]
+    pub rent_payer: SystemAccount<'info>,
+}
+
+pub fn close_phone_registry_handler(
+    ctx: Context<ClosePhoneRegistry>,
+    disaster_id: String,
+    _phone_number: String,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    emit!(PhoneRegistryClosed {
+        registry: ctx.accounts.phone_registry.key(),
+        disaster_id: disaster_id.clone(),
+        rent_payer: ctx.accounts.rent_payer.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Phone registry closed, rent returned to payer");
+    msg!("Disaster: {}", disaster_id);
+    msg!("Rent payer: {}", ctx.accounts.rent_payer.key());
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(disaster_id: String, national_id: String)]
+pub struct CloseNationalIdRegistry<'info> {
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [
+            b"national-id-registry",
+            disaster_id.as_bytes(),
+            national_id.as_bytes()
+        ],
+        bump = national_id_registry.bump,
+    )]
+    pub national_id_registry: Account<'info, NationalIdRegistry>,
+
+    #[account(
+        seeds = [b"disaster", disaster_id.as_bytes()],
+        bump = disaster.bump,
+        constraint = disaster.closed_at.is_some() @ ErrorCode::AccountStillInUse
+    )]
+    pub disaster: Account<'info, DisasterEvent>,
+
+    #[account(
+        mut,
+        address = national_id_registry.rent_payer @ ErrorCode::AccountDataMismatch
+    )]
+    pub rent_payer: SystemAccount<'info>,
+}
+
+pub fn close_national_id_registry_handler(
+    ctx: Context<CloseNationalIdRegistry>,
+    disaster_id: String,
+    _national_id: String,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    emit!(NationalIdRegistryClosed {
+        registry: ctx.accounts.national_id_registry.key(),
+        disaster_id: disaster_id.clone(),
+        rent_payer: ctx.accounts.rent_payer.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("National ID registry closed, rent returned to payer");
+    msg!("Disaster: {}", disaster_id);
+    msg!("Rent payer: {}", ctx.accounts.rent_payer.key());
+
+    Ok(())
+}
@@ -1,6 +1,12 @@
 use crate::errors::ErrorCode;
+use crate::events::{
+    DisasterClosed, DisasterCreated, DisasterForceCloseProcessed, DisasterReviewDelegationUpdated,
+    DisasterUpdated, PoolClosed,
+};
+use crate::instructions::fund_pool::release_undistributed_to_donors;
 use crate::state::{
-    ActivityLog, ActivityType, DisasterEvent, DisasterType, Location, PlatformConfig, NGO,
+    ActivityLog, ActivityType, AdminAction, AdminActionType, DisasterEvent, DisasterType, FundPool,
+    Location, PlatformConfig, NGO,
 };
 use anchor_lang::prelude::*;
 
@@ -17,7 +23,7 @@ pub struct InitializeDisasterParams {
 }
 
 #[derive(Accounts)]
-#[instruction(params: InitializeDisasterParams, timestamp: i64)]
+#[instruction(params: InitializeDisasterParams)]
 pub struct InitializeDisaster<'info> {
     #[account(
         init,
@@ -41,8 +47,8 @@ pub struct InitializeDisaster<'info> {
         space = ActivityLog::SPACE,
         seeds = [
             b"activity",
-            authority.key().as_ref(),
-            &timestamp.to_le_bytes()
+            disaster.key().as_ref(),
+            &0u64.to_le_bytes()
         ],
         bump
     )]
@@ -54,11 +60,7 @@ pub struct InitializeDisaster<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(
-    ctx: Context<InitializeDisaster>,
-    params: InitializeDisasterParams,
-    _timestamp: i64,
-) -> Result<()> {
+pub fn handler(ctx: Context<InitializeDisaster>, params: InitializeDisasterParams) -> Result<()> {
     let disaster = &mut ctx.accounts.disaster;
     let config = &mut ctx.accounts.config;
     let clock = Clock::get()?;
@@ -179,6 +181,12 @@ pub fn handler(
     disaster.created_at = clock.unix_timestamp;
     disaster.updated_at = clock.unix_timestamp;
     disaster.bump = ctx.bumps.disaster;
+    disaster.active_pool_count = 0;
+    disaster.verification_window_seconds = 0;
+    disaster.review_delegated = false;
+    disaster.closed_at = None;
+    disaster.activity_count = 0;
+    disaster.total_aid_distributed_usd = 0;
 
     config.total_disasters = config
         .total_disasters
@@ -196,7 +204,20 @@ pub fn handler(
         "Disaster: {} | Severity: {} | Affected: {}",
         disaster.name, disaster.severity, disaster.estimated_affected_population
     );
+    activity_log.rent_payer = ctx.accounts.authority.key();
     activity_log.bump = ctx.bumps.activity_log;
+    activity_log.record_sequence(disaster.key(), &mut disaster.activity_count)?;
+
+    emit!(DisasterCreated {
+        disaster: disaster.key(),
+        event_id: disaster.event_id.clone(),
+        name: disaster.name.clone(),
+        event_type: disaster.event_type.clone(),
+        severity: disaster.severity,
+        estimated_affected_population: disaster.estimated_affected_population,
+        authority: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
 
     msg!("Disaster event initialized successfully");
     msg!("Event ID: {}", disaster.event_id);
@@ -218,10 +239,11 @@ pub struct UpdateDisasterParams {
     pub affected_areas: Option<Vec<String>>,
     pub description: Option<String>,
     pub estimated_affected_population: Option<u32>,
+    pub verification_window_seconds: Option<i64>,
 }
 
 #[derive(Accounts)]
-#[instruction(event_id: String, timestamp: i64)]
+#[instruction(event_id: String)]
 pub struct UpdateDisaster<'info> {
     #[account(
         mut,
@@ -237,8 +259,8 @@ pub struct UpdateDisaster<'info> {
         space = ActivityLog::SPACE,
         seeds = [
             b"activity",
-            authority.key().as_ref(),
-            &timestamp.to_le_bytes()
+            disaster.key().as_ref(),
+            &disaster.activity_count.to_le_bytes()
         ],
         bump
     )]
@@ -253,7 +275,6 @@ pub struct UpdateDisaster<'info> {
 pub fn update_disaster_handler(
     ctx: Context<UpdateDisaster>,
     _event_id: String,
-    _timestamp: i64,
     params: UpdateDisasterParams,
 ) -> Result<()> {
     let disaster = &mut ctx.accounts.disaster;
@@ -270,7 +291,7 @@ pub fn update_disaster_handler(
 
     if let Some(severity) = params.severity {
         require!(
-            severity >= 1 && severity <= 10,
+            (1..=10).contains(&severity),
             ErrorCode::InvalidDisasterSeverity
         );
         disaster.severity = severity;
@@ -278,6 +299,10 @@ pub fn update_disaster_handler(
     }
 
     if let Some(is_active) = params.is_active {
+        require!(
+            !is_active || disaster.closed_at.is_none(),
+            ErrorCode::CannotModifyClosedDisaster
+        );
         disaster.is_active = is_active;
         msg!("Disaster active status updated to: {}", is_active);
     }
@@ -313,6 +338,12 @@ pub fn update_disaster_handler(
         msg!("Estimated affected population updated to: {}", population);
     }
 
+    if let Some(window) = params.verification_window_seconds {
+        require!(window >= 0, ErrorCode::InvalidInput);
+        disaster.verification_window_seconds = window;
+        msg!("Verification window updated to: {}s", window);
+    }
+
     disaster.updated_at = clock.unix_timestamp;
 
     let activity_log = &mut ctx.accounts.activity_log;
@@ -322,7 +353,20 @@ pub fn update_disaster_handler(
     activity_log.amount = None;
     activity_log.timestamp = clock.unix_timestamp;
     activity_log.metadata = format!("Disaster: {} | Updated", disaster.name);
+    activity_log.rent_payer = ctx.accounts.authority.key();
     activity_log.bump = ctx.bumps.activity_log;
+    activity_log.record_sequence(disaster.key(), &mut disaster.activity_count)?;
+
+    emit!(DisasterUpdated {
+        disaster: disaster.key(),
+        event_id: disaster.event_id.clone(),
+        name: disaster.name.clone(),
+        severity: disaster.severity,
+        is_active: disaster.is_active,
+        estimated_affected_population: disaster.estimated_affected_population,
+        authority: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
 
     msg!("Disaster event updated successfully");
 
@@ -330,7 +374,7 @@ pub fn update_disaster_handler(
 }
 
 #[derive(Accounts)]
-#[instruction(event_id: String, timestamp: i64)]
+#[instruction(event_id: String)]
 pub struct CloseDisaster<'info> {
     #[account(
         mut,
@@ -346,8 +390,8 @@ pub struct CloseDisaster<'info> {
         space = ActivityLog::SPACE,
         seeds = [
             b"activity",
-            authority.key().as_ref(),
-            &timestamp.to_le_bytes()
+            disaster.key().as_ref(),
+            &disaster.activity_count.to_le_bytes()
         ],
         bump
     )]
@@ -359,17 +403,19 @@ pub struct CloseDisaster<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn close_disaster_handler(
-    ctx: Context<CloseDisaster>,
-    _event_id: String,
-    _timestamp: i64,
-) -> Result<()> {
+pub fn close_disaster_handler(ctx: Context<CloseDisaster>, _event_id: String) -> Result<()> {
     let disaster = &mut ctx.accounts.disaster;
     let clock = Clock::get()?;
 
     require!(disaster.is_active, ErrorCode::DisasterNotActive);
 
+    require!(
+        disaster.active_pool_count == 0,
+        ErrorCode::DisasterHasActivePools
+    );
+
     disaster.is_active = false;
+    disaster.closed_at = Some(clock.unix_timestamp);
     disaster.updated_at = clock.unix_timestamp;
 
     let activity_log = &mut ctx.accounts.activity_log;
@@ -382,7 +428,19 @@ pub fn close_disaster_handler(
         "Disaster: {} | Beneficiaries: {} | Aid: {}",
         disaster.name, disaster.total_beneficiaries, disaster.total_aid_distributed
     );
+    activity_log.rent_payer = ctx.accounts.authority.key();
     activity_log.bump = ctx.bumps.activity_log;
+    activity_log.record_sequence(disaster.key(), &mut disaster.activity_count)?;
+
+    emit!(DisasterClosed {
+        disaster: disaster.key(),
+        event_id: disaster.event_id.clone(),
+        total_beneficiaries: disaster.total_beneficiaries,
+        verified_beneficiaries: disaster.verified_beneficiaries,
+        total_aid_distributed: disaster.total_aid_distributed,
+        closed_by: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
 
     msg!("Disaster event closed successfully");
     msg!("Event ID: {}", disaster.event_id);
@@ -395,3 +453,259 @@ pub fn close_disaster_handler(
 
     Ok(())
 }
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ForceCloseDisasterParams {
+    pub reason: String,
+}
+
+#[derive(Accounts)]
+#[instruction(event_id: String, params: ForceCloseDisasterParams)]
+pub struct ForceCloseDisaster<'info> {
+    #[account(
+        mut,
+        seeds = [b"disaster", event_id.as_bytes()],
+        bump = disaster.bump,
+    )]
+    pub disaster: Account<'info, DisasterEvent>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.is_admin(&admin.key()) @ ErrorCode::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, PlatformConfig>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = AdminAction::SPACE,
+        seeds = [
+            b"admin-action",
+            admin.key().as_ref(),
+            &config.admin_action_count.to_le_bytes()
+        ],
+        bump
+    )]
+    pub admin_action: Account<'info, AdminAction>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn force_close_disaster_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ForceCloseDisaster<'info>>,
+    event_id: String,
+    params: ForceCloseDisasterParams,
+) -> Result<()> {
+    let disaster = &mut ctx.accounts.disaster;
+    let admin_action = &mut ctx.accounts.admin_action;
+    let clock = Clock::get()?;
+
+    require!(disaster.is_active, ErrorCode::DisasterNotActive);
+
+    require!(
+        params.reason.len() <= AdminAction::MAX_REASON_LEN,
+        ErrorCode::StringTooLong
+    );
+
+    require!(!params.reason.is_empty(), ErrorCode::InvalidInput);
+
+    require!(
+        ctx.remaining_accounts.len() <= FundPool::MAX_CASCADE_CLOSE,
+        ErrorCode::BatchSizeTooLarge
+    );
+
+    let mut pools_closed: u32 = 0;
+    let mut released_total: u64 = 0;
+
+    for pool_info in ctx.remaining_accounts.iter() {
+        let mut pool: Account<FundPool> = Account::try_from(pool_info)?;
+
+        require!(pool.disaster_id == event_id, ErrorCode::AccountDataMismatch);
+
+        if pool.closed_at.is_some() {
+            msg!("Pool {}: already closed, skipping", pool.name);
+            continue;
+        }
+
+        pool.is_active = false;
+        pool.closed_at = Some(clock.unix_timestamp);
+
+        let released = release_undistributed_to_donors(&mut pool)?;
+        released_total = released_total
+            .checked_add(released)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        pool.exit(ctx.program_id)?;
+
+        disaster.active_pool_count = disaster
+            .active_pool_count
+            .checked_sub(1)
+            .ok_or(ErrorCode::ArithmeticUnderflow)?;
+        pools_closed += 1;
+
+        emit!(PoolClosed {
+            pool: pool.key(),
+            disaster_id: pool.disaster_id.clone(),
+            pool_id: pool.pool_id.clone(),
+            name: pool.name.clone(),
+            total_deposited: pool.total_deposited,
+            total_distributed: pool.total_distributed,
+            total_claimed: pool.total_claimed,
+            released_for_refunds: released,
+            closed_by: ctx.accounts.admin.key(),
+            timestamp: clock.unix_timestamp,
+        });
+
+        msg!(
+            "Pool {} closed, released for refunds: {}",
+            pool.name,
+            released
+        );
+    }
+
+    if disaster.active_pool_count == 0 {
+        disaster.is_active = false;
+        disaster.closed_at = Some(clock.unix_timestamp);
+
+        emit!(DisasterClosed {
+            disaster: disaster.key(),
+            event_id: disaster.event_id.clone(),
+            total_beneficiaries: disaster.total_beneficiaries,
+            verified_beneficiaries: disaster.verified_beneficiaries,
+            total_aid_distributed: disaster.total_aid_distributed,
+            closed_by: ctx.accounts.admin.key(),
+            timestamp: clock.unix_timestamp,
+        });
+
+        msg!("Disaster event closed");
+    } else {
+        msg!(
+            "Pools still open: {}, disaster remains active",
+            disaster.active_pool_count
+        );
+    }
+
+    disaster.updated_at = clock.unix_timestamp;
+
+    admin_action.action_type = AdminActionType::ForceCloseDisaster;
+    admin_action.target = disaster.key();
+    admin_action.admin = ctx.accounts.admin.key();
+    admin_action.reason = params.reason;
+    admin_action.timestamp = clock.unix_timestamp;
+    admin_action.metadata = format!(
+        "Disaster: {} | Pools closed: {} | Pools remaining: {} | Released for refunds: {}",
+        disaster.event_id, pools_closed, disaster.active_pool_count, released_total
+    );
+    admin_action.bump = ctx.bumps.admin_action;
+    admin_action.record_sequence(&mut ctx.accounts.config.admin_action_count)?;
+
+    emit!(DisasterForceCloseProcessed {
+        disaster: disaster.key(),
+        event_id: disaster.event_id.clone(),
+        pools_closed,
+        pools_remaining: disaster.active_pool_count,
+        released_for_refunds: released_total,
+        reason: admin_action.reason.clone(),
+        admin: ctx.accounts.admin.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Cascading disaster close processed");
+    msg!("Event ID: {}", disaster.event_id);
+    msg!("Pools closed: {}", pools_closed);
+
+    Ok(())
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct DelegateDisasterReviewParams {
+    pub enabled: bool,
+    pub reason: String,
+}
+
+#[derive(Accounts)]
+#[instruction(event_id: String, params: DelegateDisasterReviewParams)]
+pub struct DelegateDisasterReview<'info> {
+    #[account(
+        mut,
+        seeds = [b"disaster", event_id.as_bytes()],
+        bump = disaster.bump,
+    )]
+    pub disaster: Account<'info, DisasterEvent>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.is_admin_or_manager(&admin.key()) @ ErrorCode::UnauthorizedAdminOrManager
+    )]
+    pub config: Account<'info, PlatformConfig>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = AdminAction::SPACE,
+        seeds = [
+            b"admin-action",
+            admin.key().as_ref(),
+            &config.admin_action_count.to_le_bytes()
+        ],
+        bump
+    )]
+    pub admin_action: Account<'info, AdminAction>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn delegate_disaster_review_handler(
+    ctx: Context<DelegateDisasterReview>,
+    _event_id: String,
+    params: DelegateDisasterReviewParams,
+) -> Result<()> {
+    let disaster = &mut ctx.accounts.disaster;
+    let admin_action = &mut ctx.accounts.admin_action;
+    let clock = Clock::get()?;
+
+    require!(
+        params.reason.len() <= AdminAction::MAX_REASON_LEN,
+        ErrorCode::StringTooLong
+    );
+
+    disaster.review_delegated = params.enabled;
+    disaster.updated_at = clock.unix_timestamp;
+
+    admin_action.action_type = AdminActionType::DelegateDisasterReview;
+    admin_action.target = disaster.key();
+    admin_action.admin = ctx.accounts.admin.key();
+    admin_action.reason = params.reason;
+    admin_action.timestamp = clock.unix_timestamp;
+    admin_action.metadata = format!(
+        "Disaster: {} | Declared by: {} | Review delegated: {}",
+        disaster.event_id, disaster.authority, params.enabled
+    );
+    admin_action.bump = ctx.bumps.admin_action;
+    admin_action.record_sequence(&mut ctx.accounts.config.admin_action_count)?;
+
+    emit!(DisasterReviewDelegationUpdated {
+        disaster: disaster.key(),
+        event_id: disaster.event_id.clone(),
+        declaring_authority: disaster.authority,
+        enabled: params.enabled,
+        admin: ctx.accounts.admin.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Disaster review delegation updated");
+    msg!("Event ID: {}", disaster.event_id);
+    msg!("Delegated to declaring authority: {}", params.enabled);
+
+    Ok(())
+}
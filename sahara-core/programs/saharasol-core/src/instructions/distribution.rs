@@ -1,10 +1,18 @@
 use crate::errors::ErrorCode;
+use crate::events::{
+    ClaimDeadlineExtended, DistributionClosed, DistributionCreated, ExpiredDistributionReclaimed,
+    FundsClaimed, ReclaimedFundsRouted, RedistributionClaimed,
+};
+use crate::instructions::fund_pool::transfer_and_measure;
+use crate::instructions::price_feed::claim_usd_value;
 use crate::state::{
-    ActivityLog, ActivityType, Beneficiary, DisasterEvent, Distribution, FundPool,
-    PoolRegistration, VerificationStatus,
+    ActivityLog, ActivityType, Beneficiary, DisasterEvent, Distribution, FieldWorker, FundPool,
+    PlatformConfig, PoolRegistration, PriceFeed, ReclaimDestination, StandInOracle, TokenStats,
+    VerificationStatus, NGO,
 };
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use anchor_lang::system_program;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct DistributeFromPoolParams {
@@ -47,7 +55,7 @@ pub struct DistributeFromPool<'info> {
         seeds = [
             b"activity",
             pool.key().as_ref(),
-            beneficiary.key().as_ref()
+            &pool.activity_count.to_le_bytes()
         ],
         bump
     )]
@@ -78,6 +86,7 @@ pub struct DistributeFromPool<'info> {
     pub authority: Signer<'info>,
 
     #[account(
+        mut,
         seeds = [
             b"pool-registration",
             pool.key().as_ref(),
@@ -103,25 +112,88 @@ pub fn handler(
     let distribution = &mut ctx.accounts.distribution;
     let beneficiary = &ctx.accounts.beneficiary;
     let disaster = &mut ctx.accounts.disaster;
-    let pool_registration = &ctx.accounts.pool_registration;
+    let pool_registration = &mut ctx.accounts.pool_registration;
 
     require!(
         pool.registration_locked,
         ErrorCode::PoolRegistrationNotLocked
     );
 
-    let allocation_weight = pool_registration.allocation_weight;
+    let pool_key = pool.key();
+    allocate_distribution(
+        pool,
+        pool_key,
+        distribution,
+        beneficiary.key(),
+        pool_registration.allocation_weight,
+        clock.unix_timestamp,
+    )?;
+    distribution.bump = ctx.bumps.distribution;
+    distribution.rent_payer = ctx.accounts.authority.key();
+    pool_registration.is_distributed = true;
+
+    let total_allocation = distribution.amount_allocated;
+    let amount_immediate = distribution.amount_immediate;
+    let amount_locked = distribution.amount_locked;
+    let unlock_time = distribution.unlock_time;
+
+    disaster.updated_at = clock.unix_timestamp;
+
+    let activity_log = &mut ctx.accounts.activity_log;
+    activity_log.action_type = ActivityType::FundsDistributed;
+    activity_log.actor = ctx.accounts.authority.key();
+    activity_log.target = beneficiary.key();
+    activity_log.amount = Some(total_allocation);
+    activity_log.timestamp = clock.unix_timestamp;
+    activity_log.metadata = format!(
+        "Pool: {} | Beneficiary: {} | Amount: {} | Immediate: {} | Locked: {}",
+        pool.name, beneficiary.name, total_allocation, amount_immediate, amount_locked
+    );
+    activity_log.rent_payer = ctx.accounts.authority.key();
+    activity_log.bump = ctx.bumps.activity_log;
+    activity_log.record_sequence(pool.key(), &mut pool.activity_count)?;
+
+    emit!(DistributionCreated {
+        distribution: distribution.key(),
+        pool: pool_key,
+        beneficiary: beneficiary.key(),
+        amount_allocated: total_allocation,
+        amount_immediate,
+        amount_locked,
+        unlock_time,
+        claim_deadline: distribution.claim_deadline,
+        authority: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Distribution created successfully");
+    msg!("Beneficiary: {}", beneficiary.name);
+    msg!("Total allocated: {}", total_allocation);
+    msg!("Immediate: {}", amount_immediate);
+    msg!("Locked: {}", amount_locked);
+    if let Some(unlock) = unlock_time {
+        msg!("Unlock time: {}", unlock);
+    }
+
+    Ok(())
+}
 
+fn allocate_distribution(
+    pool: &mut FundPool,
+    pool_key: Pubkey,
+    distribution: &mut Distribution,
+    beneficiary_key: Pubkey,
+    allocation_weight: u64,
+    now: i64,
+) -> Result<()> {
     let total_allocation = if pool.total_allocation_weight > 0 {
         let numerator = (pool.total_deposited as u128)
             .checked_mul(allocation_weight as u128)
             .ok_or(ErrorCode::ArithmeticOverflow)?;
 
-        let allocation = numerator
+        numerator
             .checked_div(pool.total_allocation_weight as u128)
-            .ok_or(ErrorCode::DivisionByZero)? as u64;
-
-        allocation
+            .ok_or(ErrorCode::DivisionByZero)? as u64
     } else {
         pool.total_deposited
             .checked_div(pool.registered_beneficiary_count.max(1) as u64)
@@ -138,32 +210,46 @@ pub fn handler(
         .checked_sub(amount_immediate)
         .ok_or(ErrorCode::ArithmeticUnderflow)?;
 
-    let unlock_time = if amount_locked > 0 {
-        pool.time_lock_duration
-            .map(|duration| clock.unix_timestamp + duration)
-    } else {
+    let unlock_time = if amount_locked == 0 {
         None
+    } else if let Some(last_tranche) = pool.vesting_schedule.last() {
+        Some(
+            now.checked_add(last_tranche.unlock_offset)
+                .ok_or(ErrorCode::ArithmeticOverflow)?,
+        )
+    } else {
+        pool.time_lock_duration.map(|duration| now + duration)
     };
 
-    distribution.beneficiary = beneficiary.key();
-    distribution.pool = pool.key();
+    distribution.beneficiary = beneficiary_key;
+    distribution.pool = pool_key;
     distribution.amount_allocated = total_allocation;
     distribution.amount_immediate = amount_immediate;
     distribution.amount_locked = amount_locked;
     distribution.amount_claimed = 0;
     distribution.unlock_time = unlock_time;
-    distribution.created_at = clock.unix_timestamp;
+    distribution.created_at = now;
     distribution.claimed_at = None;
     distribution.locked_claimed_at = None;
     distribution.is_fully_claimed = false;
-    distribution.allocation_weight = allocation_weight as u16;
+    distribution.allocation_weight =
+        u16::try_from(allocation_weight).map_err(|_| ErrorCode::ArithmeticOverflow)?;
     distribution.notes = String::new();
-    distribution.bump = ctx.bumps.distribution;
 
-    distribution.claim_deadline = Some(clock.unix_timestamp + 90 * 24 * 60 * 60);
+    distribution.claim_deadline = Some(
+        now.checked_add(pool.claim_window_seconds)
+            .ok_or(ErrorCode::ArithmeticOverflow)?,
+    );
     distribution.is_expired = false;
     distribution.expired_at = None;
 
+    distribution.redistribution_round_claimed = 0;
+    distribution.redistribution_received = 0;
+
+    distribution.deadline_extension = 0;
+
+    distribution.tranches_claimed = 0;
+
     pool.total_distributed = pool
         .total_distributed
         .checked_add(total_allocation)
@@ -174,34 +260,314 @@ pub fn handler(
         .checked_add(1)
         .ok_or(ErrorCode::ArithmeticOverflow)?;
 
-    disaster.updated_at = clock.unix_timestamp;
+    if pool.beneficiary_count == pool.registered_beneficiary_count {
+        pool.is_distributed = true;
+        pool.distributed_at = Some(now);
+        msg!("All registered beneficiaries allocated, pool marked as distributed");
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(disaster_id: String, pool_id: String)]
+pub struct BatchDistributeFromPool<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"pool",
+            disaster_id.as_bytes(),
+            pool_id.as_bytes()
+        ],
+        bump = pool.bump,
+        constraint = pool.is_active @ ErrorCode::PoolNotActive,
+        constraint = !pool.is_distributed @ ErrorCode::DistributionAlreadyCompleted
+    )]
+    pub pool: Account<'info, FundPool>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = ActivityLog::SPACE,
+        seeds = [
+            b"activity",
+            pool.key().as_ref(),
+            &pool.activity_count.to_le_bytes()
+        ],
+        bump
+    )]
+    pub activity_log: Account<'info, ActivityLog>,
+
+    #[account(
+        mut,
+        seeds = [b"disaster", disaster_id.as_bytes()],
+        bump = disaster.bump,
+    )]
+    pub disaster: Account<'info, DisasterEvent>,
+
+    #[account(
+        mut,
+        constraint = pool.authority == authority.key() @ ErrorCode::UnauthorizedModification
+    )]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn batch_distribute_from_pool_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, BatchDistributeFromPool<'info>>,
+    disaster_id: String,
+    _pool_id: String,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    let pool = &mut ctx.accounts.pool;
+    let pool_key = pool.key();
+
+    require!(
+        pool.registration_locked,
+        ErrorCode::PoolRegistrationNotLocked
+    );
+
+    let triples = ctx.remaining_accounts.chunks_exact(3);
+
+    require!(
+        triples.len() > 0 && triples.remainder().is_empty(),
+        ErrorCode::InvalidInput
+    );
+
+    let batch_size = triples.len();
+    require!(
+        batch_size <= Distribution::MAX_BATCH_SIZE,
+        ErrorCode::BatchSizeTooLarge
+    );
+
+    let rent_lamports = Rent::get()?.minimum_balance(Distribution::SPACE);
+    let mut total_allocated = 0u64;
+
+    for triple in triples {
+        let pool_registration_info = &triple[0];
+        let beneficiary_info = &triple[1];
+        let distribution_info = &triple[2];
+
+        let mut pool_registration: Account<PoolRegistration> =
+            Account::try_from(pool_registration_info)?;
+        let beneficiary: Account<Beneficiary> = Account::try_from(beneficiary_info)?;
+
+        require!(
+            beneficiary.disaster_id == disaster_id,
+            ErrorCode::AccountDataMismatch
+        );
+        require!(
+            beneficiary.verification_status == VerificationStatus::Verified,
+            ErrorCode::BeneficiaryNotVerified
+        );
+        require!(
+            pool_registration.pool == pool_key
+                && pool_registration.beneficiary == beneficiary.key(),
+            ErrorCode::BeneficiaryNotRegisteredForPool
+        );
+        require!(
+            !pool_registration.is_distributed,
+            ErrorCode::DistributionAlreadyCompleted
+        );
+
+        let (distribution_pda, distribution_bump) = Pubkey::find_program_address(
+            &[
+                b"distribution",
+                beneficiary.authority.as_ref(),
+                pool_key.as_ref(),
+            ],
+            ctx.program_id,
+        );
+        require!(
+            distribution_info.key() == distribution_pda,
+            ErrorCode::PDADerivationFailed
+        );
+        require!(
+            distribution_info.owner == &system_program::ID && distribution_info.data_is_empty(),
+            ErrorCode::AccountAlreadyInitialized
+        );
+
+        let distribution_seeds: &[&[u8]] = &[
+            b"distribution",
+            beneficiary.authority.as_ref(),
+            pool_key.as_ref(),
+            &[distribution_bump],
+        ];
+        create_pda_account(
+            distribution_info,
+            &ctx.accounts.authority.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            distribution_seeds,
+            rent_lamports,
+            Distribution::SPACE,
+            ctx.program_id,
+        )?;
+
+        let mut distribution = Distribution {
+            beneficiary: Pubkey::default(),
+            pool: Pubkey::default(),
+            amount_allocated: 0,
+            amount_immediate: 0,
+            amount_locked: 0,
+            amount_claimed: 0,
+            unlock_time: None,
+            created_at: 0,
+            claimed_at: None,
+            locked_claimed_at: None,
+            is_fully_claimed: false,
+            allocation_weight: 0,
+            notes: String::new(),
+            bump: distribution_bump,
+            claim_deadline: None,
+            is_expired: false,
+            expired_at: None,
+            redistribution_round_claimed: 0,
+            redistribution_received: 0,
+            deadline_extension: 0,
+            tranches_claimed: 0,
+            rent_payer: ctx.accounts.authority.key(),
+        };
+        allocate_distribution(
+            pool,
+            pool_key,
+            &mut distribution,
+            beneficiary.key(),
+            pool_registration.allocation_weight,
+            clock.unix_timestamp,
+        )?;
+
+        let mut data = distribution_info.try_borrow_mut_data()?;
+        distribution.try_serialize(&mut &mut data[..])?;
+        drop(data);
+
+        pool_registration.is_distributed = true;
+        pool_registration.exit(ctx.program_id)?;
+
+        total_allocated = total_allocated
+            .checked_add(distribution.amount_allocated)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        emit!(DistributionCreated {
+            distribution: distribution_info.key(),
+            pool: pool_key,
+            beneficiary: beneficiary.key(),
+            amount_allocated: distribution.amount_allocated,
+            amount_immediate: distribution.amount_immediate,
+            amount_locked: distribution.amount_locked,
+            unlock_time: distribution.unlock_time,
+            claim_deadline: distribution.claim_deadline,
+            authority: ctx.accounts.authority.key(),
+            timestamp: clock.unix_timestamp,
+        });
+
+        msg!(
+            "Beneficiary {}: allocated {}",
+            beneficiary.name,
+            distribution.amount_allocated
+        );
+    }
+
+    ctx.accounts.disaster.updated_at = clock.unix_timestamp;
 
     let activity_log = &mut ctx.accounts.activity_log;
     activity_log.action_type = ActivityType::FundsDistributed;
     activity_log.actor = ctx.accounts.authority.key();
-    activity_log.target = beneficiary.key();
-    activity_log.amount = Some(total_allocation);
+    activity_log.target = pool_key;
+    activity_log.amount = Some(total_allocated);
     activity_log.timestamp = clock.unix_timestamp;
     activity_log.metadata = format!(
-        "Pool: {} | Beneficiary: {} | Amount: {} | Immediate: {} | Locked: {}",
-        pool.name, beneficiary.name, total_allocation, amount_immediate, amount_locked
+        "Batch distribution | Pool: {} | Beneficiaries: {} | Amount: {} | Progress: {}/{}",
+        pool.name,
+        batch_size,
+        total_allocated,
+        pool.beneficiary_count,
+        pool.registered_beneficiary_count
     );
+    activity_log.rent_payer = ctx.accounts.authority.key();
     activity_log.bump = ctx.bumps.activity_log;
+    activity_log.record_sequence(pool.key(), &mut pool.activity_count)?;
+
+    msg!("Batch distribution created successfully");
+    msg!("Beneficiaries: {}", batch_size);
+    msg!("Total allocated: {}", total_allocated);
+    msg!(
+        "Distributed: {}/{}",
+        pool.beneficiary_count,
+        pool.registered_beneficiary_count
+    );
 
-    msg!("Distribution created successfully");
-    msg!("Beneficiary: {}", beneficiary.name);
-    msg!("Total allocated: {}", total_allocation);
-    msg!("Immediate: {}", amount_immediate);
-    msg!("Locked: {}", amount_locked);
-    if let Some(unlock) = unlock_time {
-        msg!("Unlock time: {}", unlock);
+    Ok(())
+}
+
+pub(crate) fn create_pda_account<'info>(
+    target: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    seeds: &[&[u8]],
+    lamports: u64,
+    space: usize,
+    owner: &Pubkey,
+) -> Result<()> {
+    let signer_seeds = &[seeds];
+    let current_lamports = target.lamports();
+
+    if current_lamports == 0 {
+        system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                system_program::CreateAccount {
+                    from: payer.clone(),
+                    to: target.clone(),
+                },
+                signer_seeds,
+            ),
+            lamports,
+            space as u64,
+            owner,
+        )?;
+    } else {
+        let top_up = lamports.saturating_sub(current_lamports);
+        if top_up > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    system_program.clone(),
+                    system_program::Transfer {
+                        from: payer.clone(),
+                        to: target.clone(),
+                    },
+                ),
+                top_up,
+            )?;
+        }
+        system_program::allocate(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                system_program::Allocate {
+                    account_to_allocate: target.clone(),
+                },
+                signer_seeds,
+            ),
+            space as u64,
+        )?;
+        system_program::assign(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                system_program::Assign {
+                    account_to_assign: target.clone(),
+                },
+                signer_seeds,
+            ),
+            owner,
+        )?;
     }
 
     Ok(())
 }
 
 #[derive(Accounts)]
-#[instruction(disaster_id: String, pool_id: String, timestamp: i64)]
+#[instruction(disaster_id: String, pool_id: String)]
 pub struct ClaimDistribution<'info> {
     #[account(
         mut,
@@ -215,20 +581,6 @@ pub struct ClaimDistribution<'info> {
     )]
     pub distribution: Account<'info, Distribution>,
 
-    #[account(
-        init,
-        payer = beneficiary_authority,
-        space = ActivityLog::SPACE,
-        seeds = [
-            b"activity",
-            pool.key().as_ref(),
-            beneficiary.key().as_ref(),
-            &timestamp.to_le_bytes()
-        ],
-        bump
-    )]
-    pub activity_log: Account<'info, ActivityLog>,
-
     #[account(
         mut,
         seeds = [
@@ -237,6 +589,7 @@ pub struct ClaimDistribution<'info> {
             pool_id.as_bytes()
         ],
         bump = pool.bump,
+        has_one = token_mint @ ErrorCode::InvalidTokenMint,
     )]
     pub pool: Account<'info, FundPool>,
 
@@ -244,7 +597,7 @@ pub struct ClaimDistribution<'info> {
         mut,
         constraint = pool_token_account.key() == pool.token_account @ ErrorCode::TokenAccountMismatch
     )]
-    pub pool_token_account: Account<'info, TokenAccount>,
+    pub pool_token_account: InterfaceAccount<'info, TokenAccount>,
 
     #[account(
         mut,
@@ -254,20 +607,64 @@ pub struct ClaimDistribution<'info> {
             disaster_id.as_bytes()
         ],
         bump = beneficiary.bump,
+        constraint = beneficiary.verification_status == VerificationStatus::Verified @ ErrorCode::BeneficiaryNotVerified
     )]
     pub beneficiary: Account<'info, Beneficiary>,
 
+    #[account(
+        init,
+        payer = beneficiary_authority,
+        space = ActivityLog::SPACE,
+        seeds = [
+            b"activity",
+            beneficiary.key().as_ref(),
+            &beneficiary.activity_count.to_le_bytes()
+        ],
+        bump
+    )]
+    pub activity_log: Account<'info, ActivityLog>,
+
     #[account(
         mut,
         constraint = beneficiary_token_account.owner == beneficiary_authority.key() @ ErrorCode::InvalidAccountOwner,
         constraint = beneficiary_token_account.mint == pool.token_mint @ ErrorCode::InvalidTokenMint
     )]
-    pub beneficiary_token_account: Account<'info, TokenAccount>,
+    pub beneficiary_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, PlatformConfig>>,
+
+    #[account(
+        mut,
+        seeds = [b"token-stats", pool.token_mint.as_ref()],
+        bump = token_stats.bump,
+    )]
+    pub token_stats: Box<Account<'info, TokenStats>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"disaster-token-stats",
+            disaster_id.as_bytes(),
+            pool.token_mint.as_ref()
+        ],
+        bump = disaster_token_stats.bump,
+    )]
+    pub disaster_token_stats: Box<Account<'info, TokenStats>>,
+
+    pub price_feed: Option<Box<Account<'info, PriceFeed>>>,
+
+    pub price_oracle: Option<Box<Account<'info, StandInOracle>>>,
 
     #[account(mut)]
     pub beneficiary_authority: Signer<'info>,
 
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
 }
 
@@ -275,7 +672,6 @@ pub fn claim_distribution_handler(
     ctx: Context<ClaimDistribution>,
     _disaster_id: String,
     _pool_id: String,
-    _timestamp: i64,
 ) -> Result<()> {
     let clock = Clock::get()?;
 
@@ -288,38 +684,20 @@ pub fn claim_distribution_handler(
         ErrorCode::DistributionAlreadyClaimed
     );
 
-    let mut amount_to_claim = 0u64;
-
-    if distribution.claimed_at.is_none() && distribution.amount_immediate > 0 {
-        amount_to_claim = amount_to_claim
-            .checked_add(distribution.amount_immediate)
-            .ok_or(ErrorCode::ArithmeticOverflow)?;
-        distribution.claimed_at = Some(clock.unix_timestamp);
-        msg!(
-            "Claiming immediate amount: {}",
-            distribution.amount_immediate
-        );
-    }
-
-    if distribution.locked_claimed_at.is_none() && distribution.amount_locked > 0 {
-        let can_claim_locked = match distribution.unlock_time {
-            Some(unlock_time) => clock.unix_timestamp >= unlock_time,
-            None => true,
-        };
-
-        if can_claim_locked {
-            amount_to_claim = amount_to_claim
-                .checked_add(distribution.amount_locked)
-                .ok_or(ErrorCode::ArithmeticOverflow)?;
-            distribution.locked_claimed_at = Some(clock.unix_timestamp);
-            msg!("Claiming locked amount: {}", distribution.amount_locked);
-        } else {
-            msg!("Locked amount not yet available, skipping");
-        }
-    }
+    let amount_to_claim = collect_claimable(distribution, pool, clock.unix_timestamp)?;
 
     require!(amount_to_claim > 0, ErrorCode::DistributionAlreadyClaimed);
 
+    let priced_usd = claim_usd_value(
+        &ctx.accounts.config,
+        pool.token_mint,
+        ctx.accounts.price_feed.as_deref(),
+        ctx.accounts.price_oracle.as_deref(),
+        amount_to_claim,
+        clock.unix_timestamp,
+    )?;
+    let amount_usd = priced_usd.unwrap_or(0);
+
     let disaster_id_bytes = pool.disaster_id.as_bytes();
     let pool_id_bytes = pool.pool_id.as_bytes();
     let seeds = &[b"pool", disaster_id_bytes, pool_id_bytes, &[pool.bump]];
@@ -327,14 +705,19 @@ pub fn claim_distribution_handler(
 
     let transfer_ctx = CpiContext::new_with_signer(
         ctx.accounts.token_program.to_account_info(),
-        Transfer {
+        TransferChecked {
             from: ctx.accounts.pool_token_account.to_account_info(),
+            mint: ctx.accounts.token_mint.to_account_info(),
             to: ctx.accounts.beneficiary_token_account.to_account_info(),
             authority: pool.to_account_info(),
         },
         signer_seeds,
     );
-    token::transfer(transfer_ctx, amount_to_claim)?;
+    token_interface::transfer_checked(
+        transfer_ctx,
+        amount_to_claim,
+        ctx.accounts.token_mint.decimals,
+    )?;
 
     distribution.amount_claimed = distribution
         .amount_claimed
@@ -349,12 +732,29 @@ pub fn claim_distribution_handler(
         .total_claimed
         .checked_add(amount_to_claim)
         .ok_or(ErrorCode::ArithmeticOverflow)?;
+    pool.total_claimed_usd = pool
+        .total_claimed_usd
+        .checked_add(amount_usd)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
 
     beneficiary.total_received = beneficiary
         .total_received
         .checked_add(amount_to_claim)
         .ok_or(ErrorCode::ArithmeticOverflow)?;
 
+    ctx.accounts.token_stats.record_aid_distributed(
+        amount_to_claim,
+        amount_usd,
+        1,
+        clock.unix_timestamp,
+    )?;
+    ctx.accounts.disaster_token_stats.record_aid_distributed(
+        amount_to_claim,
+        amount_usd,
+        1,
+        clock.unix_timestamp,
+    )?;
+
     let activity_log = &mut ctx.accounts.activity_log;
     activity_log.action_type = ActivityType::FundsClaimed;
     activity_log.actor = ctx.accounts.beneficiary_authority.key();
@@ -369,7 +769,21 @@ pub fn claim_distribution_handler(
         distribution.amount_claimed,
         distribution.is_fully_claimed
     );
+    activity_log.rent_payer = ctx.accounts.beneficiary_authority.key();
     activity_log.bump = ctx.bumps.activity_log;
+    activity_log.record_sequence(beneficiary.key(), &mut beneficiary.activity_count)?;
+
+    emit!(FundsClaimed {
+        distribution: distribution.key(),
+        pool: pool.key(),
+        beneficiary: beneficiary.key(),
+        amount: amount_to_claim,
+        usd_value: amount_usd,
+        is_usd_priced: priced_usd.is_some(),
+        total_claimed: distribution.amount_claimed,
+        is_fully_claimed: distribution.is_fully_claimed,
+        timestamp: clock.unix_timestamp,
+    });
 
     msg!("Distribution claimed successfully");
     msg!("Amount claimed: {}", amount_to_claim);
@@ -379,31 +793,409 @@ pub fn claim_distribution_handler(
     Ok(())
 }
 
-pub fn batch_claim_distributions_handler() -> Result<()> {
-    msg!("Batch claims should be implemented by calling claim_distribution multiple times");
-    Err(ErrorCode::NotImplemented.into())
-}
+fn collect_claimable(distribution: &mut Distribution, pool: &FundPool, now: i64) -> Result<u64> {
+    let mut amount_to_claim = 0u64;
 
-#[derive(Accounts)]
-#[instruction(disaster_id: String, pool_id: String, beneficiary_authority: Pubkey)]
-pub struct ReclaimExpiredDistribution<'info> {
-    #[account(
-        mut,
-        seeds = [
-            b"distribution",
-            beneficiary_authority.as_ref(),
-            pool.key().as_ref()
-        ],
-        bump = distribution.bump,
-        constraint = distribution.pool == pool.key() @ ErrorCode::AccountDataMismatch
-    )]
-    pub distribution: Account<'info, Distribution>,
+    if distribution.claimed_at.is_none() && distribution.amount_immediate > 0 {
+        amount_to_claim = amount_to_claim
+            .checked_add(distribution.amount_immediate)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        distribution.claimed_at = Some(now);
+        msg!(
+            "Claiming immediate amount: {}",
+            distribution.amount_immediate
+        );
+    }
 
-    #[account(
-        mut,
-        seeds = [
-            b"pool",
-            disaster_id.as_bytes(),
+    if distribution.locked_claimed_at.is_none()
+        && distribution.amount_locked > 0
+        && !pool.vesting_schedule.is_empty()
+    {
+        let locked_percentage = pool.distribution_percentage_locked as u128;
+        let mut released_before = vested_amount(distribution, pool, locked_percentage)?;
+
+        while (distribution.tranches_claimed as usize) < pool.vesting_schedule.len() {
+            let tranche = &pool.vesting_schedule[distribution.tranches_claimed as usize];
+            let unlock_time = distribution
+                .created_at
+                .checked_add(tranche.unlock_offset)
+                .ok_or(ErrorCode::ArithmeticOverflow)?;
+            if now < unlock_time {
+                break;
+            }
+
+            distribution.tranches_claimed += 1;
+            let released = vested_amount(distribution, pool, locked_percentage)?;
+            let tranche_amount = released
+                .checked_sub(released_before)
+                .ok_or(ErrorCode::ArithmeticUnderflow)?;
+            released_before = released;
+
+            amount_to_claim = amount_to_claim
+                .checked_add(tranche_amount)
+                .ok_or(ErrorCode::ArithmeticOverflow)?;
+            msg!(
+                "Claiming tranche {}: {}",
+                distribution.tranches_claimed,
+                tranche_amount
+            );
+        }
+
+        if distribution.tranches_claimed as usize == pool.vesting_schedule.len() {
+            distribution.locked_claimed_at = Some(now);
+        } else {
+            msg!(
+                "Tranches remaining: {}",
+                pool.vesting_schedule.len() - distribution.tranches_claimed as usize
+            );
+        }
+    } else if distribution.locked_claimed_at.is_none() && distribution.amount_locked > 0 {
+        let can_claim_locked = match distribution.unlock_time {
+            Some(unlock_time) => now >= unlock_time,
+            None => true,
+        };
+
+        if can_claim_locked {
+            amount_to_claim = amount_to_claim
+                .checked_add(distribution.amount_locked)
+                .ok_or(ErrorCode::ArithmeticOverflow)?;
+            distribution.locked_claimed_at = Some(now);
+            msg!("Claiming locked amount: {}", distribution.amount_locked);
+        } else {
+            msg!("Locked amount not yet available, skipping");
+        }
+    }
+
+    Ok(amount_to_claim)
+}
+
+fn vested_amount(
+    distribution: &Distribution,
+    pool: &FundPool,
+    locked_percentage: u128,
+) -> Result<u64> {
+    let vested_percentage: u128 = pool
+        .vesting_schedule
+        .iter()
+        .take(distribution.tranches_claimed as usize)
+        .map(|tranche| tranche.percentage as u128)
+        .sum();
+
+    Ok((distribution.amount_locked as u128)
+        .checked_mul(vested_percentage)
+        .ok_or(ErrorCode::ArithmeticOverflow)?
+        .checked_div(locked_percentage)
+        .ok_or(ErrorCode::DivisionByZero)? as u64)
+}
+
+#[derive(Accounts)]
+#[instruction(disaster_id: String)]
+pub struct BatchClaimDistributions<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"beneficiary",
+            beneficiary_authority.key().as_ref(),
+            disaster_id.as_bytes()
+        ],
+        bump = beneficiary.bump,
+        constraint = beneficiary.verification_status == VerificationStatus::Verified @ ErrorCode::BeneficiaryNotVerified
+    )]
+    pub beneficiary: Account<'info, Beneficiary>,
+
+    #[account(
+        init,
+        payer = beneficiary_authority,
+        space = ActivityLog::SPACE,
+        seeds = [
+            b"activity",
+            beneficiary.key().as_ref(),
+            &beneficiary.activity_count.to_le_bytes()
+        ],
+        bump
+    )]
+    pub activity_log: Account<'info, ActivityLog>,
+
+    #[account(
+        mut,
+        constraint = beneficiary_token_account.owner == beneficiary_authority.key() @ ErrorCode::InvalidAccountOwner
+    )]
+    pub beneficiary_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        constraint = token_mint.key() == beneficiary_token_account.mint @ ErrorCode::InvalidTokenMint
+    )]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, PlatformConfig>>,
+
+    #[account(
+        mut,
+        seeds = [b"token-stats", token_mint.key().as_ref()],
+        bump = token_stats.bump,
+    )]
+    pub token_stats: Box<Account<'info, TokenStats>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"disaster-token-stats",
+            disaster_id.as_bytes(),
+            token_mint.key().as_ref()
+        ],
+        bump = disaster_token_stats.bump,
+    )]
+    pub disaster_token_stats: Box<Account<'info, TokenStats>>,
+
+    pub price_feed: Option<Box<Account<'info, PriceFeed>>>,
+
+    pub price_oracle: Option<Box<Account<'info, StandInOracle>>>,
+
+    #[account(mut)]
+    pub beneficiary_authority: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn batch_claim_distributions_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, BatchClaimDistributions<'info>>,
+    disaster_id: String,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    let triples = ctx.remaining_accounts.chunks_exact(3);
+
+    require!(
+        triples.len() > 0 && triples.remainder().is_empty(),
+        ErrorCode::InvalidInput
+    );
+
+    let batch_size = triples.len();
+    require!(
+        batch_size <= Distribution::MAX_BATCH_SIZE,
+        ErrorCode::BatchSizeTooLarge
+    );
+
+    let beneficiary_key = ctx.accounts.beneficiary.key();
+    let beneficiary_authority_key = ctx.accounts.beneficiary_authority.key();
+
+    let mut total_claimed = 0u64;
+    let mut total_claimed_usd = 0u64;
+    let mut claims_made = 0u32;
+
+    for triple in triples {
+        let distribution_info = &triple[0];
+        let pool_info = &triple[1];
+        let pool_token_info = &triple[2];
+
+        let mut pool: Account<FundPool> = Account::try_from(pool_info)?;
+        require!(
+            pool.disaster_id == disaster_id,
+            ErrorCode::AccountDataMismatch
+        );
+
+        let (pool_pda, _) = Pubkey::find_program_address(
+            &[
+                b"pool",
+                pool.disaster_id.as_bytes(),
+                pool.pool_id.as_bytes(),
+            ],
+            ctx.program_id,
+        );
+        require!(pool_info.key() == pool_pda, ErrorCode::PDADerivationFailed);
+
+        let (distribution_pda, _) = Pubkey::find_program_address(
+            &[
+                b"distribution",
+                beneficiary_authority_key.as_ref(),
+                pool_pda.as_ref(),
+            ],
+            ctx.program_id,
+        );
+        require!(
+            distribution_info.key() == distribution_pda,
+            ErrorCode::PDADerivationFailed
+        );
+
+        let mut distribution: Account<Distribution> = Account::try_from(distribution_info)?;
+        require!(
+            distribution.beneficiary == beneficiary_key,
+            ErrorCode::UnauthorizedBeneficiary
+        );
+
+        require!(
+            pool_token_info.key() == pool.token_account,
+            ErrorCode::TokenAccountMismatch
+        );
+        require!(
+            pool.token_mint == ctx.accounts.beneficiary_token_account.mint,
+            ErrorCode::InvalidTokenMint
+        );
+
+        if distribution.is_fully_claimed {
+            msg!("Pool {}: already fully claimed, skipping", pool.name);
+            continue;
+        }
+
+        let amount_to_claim = collect_claimable(&mut distribution, &pool, clock.unix_timestamp)?;
+        if amount_to_claim == 0 {
+            msg!("Pool {}: nothing claimable yet, skipping", pool.name);
+            continue;
+        }
+
+        let priced_usd = claim_usd_value(
+            &ctx.accounts.config,
+            pool.token_mint,
+            ctx.accounts.price_feed.as_deref(),
+            ctx.accounts.price_oracle.as_deref(),
+            amount_to_claim,
+            clock.unix_timestamp,
+        )?;
+        let amount_usd = priced_usd.unwrap_or(0);
+
+        let disaster_id_bytes = pool.disaster_id.as_bytes();
+        let pool_id_bytes = pool.pool_id.as_bytes();
+        let seeds = &[b"pool", disaster_id_bytes, pool_id_bytes, &[pool.bump]];
+        let signer_seeds = &[&seeds[..]];
+
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: pool_token_info.clone(),
+                mint: ctx.accounts.token_mint.to_account_info(),
+                to: ctx.accounts.beneficiary_token_account.to_account_info(),
+                authority: pool_info.clone(),
+            },
+            signer_seeds,
+        );
+        token_interface::transfer_checked(
+            transfer_ctx,
+            amount_to_claim,
+            ctx.accounts.token_mint.decimals,
+        )?;
+
+        distribution.amount_claimed = distribution
+            .amount_claimed
+            .checked_add(amount_to_claim)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        if distribution.amount_claimed >= distribution.amount_allocated {
+            distribution.is_fully_claimed = true;
+        }
+
+        pool.total_claimed = pool
+            .total_claimed
+            .checked_add(amount_to_claim)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        pool.total_claimed_usd = pool
+            .total_claimed_usd
+            .checked_add(amount_usd)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        distribution.exit(ctx.program_id)?;
+        pool.exit(ctx.program_id)?;
+
+        total_claimed = total_claimed
+            .checked_add(amount_to_claim)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        total_claimed_usd = total_claimed_usd
+            .checked_add(amount_usd)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        claims_made += 1;
+
+        emit!(FundsClaimed {
+            distribution: distribution.key(),
+            pool: pool.key(),
+            beneficiary: beneficiary_key,
+            amount: amount_to_claim,
+            usd_value: amount_usd,
+            is_usd_priced: priced_usd.is_some(),
+            total_claimed: distribution.amount_claimed,
+            is_fully_claimed: distribution.is_fully_claimed,
+            timestamp: clock.unix_timestamp,
+        });
+
+        msg!("Pool {}: claimed {}", pool.name, amount_to_claim);
+    }
+
+    require!(total_claimed > 0, ErrorCode::DistributionAlreadyClaimed);
+
+    let beneficiary = &mut ctx.accounts.beneficiary;
+    beneficiary.total_received = beneficiary
+        .total_received
+        .checked_add(total_claimed)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    ctx.accounts.token_stats.record_aid_distributed(
+        total_claimed,
+        total_claimed_usd,
+        claims_made as u64,
+        clock.unix_timestamp,
+    )?;
+    ctx.accounts.disaster_token_stats.record_aid_distributed(
+        total_claimed,
+        total_claimed_usd,
+        claims_made as u64,
+        clock.unix_timestamp,
+    )?;
+
+    let activity_log = &mut ctx.accounts.activity_log;
+    activity_log.action_type = ActivityType::FundsClaimed;
+    activity_log.actor = beneficiary_authority_key;
+    activity_log.target = beneficiary.key();
+    activity_log.amount = Some(total_claimed);
+    activity_log.timestamp = clock.unix_timestamp;
+    activity_log.metadata = format!(
+        "Batch claim | Beneficiary: {} | Distributions: {}/{} | Amount: {}",
+        beneficiary.name, claims_made, batch_size, total_claimed
+    );
+    activity_log.rent_payer = ctx.accounts.beneficiary_authority.key();
+    activity_log.bump = ctx.bumps.activity_log;
+    activity_log.record_sequence(beneficiary.key(), &mut beneficiary.activity_count)?;
+
+    msg!("Batch claim successful");
+    msg!("Distributions claimed: {}/{}", claims_made, batch_size);
+    msg!("Total claimed: {}", total_claimed);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(disaster_id: String, pool_id: String, beneficiary_authority: Pubkey)]
+pub struct ReclaimExpiredDistribution<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"distribution",
+            beneficiary_authority.as_ref(),
+            pool.key().as_ref()
+        ],
+        bump = distribution.bump,
+        constraint = distribution.pool == pool.key() @ ErrorCode::AccountDataMismatch
+    )]
+    pub distribution: Account<'info, Distribution>,
+
+    #[account(
+        seeds = [
+            b"beneficiary",
+            beneficiary_authority.as_ref(),
+            disaster_id.as_bytes()
+        ],
+        bump = beneficiary.bump,
+        constraint = distribution.beneficiary == beneficiary.key() @ ErrorCode::UnauthorizedBeneficiary
+    )]
+    pub beneficiary: Account<'info, Beneficiary>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"pool",
+            disaster_id.as_bytes(),
             pool_id.as_bytes()
         ],
         bump = pool.bump,
@@ -418,8 +1210,7 @@ pub struct ReclaimExpiredDistribution<'info> {
         seeds = [
             b"activity",
             pool.key().as_ref(),
-            distribution.key().as_ref(),
-            b"reclaim"
+            &pool.activity_count.to_le_bytes()
         ],
         bump
     )]
@@ -446,21 +1237,33 @@ pub fn reclaim_expired_distribution_handler(
         ErrorCode::DistributionAlreadyExpired
     );
 
-    require!(
-        distribution.amount_claimed == 0,
-        ErrorCode::DistributionPartiallyClaimed
-    );
+    let is_revoked = ctx.accounts.beneficiary.verification_status != VerificationStatus::Verified;
 
-    if let Some(deadline) = distribution.claim_deadline {
+    if is_revoked {
         require!(
-            clock.unix_timestamp > deadline,
-            ErrorCode::DistributionNotExpired
+            !distribution.is_fully_claimed,
+            ErrorCode::DistributionAlreadyClaimed
         );
     } else {
-        return Err(ErrorCode::DistributionNotExpired.into());
+        require!(
+            distribution.amount_claimed == 0,
+            ErrorCode::DistributionPartiallyClaimed
+        );
+
+        if let Some(deadline) = distribution.claim_deadline {
+            require!(
+                clock.unix_timestamp > deadline,
+                ErrorCode::DistributionNotExpired
+            );
+        } else {
+            return Err(ErrorCode::DistributionNotExpired.into());
+        }
     }
 
-    let unclaimed_amount = distribution.amount_allocated;
+    let unclaimed_amount = distribution
+        .amount_allocated
+        .checked_sub(distribution.amount_claimed)
+        .ok_or(ErrorCode::ArithmeticUnderflow)?;
 
     distribution.is_expired = true;
     distribution.expired_at = Some(clock.unix_timestamp);
@@ -471,21 +1274,735 @@ pub fn reclaim_expired_distribution_handler(
         .checked_sub(unclaimed_amount)
         .ok_or(ErrorCode::ArithmeticUnderflow)?;
 
+    pool.reclaimed_amount = pool
+        .reclaimed_amount
+        .checked_add(unclaimed_amount)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    pool.expired_allocation_weight = pool
+        .expired_allocation_weight
+        .checked_add(distribution.allocation_weight as u64)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
     let activity_log = &mut ctx.accounts.activity_log;
-    activity_log.action_type = ActivityType::FundsDistributed;
+    activity_log.action_type = ActivityType::DistributionReclaimed;
     activity_log.actor = ctx.accounts.authority.key();
     activity_log.target = distribution.beneficiary;
     activity_log.amount = Some(unclaimed_amount);
     activity_log.timestamp = clock.unix_timestamp;
     activity_log.metadata = format!(
-        "Reclaimed expired distribution | Pool: {} | Amount: {} | Original deadline: {:?}",
-        pool.name, unclaimed_amount, distribution.claim_deadline
+        "Reclaimed {} distribution | Pool: {} | Amount: {} | Original deadline: {:?}",
+        if is_revoked { "revoked" } else { "expired" },
+        pool.name,
+        unclaimed_amount,
+        distribution.claim_deadline
     );
+    activity_log.rent_payer = ctx.accounts.authority.key();
     activity_log.bump = ctx.bumps.activity_log;
+    activity_log.record_sequence(pool.key(), &mut pool.activity_count)?;
+
+    emit!(ExpiredDistributionReclaimed {
+        distribution: distribution.key(),
+        pool: pool.key(),
+        beneficiary: distribution.beneficiary,
+        amount: unclaimed_amount,
+        pending_reclaimed: pool.reclaimed_amount,
+        original_deadline: distribution.claim_deadline,
+        authority: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
 
     msg!("Expired distribution reclaimed successfully");
     msg!("Reclaimed amount: {}", unclaimed_amount);
-    msg!("Funds returned to pool available balance");
+    msg!("Pending reclaimed balance: {}", pool.reclaimed_amount);
+
+    Ok(())
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RouteReclaimedFundsParams {
+    pub destination: ReclaimDestination,
+}
+
+#[derive(Accounts)]
+#[instruction(disaster_id: String, pool_id: String)]
+pub struct RouteReclaimedFunds<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"pool",
+            disaster_id.as_bytes(),
+            pool_id.as_bytes()
+        ],
+        bump = pool.bump,
+        has_one = token_mint @ ErrorCode::InvalidTokenMint,
+        constraint = pool.authority == authority.key() @ ErrorCode::UnauthorizedModification
+    )]
+    pub pool: Box<Account<'info, FundPool>>,
+
+    #[account(
+        mut,
+        constraint = pool_token_account.key() == pool.token_account @ ErrorCode::TokenAccountMismatch
+    )]
+    pub pool_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub destination_pool: Option<Box<Account<'info, FundPool>>>,
+
+    #[account(mut)]
+    pub destination_pool_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = ActivityLog::SPACE,
+        seeds = [
+            b"activity",
+            pool.key().as_ref(),
+            &pool.activity_count.to_le_bytes()
+        ],
+        bump
+    )]
+    pub activity_log: Account<'info, ActivityLog>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn route_reclaimed_funds_handler(
+    ctx: Context<RouteReclaimedFunds>,
+    _disaster_id: String,
+    _pool_id: String,
+    params: RouteReclaimedFundsParams,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    let pool = &mut ctx.accounts.pool;
+    let amount = pool.reclaimed_amount;
+
+    require!(amount > 0, ErrorCode::NoReclaimedFunds);
+
+    let (action_type, target, details) = match params.destination {
+        ReclaimDestination::RedistributionRound => {
+            let eligible_weight = pool
+                .total_allocation_weight
+                .checked_sub(pool.expired_allocation_weight)
+                .ok_or(ErrorCode::ArithmeticUnderflow)?;
+
+            require!(
+                eligible_weight > 0,
+                ErrorCode::NoBeneficiariesForDistribution
+            );
+
+            let carried_over = pool
+                .redistribution_amount
+                .checked_sub(pool.redistribution_claimed)
+                .ok_or(ErrorCode::ArithmeticUnderflow)?;
+
+            pool.redistribution_round = pool
+                .redistribution_round
+                .checked_add(1)
+                .ok_or(ErrorCode::ArithmeticOverflow)?;
+            pool.redistribution_amount = carried_over
+                .checked_add(amount)
+                .ok_or(ErrorCode::ArithmeticOverflow)?;
+            pool.redistribution_claimed = 0;
+            pool.redistribution_weight = eligible_weight;
+
+            pool.total_distributed = pool
+                .total_distributed
+                .checked_add(amount)
+                .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+            msg!(
+                "Redistribution round {} opened with {} (carried over: {})",
+                pool.redistribution_round,
+                pool.redistribution_amount,
+                carried_over
+            );
+
+            (
+                ActivityType::ReclaimedFundsRedistributed,
+                pool.key(),
+                format!(
+                    "Round: {} | Pot: {} | Eligible weight: {}",
+                    pool.redistribution_round, pool.redistribution_amount, eligible_weight
+                ),
+            )
+        }
+        ReclaimDestination::AnotherPool => {
+            let destination_pool = ctx
+                .accounts
+                .destination_pool
+                .as_mut()
+                .ok_or(ErrorCode::InvalidDestinationPool)?;
+            let destination_token_account = ctx
+                .accounts
+                .destination_pool_token_account
+                .as_mut()
+                .ok_or(ErrorCode::InvalidDestinationPool)?;
+
+            require!(
+                destination_pool.key() != pool.key()
+                    && destination_pool.disaster_id == pool.disaster_id
+                    && destination_pool.token_mint == pool.token_mint,
+                ErrorCode::InvalidDestinationPool
+            );
+            require!(destination_pool.is_active, ErrorCode::PoolNotActive);
+            require!(
+                !destination_pool.is_distributed,
+                ErrorCode::DistributionAlreadyCompleted
+            );
+            require!(
+                destination_token_account.key() == destination_pool.token_account,
+                ErrorCode::TokenAccountMismatch
+            );
+
+            let disaster_id_bytes = pool.disaster_id.as_bytes();
+            let pool_id_bytes = pool.pool_id.as_bytes();
+            let seeds = &[b"pool", disaster_id_bytes, pool_id_bytes, &[pool.bump]];
+            let signer_seeds = &[&seeds[..]];
+
+            let received = transfer_and_measure(
+                &ctx.accounts.token_program,
+                ctx.accounts.pool_token_account.to_account_info(),
+                destination_token_account,
+                &ctx.accounts.token_mint,
+                pool.to_account_info(),
+                signer_seeds,
+                amount,
+            )?;
+
+            pool.total_transferred_out = pool
+                .total_transferred_out
+                .checked_add(amount)
+                .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+            destination_pool.total_deposited = destination_pool
+                .total_deposited
+                .checked_add(received)
+                .ok_or(ErrorCode::ArithmeticOverflow)?;
+            destination_pool.total_transferred_in = destination_pool
+                .total_transferred_in
+                .checked_add(received)
+                .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+            msg!("Moved {} to pool: {}", received, destination_pool.name);
+
+            (
+                ActivityType::ReclaimedFundsMovedToPool,
+                destination_pool.key(),
+                format!("Destination pool: {}", destination_pool.name),
+            )
+        }
+        ReclaimDestination::DonorRefund => {
+            pool.donor_refund_total = pool
+                .donor_refund_total
+                .checked_add(amount)
+                .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+            msg!(
+                "Donor refunds available: {}",
+                pool.donor_refund_total - pool.donor_refunded
+            );
+
+            (
+                ActivityType::ReclaimedFundsRefundedToDonors,
+                pool.key(),
+                format!("Refund total: {}", pool.donor_refund_total),
+            )
+        }
+    };
+
+    pool.reclaimed_amount = 0;
+
+    let activity_log = &mut ctx.accounts.activity_log;
+    activity_log.action_type = action_type;
+    activity_log.actor = ctx.accounts.authority.key();
+    activity_log.target = target;
+    activity_log.amount = Some(amount);
+    activity_log.timestamp = clock.unix_timestamp;
+    activity_log.metadata = format!(
+        "Reclaimed funds routed | Pool: {} | Amount: {} | {}",
+        pool.name, amount, details
+    );
+    activity_log.rent_payer = ctx.accounts.authority.key();
+    activity_log.bump = ctx.bumps.activity_log;
+    activity_log.record_sequence(pool.key(), &mut pool.activity_count)?;
+
+    emit!(ReclaimedFundsRouted {
+        pool: pool.key(),
+        destination: params.destination.clone(),
+        target,
+        amount,
+        authority: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Reclaimed funds routed successfully");
+    msg!("Destination: {:?}", params.destination);
+    msg!("Amount: {}", amount);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(disaster_id: String, pool_id: String)]
+pub struct ClaimRedistribution<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"distribution",
+            beneficiary_authority.key().as_ref(),
+            pool.key().as_ref()
+        ],
+        bump = distribution.bump,
+        constraint = distribution.beneficiary == beneficiary.key() @ ErrorCode::UnauthorizedBeneficiary
+    )]
+    pub distribution: Account<'info, Distribution>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"pool",
+            disaster_id.as_bytes(),
+            pool_id.as_bytes()
+        ],
+        bump = pool.bump,
+        has_one = token_mint @ ErrorCode::InvalidTokenMint,
+    )]
+    pub pool: Account<'info, FundPool>,
+
+    #[account(
+        mut,
+        constraint = pool_token_account.key() == pool.token_account @ ErrorCode::TokenAccountMismatch
+    )]
+    pub pool_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"beneficiary",
+            beneficiary_authority.key().as_ref(),
+            disaster_id.as_bytes()
+        ],
+        bump = beneficiary.bump,
+        constraint = beneficiary.verification_status == VerificationStatus::Verified @ ErrorCode::BeneficiaryNotVerified
+    )]
+    pub beneficiary: Account<'info, Beneficiary>,
+
+    #[account(
+        init,
+        payer = beneficiary_authority,
+        space = ActivityLog::SPACE,
+        seeds = [
+            b"activity",
+            beneficiary.key().as_ref(),
+            &beneficiary.activity_count.to_le_bytes()
+        ],
+        bump
+    )]
+    pub activity_log: Account<'info, ActivityLog>,
+
+    #[account(
+        mut,
+        constraint = beneficiary_token_account.owner == beneficiary_authority.key() @ ErrorCode::InvalidAccountOwner,
+        constraint = beneficiary_token_account.mint == pool.token_mint @ ErrorCode::InvalidTokenMint
+    )]
+    pub beneficiary_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, PlatformConfig>>,
+
+    #[account(
+        mut,
+        seeds = [b"token-stats", pool.token_mint.as_ref()],
+        bump = token_stats.bump,
+    )]
+    pub token_stats: Box<Account<'info, TokenStats>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"disaster-token-stats",
+            disaster_id.as_bytes(),
+            pool.token_mint.as_ref()
+        ],
+        bump = disaster_token_stats.bump,
+    )]
+    pub disaster_token_stats: Box<Account<'info, TokenStats>>,
+
+    pub price_feed: Option<Box<Account<'info, PriceFeed>>>,
+
+    pub price_oracle: Option<Box<Account<'info, StandInOracle>>>,
+
+    #[account(mut)]
+    pub beneficiary_authority: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn claim_redistribution_handler(
+    ctx: Context<ClaimRedistribution>,
+    _disaster_id: String,
+    _pool_id: String,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    let distribution = &mut ctx.accounts.distribution;
+    let pool = &mut ctx.accounts.pool;
+    let beneficiary = &mut ctx.accounts.beneficiary;
+
+    require!(
+        distribution.redistribution_round_claimed < pool.redistribution_round,
+        ErrorCode::NoRedistributionAvailable
+    );
+
+    require!(
+        !distribution.is_expired && distribution.amount_claimed > 0,
+        ErrorCode::NotEligibleForRedistribution
+    );
+
+    let share = (pool.redistribution_amount as u128)
+        .checked_mul(distribution.allocation_weight as u128)
+        .ok_or(ErrorCode::ArithmeticOverflow)?
+        .checked_div(pool.redistribution_weight as u128)
+        .ok_or(ErrorCode::DivisionByZero)? as u64;
+
+    require!(share > 0, ErrorCode::NoRedistributionAvailable);
+
+    let priced_usd = claim_usd_value(
+        &ctx.accounts.config,
+        pool.token_mint,
+        ctx.accounts.price_feed.as_deref(),
+        ctx.accounts.price_oracle.as_deref(),
+        share,
+        clock.unix_timestamp,
+    )?;
+    let share_usd = priced_usd.unwrap_or(0);
+
+    let disaster_id_bytes = pool.disaster_id.as_bytes();
+    let pool_id_bytes = pool.pool_id.as_bytes();
+    let seeds = &[b"pool", disaster_id_bytes, pool_id_bytes, &[pool.bump]];
+    let signer_seeds = &[&seeds[..]];
+
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        TransferChecked {
+            from: ctx.accounts.pool_token_account.to_account_info(),
+            mint: ctx.accounts.token_mint.to_account_info(),
+            to: ctx.accounts.beneficiary_token_account.to_account_info(),
+            authority: pool.to_account_info(),
+        },
+        signer_seeds,
+    );
+    token_interface::transfer_checked(transfer_ctx, share, ctx.accounts.token_mint.decimals)?;
+
+    distribution.redistribution_round_claimed = pool.redistribution_round;
+    distribution.redistribution_received = distribution
+        .redistribution_received
+        .checked_add(share)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    pool.redistribution_claimed = pool
+        .redistribution_claimed
+        .checked_add(share)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    pool.total_claimed = pool
+        .total_claimed
+        .checked_add(share)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    pool.total_claimed_usd = pool
+        .total_claimed_usd
+        .checked_add(share_usd)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    beneficiary.total_received = beneficiary
+        .total_received
+        .checked_add(share)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    ctx.accounts
+        .token_stats
+        .record_aid_distributed(share, share_usd, 1, clock.unix_timestamp)?;
+    ctx.accounts.disaster_token_stats.record_aid_distributed(
+        share,
+        share_usd,
+        1,
+        clock.unix_timestamp,
+    )?;
+
+    let activity_log = &mut ctx.accounts.activity_log;
+    activity_log.action_type = ActivityType::FundsClaimed;
+    activity_log.actor = ctx.accounts.beneficiary_authority.key();
+    activity_log.target = beneficiary.key();
+    activity_log.amount = Some(share);
+    activity_log.timestamp = clock.unix_timestamp;
+    activity_log.metadata = format!(
+        "Redistribution claim | Pool: {} | Beneficiary: {} | Round: {} | Amount: {}",
+        pool.name, beneficiary.name, pool.redistribution_round, share
+    );
+    activity_log.rent_payer = ctx.accounts.beneficiary_authority.key();
+    activity_log.bump = ctx.bumps.activity_log;
+    activity_log.record_sequence(beneficiary.key(), &mut beneficiary.activity_count)?;
+
+    emit!(RedistributionClaimed {
+        distribution: distribution.key(),
+        pool: pool.key(),
+        beneficiary: beneficiary.key(),
+        round: pool.redistribution_round,
+        amount: share,
+        usd_value: share_usd,
+        is_usd_priced: priced_usd.is_some(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Redistribution claimed successfully");
+    msg!("Round: {}", pool.redistribution_round);
+    msg!("Amount: {}", share);
+
+    Ok(())
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ExtendClaimDeadlineParams {
+    pub extension_seconds: i64,
+    pub reason: String,
+}
+
+#[derive(Accounts)]
+#[instruction(disaster_id: String, pool_id: String, beneficiary_authority: Pubkey)]
+pub struct ExtendClaimDeadline<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"distribution",
+            beneficiary_authority.as_ref(),
+            pool.key().as_ref()
+        ],
+        bump = distribution.bump,
+    )]
+    pub distribution: Account<'info, Distribution>,
+
+    #[account(
+        seeds = [
+            b"pool",
+            disaster_id.as_bytes(),
+            pool_id.as_bytes()
+        ],
+        bump = pool.bump,
+    )]
+    pub pool: Account<'info, FundPool>,
+
+    #[account(
+        seeds = [b"ngo", pool.authority.as_ref()],
+        bump = ngo.bump,
+    )]
+    pub ngo: Account<'info, NGO>,
+
+    #[account(
+        mut,
+        seeds = [b"field-worker", field_worker_authority.key().as_ref()],
+        bump = field_worker.bump,
+        constraint = field_worker.is_active @ ErrorCode::FieldWorkerNotActive,
+        constraint = field_worker.ngo == Some(ngo.key()) @ ErrorCode::UnauthorizedFieldWorker
+    )]
+    pub field_worker: Account<'info, FieldWorker>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, PlatformConfig>,
+
+    #[account(
+        init,
+        payer = field_worker_authority,
+        space = ActivityLog::SPACE,
+        seeds = [
+            b"activity",
+            field_worker.key().as_ref(),
+            &field_worker.activity_count.to_le_bytes()
+        ],
+        bump
+    )]
+    pub activity_log: Account<'info, ActivityLog>,
+
+    #[account(mut)]
+    pub field_worker_authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn extend_claim_deadline_handler(
+    ctx: Context<ExtendClaimDeadline>,
+    _disaster_id: String,
+    _pool_id: String,
+    _beneficiary_authority: Pubkey,
+    params: ExtendClaimDeadlineParams,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    let distribution = &mut ctx.accounts.distribution;
+    let field_worker = &mut ctx.accounts.field_worker;
+    let config = &ctx.accounts.config;
+
+    require!(
+        !distribution.is_expired,
+        ErrorCode::DistributionAlreadyExpired
+    );
+
+    require!(
+        !distribution.is_fully_claimed,
+        ErrorCode::DistributionAlreadyClaimed
+    );
+
+    require!(params.extension_seconds > 0, ErrorCode::InvalidInput);
+
+    require!(
+        params.reason.len() <= Distribution::MAX_NOTES_LEN,
+        ErrorCode::StringTooLong
+    );
+
+    let total_extension = distribution
+        .deadline_extension
+        .checked_add(params.extension_seconds)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    require!(
+        total_extension <= config.max_claim_window_seconds,
+        ErrorCode::ClaimExtensionTooLong
+    );
+
+    let current_deadline = distribution
+        .claim_deadline
+        .ok_or(ErrorCode::InvalidOperation)?;
+
+    let new_deadline = current_deadline
+        .checked_add(params.extension_seconds)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    distribution.claim_deadline = Some(new_deadline);
+    distribution.deadline_extension = total_extension;
+
+    field_worker.last_activity_at = clock.unix_timestamp;
+
+    let activity_log = &mut ctx.accounts.activity_log;
+    activity_log.action_type = ActivityType::ClaimDeadlineExtended;
+    activity_log.actor = ctx.accounts.field_worker_authority.key();
+    activity_log.target = distribution.beneficiary;
+    activity_log.amount = None;
+    activity_log.timestamp = clock.unix_timestamp;
+    activity_log.metadata = format!(
+        "Pool: {} | Old deadline: {} | New deadline: {} | Reason: {}",
+        ctx.accounts.pool.name, current_deadline, new_deadline, params.reason
+    );
+    activity_log.rent_payer = ctx.accounts.field_worker_authority.key();
+    activity_log.bump = ctx.bumps.activity_log;
+    activity_log.record_sequence(field_worker.key(), &mut field_worker.activity_count)?;
+
+    emit!(ClaimDeadlineExtended {
+        distribution: distribution.key(),
+        pool: ctx.accounts.pool.key(),
+        beneficiary: distribution.beneficiary,
+        extension_seconds: params.extension_seconds,
+        previous_deadline: current_deadline,
+        new_deadline,
+        reason: params.reason,
+        field_worker: ctx.accounts.field_worker_authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Claim deadline extended successfully");
+    msg!("Extended by: {}s", params.extension_seconds);
+    msg!("New deadline: {}", new_deadline);
+    msg!("Field worker: {}", field_worker.name);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(disaster_id: String, pool_id: String, beneficiary_authority: Pubkey)]
+pub struct CloseDistribution<'info> {
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [
+            b"distribution",
+            beneficiary_authority.as_ref(),
+            pool.key().as_ref()
+        ],
+        bump = distribution.bump,
+    )]
+    pub distribution: Account<'info, Distribution>,
+
+    #[account(
+        seeds = [
+            b"pool",
+            disaster_id.as_bytes(),
+            pool_id.as_bytes()
+        ],
+        bump = pool.bump,
+    )]
+    pub pool: Account<'info, FundPool>,
+
+    #[account(
+        mut,
+        address = distribution.rent_payer @ ErrorCode::AccountDataMismatch
+    )]
+    pub rent_payer: SystemAccount<'info>,
+
+    pub closer: Option<Signer<'info>>,
+}
+
+pub fn close_distribution_handler(
+    ctx: Context<CloseDistribution>,
+    _disaster_id: String,
+    _pool_id: String,
+    beneficiary_authority: Pubkey,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let distribution = &ctx.accounts.distribution;
+    let pool = &ctx.accounts.pool;
+
+    require!(
+        distribution.is_expired || (distribution.is_fully_claimed && pool.closed_at.is_some()),
+        ErrorCode::AccountStillInUse
+    );
+
+    let closer_consented = ctx.accounts.closer.as_ref().is_some_and(|closer| {
+        closer.key() == beneficiary_authority || closer.key() == distribution.rent_payer
+    });
+    require!(
+        distribution.is_expired
+            || distribution.redistribution_round_claimed == pool.redistribution_round
+            || closer_consented,
+        ErrorCode::AccountStillInUse
+    );
+
+    emit!(DistributionClosed {
+        distribution: distribution.key(),
+        pool: pool.key(),
+        beneficiary: distribution.beneficiary,
+        was_expired: distribution.is_expired,
+        rent_payer: ctx.accounts.rent_payer.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Distribution closed, rent returned to payer");
+    msg!("Pool: {}", pool.name);
+    msg!("Rent payer: {}", ctx.accounts.rent_payer.key());
 
     Ok(())
 }
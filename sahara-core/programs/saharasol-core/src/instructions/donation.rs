@@ -1,15 +1,20 @@
 use crate::errors::ErrorCode;
+use crate::events::DonationMade;
+use crate::instructions::fund_pool::deposit_from_donor;
+use crate::instructions::price_feed::usd_value;
 use crate::state::{
-    Beneficiary, DisasterEvent, DonationRecord, DonationType, PlatformConfig, VerificationStatus,
+    Beneficiary, DisasterEvent, DonationRecord, DonationType, PlatformConfig, PriceFeed,
+    StandInOracle, TokenStats, VerificationStatus,
 };
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct DonateDirectParams {
     pub amount: u64,
     pub message: String,
     pub is_anonymous: bool,
+    pub donor_commitment: Option<[u8; 32]>,
 }
 
 #[derive(Accounts)]
@@ -40,7 +45,7 @@ pub struct DonateDirect<'info> {
         space = DonationRecord::SPACE,
         seeds = [
             b"donation",
-            donor.key().as_ref(),
+            DonationRecord::donor_seed(&donor.key(), params.donor_commitment).as_ref(),
             beneficiary.key().as_ref(),
             &timestamp.to_le_bytes()
         ],
@@ -50,16 +55,17 @@ pub struct DonateDirect<'info> {
 
     #[account(
         mut,
+        constraint = donor_token_account.mint == token_mint.key() @ ErrorCode::InvalidTokenMint,
         constraint = donor_token_account.owner == donor.key() @ ErrorCode::InvalidAccountOwner
     )]
-    pub donor_token_account: Box<Account<'info, TokenAccount>>,
+    pub donor_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 
     #[account(
         mut,
         constraint = beneficiary_token_account.owner == beneficiary_authority @ ErrorCode::InvalidAccountOwner,
-        constraint = beneficiary_token_account.mint == donor_token_account.mint @ ErrorCode::InvalidTokenMint
+        constraint = beneficiary_token_account.mint == token_mint.key() @ ErrorCode::InvalidTokenMint
     )]
-    pub beneficiary_token_account: Box<Account<'info, TokenAccount>>,
+    pub beneficiary_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
         mut,
@@ -71,17 +77,46 @@ pub struct DonateDirect<'info> {
     #[account(
         mut,
         constraint = platform_fee_recipient.owner == config.platform_fee_recipient @ ErrorCode::InvalidAccountOwner,
-        constraint = platform_fee_recipient.mint == donor_token_account.mint @ ErrorCode::InvalidTokenMint
+        constraint = platform_fee_recipient.mint == token_mint.key() @ ErrorCode::InvalidTokenMint
     )]
-    pub platform_fee_recipient: Box<Account<'info, TokenAccount>>,
+    pub platform_fee_recipient: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = donor,
+        space = TokenStats::SPACE,
+        seeds = [b"token-stats", token_mint.key().as_ref()],
+        bump
+    )]
+    pub token_stats: Box<Account<'info, TokenStats>>,
+
+    #[account(
+        init_if_needed,
+        payer = donor,
+        space = TokenStats::SPACE,
+        seeds = [
+            b"disaster-token-stats",
+            disaster_id.as_bytes(),
+            token_mint.key().as_ref()
+        ],
+        bump
+    )]
+    pub disaster_token_stats: Box<Account<'info, TokenStats>>,
+
+    pub price_feed: Option<Box<Account<'info, PriceFeed>>>,
+
+    pub price_oracle: Option<Box<Account<'info, StandInOracle>>>,
 
     #[account(mut)]
     pub donor: Signer<'info>,
 
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
 }
 
+#[allow(clippy::boxed_local)]
 pub fn handler(
     ctx: Context<DonateDirect>,
     _beneficiary_authority: Pubkey,
@@ -113,38 +148,54 @@ pub fn handler(
         ErrorCode::StringTooLong
     );
 
-    let platform_fee = (params.amount as u128)
+    require!(
+        params.is_anonymous == params.donor_commitment.is_some(),
+        ErrorCode::DonorCommitmentMismatch
+    );
+
+    let fee_amount = (params.amount as u128)
         .checked_mul(config.platform_fee_percentage as u128)
         .ok_or(ErrorCode::ArithmeticOverflow)?
         .checked_div(10000)
         .ok_or(ErrorCode::DivisionByZero)? as u64;
 
-    let net_amount = params
+    let transfer_amount = params
         .amount
-        .checked_sub(platform_fee)
+        .checked_sub(fee_amount)
         .ok_or(ErrorCode::ArithmeticUnderflow)?;
 
-    let transfer_ctx = CpiContext::new(
-        ctx.accounts.token_program.to_account_info(),
-        Transfer {
-            from: ctx.accounts.donor_token_account.to_account_info(),
-            to: ctx.accounts.beneficiary_token_account.to_account_info(),
-            authority: ctx.accounts.donor.to_account_info(),
-        },
-    );
-    token::transfer(transfer_ctx, net_amount)?;
-
-    if platform_fee > 0 {
-        let fee_transfer_ctx = CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            Transfer {
-                from: ctx.accounts.donor_token_account.to_account_info(),
-                to: ctx.accounts.platform_fee_recipient.to_account_info(),
-                authority: ctx.accounts.donor.to_account_info(),
-            },
-        );
-        token::transfer(fee_transfer_ctx, platform_fee)?;
-    }
+    let net_amount = deposit_from_donor(
+        &ctx.accounts.token_program,
+        &ctx.accounts.system_program,
+        &ctx.accounts.donor,
+        ctx.accounts.donor_token_account.as_deref(),
+        &mut ctx.accounts.beneficiary_token_account,
+        &ctx.accounts.token_mint,
+        transfer_amount,
+    )?;
+
+    let net_amount_usd = usd_value(
+        config,
+        ctx.accounts.token_mint.key(),
+        ctx.accounts.price_feed.as_deref(),
+        ctx.accounts.price_oracle.as_deref(),
+        net_amount,
+        clock.unix_timestamp,
+    )?;
+
+    let platform_fee = if fee_amount > 0 {
+        deposit_from_donor(
+            &ctx.accounts.token_program,
+            &ctx.accounts.system_program,
+            &ctx.accounts.donor,
+            ctx.accounts.donor_token_account.as_deref(),
+            &mut ctx.accounts.platform_fee_recipient,
+            &ctx.accounts.token_mint,
+            fee_amount,
+        )?
+    } else {
+        0
+    };
 
     beneficiary.total_received = beneficiary
         .total_received
@@ -155,13 +206,57 @@ pub fn handler(
         .total_aid_distributed
         .checked_add(net_amount)
         .ok_or(ErrorCode::ArithmeticOverflow)?;
+    disaster.total_aid_distributed_usd = disaster
+        .total_aid_distributed_usd
+        .checked_add(net_amount_usd)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
     disaster.updated_at = clock.unix_timestamp;
 
-    donation_record.donor = ctx.accounts.donor.key();
+    let token_mint = ctx.accounts.token_mint.key();
+
+    let token_stats = &mut ctx.accounts.token_stats;
+    token_stats.initialize_if_needed(
+        token_mint,
+        None,
+        ctx.bumps.token_stats,
+        clock.unix_timestamp,
+    );
+    token_stats.record_donation(
+        net_amount,
+        net_amount_usd,
+        platform_fee,
+        clock.unix_timestamp,
+    )?;
+    token_stats.record_aid_distributed(net_amount, net_amount_usd, 1, clock.unix_timestamp)?;
+
+    let disaster_token_stats = &mut ctx.accounts.disaster_token_stats;
+    disaster_token_stats.initialize_if_needed(
+        token_mint,
+        Some(disaster_id.clone()),
+        ctx.bumps.disaster_token_stats,
+        clock.unix_timestamp,
+    );
+    disaster_token_stats.record_donation(
+        net_amount,
+        net_amount_usd,
+        platform_fee,
+        clock.unix_timestamp,
+    )?;
+    disaster_token_stats.record_aid_distributed(
+        net_amount,
+        net_amount_usd,
+        1,
+        clock.unix_timestamp,
+    )?;
+
+    donation_record.donor = match params.donor_commitment {
+        Some(_) => Pubkey::default(),
+        None => ctx.accounts.donor.key(),
+    };
     donation_record.recipient = beneficiary.key();
     donation_record.donation_type = DonationType::Direct;
     donation_record.amount = params.amount;
-    donation_record.token_mint = ctx.accounts.donor_token_account.mint;
+    donation_record.token_mint = token_mint;
     donation_record.disaster_id = disaster_id;
     donation_record.pool = None;
     donation_record.transaction_signature = String::new();
@@ -174,6 +269,14 @@ pub fn handler(
     donation_record.donor_email = None;
     donation_record.receipt_sent = false;
     donation_record.bump = ctx.bumps.donation_record;
+    donation_record.refunded_amount = 0;
+    donation_record.is_refunded = false;
+    donation_record.receipt_number = None;
+    donation_record.receipt_tax_id = None;
+    donation_record.receipt_fiscal_year = None;
+    donation_record.receipt_issued_at = None;
+    donation_record.donor_commitment = params.donor_commitment;
+    donation_record.activity_count = 0;
 
     config.total_donations = config
         .total_donations
@@ -183,13 +286,39 @@ pub fn handler(
         .total_aid_distributed
         .checked_add(net_amount)
         .ok_or(ErrorCode::ArithmeticOverflow)?;
+    config.total_donations_usd = config
+        .total_donations_usd
+        .checked_add(net_amount_usd)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    config.total_aid_distributed_usd = config
+        .total_aid_distributed_usd
+        .checked_add(net_amount_usd)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
     config.updated_at = clock.unix_timestamp;
 
+    emit!(DonationMade {
+        donation_record: donation_record.key(),
+        donor: donation_record.donor,
+        recipient: donation_record.recipient,
+        donation_type: donation_record.donation_type.clone(),
+        disaster_id: donation_record.disaster_id.clone(),
+        pool: donation_record.pool,
+        token_mint: donation_record.token_mint,
+        amount: donation_record.amount,
+        platform_fee: donation_record.platform_fee,
+        net_amount: donation_record.net_amount,
+        usd_value: net_amount_usd,
+        is_anonymous: donation_record.is_anonymous,
+        timestamp: clock.unix_timestamp,
+        donor_commitment: donation_record.donor_commitment,
+    });
+
     msg!("Direct donation successful");
     msg!("Beneficiary: {}", beneficiary.name);
     msg!("Amount: {}", params.amount);
     msg!("Platform fee: {}", platform_fee);
     msg!("Net amount: {}", net_amount);
+    msg!("USD value: {}", net_amount_usd);
 
     Ok(())
 }
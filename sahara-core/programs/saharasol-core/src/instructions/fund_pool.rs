@@ -1,10 +1,22 @@
 use crate::errors::ErrorCode;
+use crate::events::{
+    DonationMade, DonationRefunded, FundPoolCreated, PoolClosed, PoolConfigUpdated,
+};
+use crate::instructions::price_feed::usd_value;
 use crate::state::{
     ActivityLog, ActivityType, DisasterEvent, DistributionType, DonationRecord, DonationType,
-    FundPool, PlatformConfig, NGO,
+    FundPool, PlatformConfig, PriceFeed, StandInOracle, TokenStats, VestingTranche, NGO,
 };
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use anchor_lang::system_program;
+use anchor_spl::token_2022::spl_token_2022::extension::{
+    BaseStateWithExtensions, ExtensionType, StateWithExtensions,
+};
+use anchor_spl::token_2022::spl_token_2022::state::Mint as MintState;
+use anchor_spl::token_2022::ID as TOKEN_2022_PROGRAM_ID;
+use anchor_spl::token_interface::{
+    self, Mint, SyncNative, TokenAccount, TokenInterface, TransferChecked,
+};
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct CreateFundPoolParams {
@@ -18,10 +30,12 @@ pub struct CreateFundPoolParams {
     pub minimum_damage_severity: Option<u8>,
     pub target_amount: Option<u64>,
     pub description: String,
+    pub claim_window_seconds: Option<i64>,
+    pub vesting_schedule: Vec<VestingTranche>,
 }
 
 #[derive(Accounts)]
-#[instruction(disaster_id: String, pool_id: String, timestamp: i64)]
+#[instruction(disaster_id: String, pool_id: String)]
 pub struct CreateFundPool<'info> {
     #[account(
         init,
@@ -41,6 +55,7 @@ pub struct CreateFundPool<'info> {
         payer = payer,
         token::mint = token_mint,
         token::authority = pool,
+        token::token_program = token_program,
         seeds = [
             b"pool-token",
             disaster_id.as_bytes(),
@@ -48,9 +63,10 @@ pub struct CreateFundPool<'info> {
         ],
         bump
     )]
-    pub pool_token_account: Box<Account<'info, TokenAccount>>,
+    pub pool_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
+        mut,
         seeds = [b"disaster", disaster_id.as_bytes()],
         bump = disaster.bump,
         constraint = disaster.is_active @ ErrorCode::DisasterNotActive
@@ -73,7 +89,7 @@ pub struct CreateFundPool<'info> {
     )]
     pub config: Box<Account<'info, PlatformConfig>>,
 
-    pub token_mint: Account<'info, Mint>,
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
 
     #[account(
         init,
@@ -81,8 +97,8 @@ pub struct CreateFundPool<'info> {
         space = ActivityLog::SPACE,
         seeds = [
             b"activity",
-            ngo_authority.key().as_ref(),
-            &timestamp.to_le_bytes()
+            pool.key().as_ref(),
+            &0u64.to_le_bytes()
         ],
         bump
     )]
@@ -93,15 +109,38 @@ pub struct CreateFundPool<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
 
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
 }
 
+pub(crate) fn validate_pool_mint(token_mint: &AccountInfo) -> Result<()> {
+    if *token_mint.owner != TOKEN_2022_PROGRAM_ID {
+        return Ok(());
+    }
+
+    let data = token_mint.try_borrow_data()?;
+    let mint = StateWithExtensions::<MintState>::unpack(&data)?;
+    for extension in mint.get_extension_types()? {
+        require!(
+            !matches!(
+                extension,
+                ExtensionType::TransferHook
+                    | ExtensionType::PermanentDelegate
+                    | ExtensionType::NonTransferable
+                    | ExtensionType::DefaultAccountState
+            ),
+            ErrorCode::UnsupportedMintExtension
+        );
+    }
+
+    Ok(())
+}
+
+#[allow(clippy::boxed_local)]
 pub fn handler(
     ctx: Context<CreateFundPool>,
     disaster_id: String,
     pool_id: String,
-    _timestamp: i64,
     params: Box<CreateFundPoolParams>,
 ) -> Result<()> {
     let clock = Clock::get()?;
@@ -160,6 +199,43 @@ pub fn handler(
         ErrorCode::InvalidTokenMint
     );
 
+    validate_pool_mint(&ctx.accounts.token_mint.to_account_info())?;
+
+    if !params.vesting_schedule.is_empty() {
+        require!(
+            params.vesting_schedule.len() <= FundPool::MAX_VESTING_TRANCHES,
+            ErrorCode::VectorTooLong
+        );
+
+        let mut total_percentage: u16 = 0;
+        let mut previous_offset: i64 = 0;
+        for tranche in params.vesting_schedule.iter() {
+            require!(
+                tranche.percentage > 0
+                    && tranche.unlock_offset > previous_offset
+                    && tranche.unlock_offset <= config.max_claim_window_seconds,
+                ErrorCode::InvalidVestingSchedule
+            );
+            total_percentage += tranche.percentage as u16;
+            previous_offset = tranche.unlock_offset;
+        }
+
+        require!(
+            total_percentage == params.distribution_percentage_locked as u16,
+            ErrorCode::InvalidVestingSchedule
+        );
+    }
+
+    let claim_window_seconds = params
+        .claim_window_seconds
+        .unwrap_or(FundPool::DEFAULT_CLAIM_WINDOW_SECONDS);
+
+    require!(
+        claim_window_seconds >= config.min_claim_window_seconds
+            && claim_window_seconds <= config.max_claim_window_seconds,
+        ErrorCode::InvalidClaimWindow
+    );
+
     pool.pool_id = pool_id;
     pool.disaster_id = disaster_id;
     pool.name = params.name;
@@ -193,7 +269,28 @@ pub fn handler(
     pool.expected_beneficiary_count = None;
     pool.registered_beneficiary_count = 0;
 
+    pool.reclaimed_amount = 0;
+    pool.expired_allocation_weight = 0;
+    pool.redistribution_round = 0;
+    pool.redistribution_amount = 0;
+    pool.redistribution_claimed = 0;
+    pool.redistribution_weight = 0;
+    pool.donor_refund_total = 0;
+    pool.donor_refunded = 0;
+    pool.total_transferred_in = 0;
+    pool.total_transferred_out = 0;
+    pool.claim_window_seconds = claim_window_seconds;
+    pool.vesting_schedule = params.vesting_schedule.clone();
+
+    require!(
+        pool.claim_window_seconds >= pool.last_unlock_offset(),
+        ErrorCode::InvalidClaimWindow
+    );
+
     pool.bump = ctx.bumps.pool;
+    pool.activity_count = 0;
+    pool.total_deposited_usd = 0;
+    pool.total_claimed_usd = 0;
 
     ngo.pools_created = ngo
         .pools_created
@@ -201,6 +298,13 @@ pub fn handler(
         .ok_or(ErrorCode::ArithmeticOverflow)?;
     ngo.last_activity_at = clock.unix_timestamp;
 
+    let disaster = &mut ctx.accounts.disaster;
+    disaster.active_pool_count = disaster
+        .active_pool_count
+        .checked_add(1)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    disaster.updated_at = clock.unix_timestamp;
+
     config.total_pools = config
         .total_pools
         .checked_add(1)
@@ -217,7 +321,25 @@ pub fn handler(
         "Pool: {} | Disaster: {} | Type: {:?}",
         pool.name, pool.disaster_id, pool.distribution_type
     );
+    activity_log.rent_payer = ctx.accounts.payer.key();
     activity_log.bump = ctx.bumps.activity_log;
+    activity_log.record_sequence(pool.key(), &mut pool.activity_count)?;
+
+    emit!(FundPoolCreated {
+        pool: pool.key(),
+        disaster_id: pool.disaster_id.clone(),
+        pool_id: pool.pool_id.clone(),
+        name: pool.name.clone(),
+        authority: pool.authority,
+        token_mint: pool.token_mint,
+        distribution_type: pool.distribution_type.clone(),
+        distribution_percentage_immediate: pool.distribution_percentage_immediate,
+        distribution_percentage_locked: pool.distribution_percentage_locked,
+        claim_window_seconds: pool.claim_window_seconds,
+        vesting_tranches: pool.vesting_schedule.len() as u8,
+        target_amount: pool.target_amount,
+        timestamp: clock.unix_timestamp,
+    });
 
     msg!("Fund pool created successfully");
     msg!("Pool ID: {}", pool.pool_id);
@@ -228,15 +350,106 @@ pub fn handler(
         pool.distribution_percentage_immediate
     );
     msg!("Time-locked: {}%", pool.distribution_percentage_locked);
+    msg!("Claim window: {}s", pool.claim_window_seconds);
+    if !pool.vesting_schedule.is_empty() {
+        msg!("Vesting tranches: {}", pool.vesting_schedule.len());
+    }
 
     Ok(())
 }
 
+pub(crate) fn transfer_and_measure<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    from: AccountInfo<'info>,
+    to: &mut InterfaceAccount<'info, TokenAccount>,
+    token_mint: &InterfaceAccount<'info, Mint>,
+    authority: AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+    amount: u64,
+) -> Result<u64> {
+    let balance_before = to.amount;
+
+    let transfer_ctx = CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        TransferChecked {
+            from,
+            mint: token_mint.to_account_info(),
+            to: to.to_account_info(),
+            authority,
+        },
+        signer_seeds,
+    );
+    token_interface::transfer_checked(transfer_ctx, amount, token_mint.decimals)?;
+
+    to.reload()?;
+    let received = to
+        .amount
+        .checked_sub(balance_before)
+        .ok_or(ErrorCode::ArithmeticUnderflow)?;
+
+    Ok(received)
+}
+
+pub(crate) fn wrap_lamports<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    system_program: &Program<'info, System>,
+    donor: &Signer<'info>,
+    to: &InterfaceAccount<'info, TokenAccount>,
+    amount: u64,
+) -> Result<u64> {
+    require!(to.is_native(), ErrorCode::NativeDonationNotSupported);
+
+    let transfer_ctx = CpiContext::new(
+        system_program.to_account_info(),
+        system_program::Transfer {
+            from: donor.to_account_info(),
+            to: to.to_account_info(),
+        },
+    );
+    system_program::transfer(transfer_ctx, amount)?;
+
+    let sync_ctx = CpiContext::new(
+        token_program.to_account_info(),
+        SyncNative {
+            account: to.to_account_info(),
+        },
+    );
+    token_interface::sync_native(sync_ctx)?;
+
+    msg!("Wrapped {} lamports", amount);
+
+    Ok(amount)
+}
+
+pub(crate) fn deposit_from_donor<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    system_program: &Program<'info, System>,
+    donor: &Signer<'info>,
+    donor_token_account: Option<&InterfaceAccount<'info, TokenAccount>>,
+    to: &mut InterfaceAccount<'info, TokenAccount>,
+    token_mint: &InterfaceAccount<'info, Mint>,
+    amount: u64,
+) -> Result<u64> {
+    match donor_token_account {
+        Some(donor_token_account) => transfer_and_measure(
+            token_program,
+            donor_token_account.to_account_info(),
+            to,
+            token_mint,
+            donor.to_account_info(),
+            &[],
+            amount,
+        ),
+        None => wrap_lamports(token_program, system_program, donor, to, amount),
+    }
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct DonateToPoolParams {
     pub amount: u64,
     pub message: String,
     pub is_anonymous: bool,
+    pub donor_commitment: Option<[u8; 32]>,
 }
 
 #[derive(Accounts)]
@@ -250,6 +463,7 @@ pub struct DonateToPool<'info> {
             pool_id.as_bytes()
         ],
         bump = pool.bump,
+        has_one = token_mint @ ErrorCode::InvalidTokenMint,
         constraint = pool.is_active @ ErrorCode::PoolNotActive
     )]
     pub pool: Box<Account<'info, FundPool>>,
@@ -258,7 +472,7 @@ pub struct DonateToPool<'info> {
         mut,
         constraint = pool_token_account.key() == pool.token_account @ ErrorCode::TokenAccountMismatch
     )]
-    pub pool_token_account: Box<Account<'info, TokenAccount>>,
+    pub pool_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
         init,
@@ -266,7 +480,7 @@ pub struct DonateToPool<'info> {
         space = DonationRecord::SPACE,
         seeds = [
             b"donation",
-            donor.key().as_ref(),
+            DonationRecord::donor_seed(&donor.key(), params.donor_commitment).as_ref(),
             pool.key().as_ref(),
             &timestamp.to_le_bytes()
         ],
@@ -279,7 +493,9 @@ pub struct DonateToPool<'info> {
         constraint = donor_token_account.mint == pool.token_mint @ ErrorCode::InvalidTokenMint,
         constraint = donor_token_account.owner == donor.key() @ ErrorCode::InvalidAccountOwner
     )]
-    pub donor_token_account: Box<Account<'info, TokenAccount>>,
+    pub donor_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
 
     #[account(
         mut,
@@ -293,7 +509,7 @@ pub struct DonateToPool<'info> {
         constraint = platform_fee_recipient.owner == config.platform_fee_recipient @ ErrorCode::InvalidAccountOwner,
         constraint = platform_fee_recipient.mint == pool.token_mint @ ErrorCode::InvalidTokenMint
     )]
-    pub platform_fee_recipient: Box<Account<'info, TokenAccount>>,
+    pub platform_fee_recipient: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
         init,
@@ -301,17 +517,43 @@ pub struct DonateToPool<'info> {
         space = ActivityLog::SPACE,
         seeds = [
             b"activity",
-            donor.key().as_ref(),
-            &timestamp.to_le_bytes()
+            donation_record.key().as_ref(),
+            &0u64.to_le_bytes()
         ],
         bump
     )]
     pub activity_log: Account<'info, ActivityLog>,
 
+    #[account(
+        init_if_needed,
+        payer = donor,
+        space = TokenStats::SPACE,
+        seeds = [b"token-stats", pool.token_mint.as_ref()],
+        bump
+    )]
+    pub token_stats: Box<Account<'info, TokenStats>>,
+
+    #[account(
+        init_if_needed,
+        payer = donor,
+        space = TokenStats::SPACE,
+        seeds = [
+            b"disaster-token-stats",
+            disaster_id.as_bytes(),
+            pool.token_mint.as_ref()
+        ],
+        bump
+    )]
+    pub disaster_token_stats: Box<Account<'info, TokenStats>>,
+
+    pub price_feed: Option<Box<Account<'info, PriceFeed>>>,
+
+    pub price_oracle: Option<Box<Account<'info, StandInOracle>>>,
+
     #[account(mut)]
     pub donor: Signer<'info>,
 
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
 }
 
@@ -335,7 +577,7 @@ pub fn donate_to_pool_handler<'info>(
 
     let ngo_account = ctx
         .remaining_accounts
-        .get(0)
+        .first()
         .ok_or(ErrorCode::ResourceNotFound)?;
 
     require!(ngo_account.key() == ngo_pda, ErrorCode::InvalidAccountOwner);
@@ -366,60 +608,111 @@ pub fn donate_to_pool_handler<'info>(
         ErrorCode::StringTooLong
     );
 
+    require!(
+        params.is_anonymous == params.donor_commitment.is_some(),
+        ErrorCode::DonorCommitmentMismatch
+    );
+
     let fee_percentage = if ngo.is_verified {
         config.verified_ngo_fee_percentage
     } else {
         config.unverified_ngo_fee_percentage
     };
 
-    let platform_fee = (params.amount as u128)
+    let fee_amount = (params.amount as u128)
         .checked_mul(fee_percentage as u128)
         .ok_or(ErrorCode::ArithmeticOverflow)?
         .checked_div(10000)
         .ok_or(ErrorCode::DivisionByZero)? as u64;
 
-    let net_amount = params
+    let transfer_amount = params
         .amount
-        .checked_sub(platform_fee)
+        .checked_sub(fee_amount)
         .ok_or(ErrorCode::ArithmeticUnderflow)?;
 
-    let transfer_ctx = CpiContext::new(
-        ctx.accounts.token_program.to_account_info(),
-        Transfer {
-            from: ctx.accounts.donor_token_account.to_account_info(),
-            to: ctx.accounts.pool_token_account.to_account_info(),
-            authority: ctx.accounts.donor.to_account_info(),
-        },
-    );
-    token::transfer(transfer_ctx, net_amount)?;
-
-    if platform_fee > 0 {
-        let fee_transfer_ctx = CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            Transfer {
-                from: ctx.accounts.donor_token_account.to_account_info(),
-                to: ctx.accounts.platform_fee_recipient.to_account_info(),
-                authority: ctx.accounts.donor.to_account_info(),
-            },
-        );
-        token::transfer(fee_transfer_ctx, platform_fee)?;
+    let net_amount = deposit_from_donor(
+        &ctx.accounts.token_program,
+        &ctx.accounts.system_program,
+        &ctx.accounts.donor,
+        ctx.accounts.donor_token_account.as_deref(),
+        &mut ctx.accounts.pool_token_account,
+        &ctx.accounts.token_mint,
+        transfer_amount,
+    )?;
+
+    let net_amount_usd = usd_value(
+        config,
+        pool.token_mint,
+        ctx.accounts.price_feed.as_deref(),
+        ctx.accounts.price_oracle.as_deref(),
+        net_amount,
+        clock.unix_timestamp,
+    )?;
+
+    let platform_fee = if fee_amount > 0 {
+        deposit_from_donor(
+            &ctx.accounts.token_program,
+            &ctx.accounts.system_program,
+            &ctx.accounts.donor,
+            ctx.accounts.donor_token_account.as_deref(),
+            &mut ctx.accounts.platform_fee_recipient,
+            &ctx.accounts.token_mint,
+            fee_amount,
+        )?
+    } else {
+        0
+    };
 
-        config.total_fees_collected = config
-            .total_fees_collected
-            .checked_add(platform_fee)
-            .ok_or(ErrorCode::ArithmeticOverflow)?;
-    }
+    config.total_fees_collected = config
+        .total_fees_collected
+        .checked_add(platform_fee)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
 
     pool.total_deposited = pool
         .total_deposited
         .checked_add(net_amount)
         .ok_or(ErrorCode::ArithmeticOverflow)?;
+    pool.total_deposited_usd = pool
+        .total_deposited_usd
+        .checked_add(net_amount_usd)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
     pool.donor_count = pool
         .donor_count
         .checked_add(1)
         .ok_or(ErrorCode::ArithmeticOverflow)?;
 
-    donation_record.donor = ctx.accounts.donor.key();
+    let token_stats = &mut ctx.accounts.token_stats;
+    token_stats.initialize_if_needed(
+        pool.token_mint,
+        None,
+        ctx.bumps.token_stats,
+        clock.unix_timestamp,
+    );
+    token_stats.record_donation(
+        net_amount,
+        net_amount_usd,
+        platform_fee,
+        clock.unix_timestamp,
+    )?;
+
+    let disaster_token_stats = &mut ctx.accounts.disaster_token_stats;
+    disaster_token_stats.initialize_if_needed(
+        pool.token_mint,
+        Some(disaster_id.clone()),
+        ctx.bumps.disaster_token_stats,
+        clock.unix_timestamp,
+    );
+    disaster_token_stats.record_donation(
+        net_amount,
+        net_amount_usd,
+        platform_fee,
+        clock.unix_timestamp,
+    )?;
+
+    donation_record.donor = match params.donor_commitment {
+        Some(_) => Pubkey::default(),
+        None => ctx.accounts.donor.key(),
+    };
     donation_record.recipient = pool.key();
     donation_record.donation_type = DonationType::Pool;
     donation_record.amount = params.amount;
@@ -436,16 +729,28 @@ pub fn donate_to_pool_handler<'info>(
     donation_record.donor_email = None;
     donation_record.receipt_sent = false;
     donation_record.bump = ctx.bumps.donation_record;
+    donation_record.refunded_amount = 0;
+    donation_record.is_refunded = false;
+    donation_record.receipt_number = None;
+    donation_record.receipt_tax_id = None;
+    donation_record.receipt_fiscal_year = None;
+    donation_record.receipt_issued_at = None;
+    donation_record.donor_commitment = params.donor_commitment;
+    donation_record.activity_count = 0;
 
     config.total_donations = config
         .total_donations
         .checked_add(net_amount)
         .ok_or(ErrorCode::ArithmeticOverflow)?;
+    config.total_donations_usd = config
+        .total_donations_usd
+        .checked_add(net_amount_usd)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
     config.updated_at = clock.unix_timestamp;
 
     let activity_log = &mut ctx.accounts.activity_log;
     activity_log.action_type = ActivityType::DonationToPool;
-    activity_log.actor = ctx.accounts.donor.key();
+    activity_log.actor = donation_record.donor;
     activity_log.target = pool.key();
     activity_log.amount = Some(net_amount);
     activity_log.timestamp = clock.unix_timestamp;
@@ -453,10 +758,30 @@ pub fn donate_to_pool_handler<'info>(
         "Pool: {} | Amount: {} | Fee: {}",
         pool.name, params.amount, platform_fee
     );
+    activity_log.rent_payer = ctx.accounts.donor.key();
     activity_log.bump = ctx.bumps.activity_log;
+    activity_log.record_sequence(donation_record.key(), &mut donation_record.activity_count)?;
+
+    emit!(DonationMade {
+        donation_record: donation_record.key(),
+        donor: donation_record.donor,
+        recipient: donation_record.recipient,
+        donation_type: donation_record.donation_type.clone(),
+        disaster_id: donation_record.disaster_id.clone(),
+        pool: donation_record.pool,
+        token_mint: donation_record.token_mint,
+        amount: donation_record.amount,
+        platform_fee: donation_record.platform_fee,
+        net_amount: donation_record.net_amount,
+        usd_value: net_amount_usd,
+        is_anonymous: donation_record.is_anonymous,
+        timestamp: clock.unix_timestamp,
+        donor_commitment: donation_record.donor_commitment,
+    });
 
     msg!("Donation to pool successful");
     msg!("Amount: {}", params.amount);
+    msg!("USD value: {}", net_amount_usd);
     msg!("Platform fee: {}", platform_fee);
     msg!("Net amount: {}", net_amount);
     msg!("Pool: {}", pool.name);
@@ -470,6 +795,7 @@ pub struct UpdatePoolConfigParams {
     pub eligibility_criteria: Option<String>,
     pub target_amount: Option<u64>,
     pub description: Option<String>,
+    pub claim_window_seconds: Option<i64>,
 }
 
 #[derive(Accounts)]
@@ -487,6 +813,18 @@ pub struct UpdatePoolConfig<'info> {
     )]
     pub pool: Account<'info, FundPool>,
 
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, PlatformConfig>,
+
+    #[account(
+        seeds = [b"ngo", authority.key().as_ref()],
+        bump = ngo.bump,
+    )]
+    pub ngo: Account<'info, NGO>,
+
     pub authority: Signer<'info>,
 }
 
@@ -496,14 +834,18 @@ pub fn update_pool_config_handler(
     _pool_id: String,
     params: UpdatePoolConfigParams,
 ) -> Result<()> {
+    let clock = Clock::get()?;
     let pool = &mut ctx.accounts.pool;
 
+    require!(!ctx.accounts.ngo.is_blacklisted, ErrorCode::NGOBlacklisted);
+
     require!(
         !pool.is_distributed,
         ErrorCode::DistributionAlreadyCompleted
     );
 
     if let Some(is_active) = params.is_active {
+        require!(pool.closed_at.is_none(), ErrorCode::PoolClosed);
         pool.is_active = is_active;
         msg!("Pool active status updated to: {}", is_active);
     }
@@ -531,13 +873,38 @@ pub fn update_pool_config_handler(
         msg!("Pool description updated");
     }
 
+    if let Some(claim_window) = params.claim_window_seconds {
+        let config = &ctx.accounts.config;
+        require!(
+            claim_window >= config.min_claim_window_seconds
+                && claim_window <= config.max_claim_window_seconds,
+            ErrorCode::InvalidClaimWindow
+        );
+        require!(
+            claim_window >= pool.last_unlock_offset(),
+            ErrorCode::InvalidClaimWindow
+        );
+        pool.claim_window_seconds = claim_window;
+        msg!("Pool claim window updated to: {}s", claim_window);
+    }
+
+    emit!(PoolConfigUpdated {
+        pool: pool.key(),
+        name: pool.name.clone(),
+        is_active: pool.is_active,
+        target_amount: pool.target_amount,
+        claim_window_seconds: pool.claim_window_seconds,
+        authority: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
     msg!("Pool configuration updated successfully");
 
     Ok(())
 }
 
 #[derive(Accounts)]
-#[instruction(disaster_id: String, pool_id: String, timestamp: i64)]
+#[instruction(disaster_id: String, pool_id: String)]
 pub struct ClosePool<'info> {
     #[account(
         mut,
@@ -551,14 +918,21 @@ pub struct ClosePool<'info> {
     )]
     pub pool: Account<'info, FundPool>,
 
+    #[account(
+        mut,
+        seeds = [b"disaster", disaster_id.as_bytes()],
+        bump = disaster.bump,
+    )]
+    pub disaster: Account<'info, DisasterEvent>,
+
     #[account(
         init,
         payer = authority,
         space = ActivityLog::SPACE,
         seeds = [
             b"activity",
-            authority.key().as_ref(),
-            &timestamp.to_le_bytes()
+            pool.key().as_ref(),
+            &pool.activity_count.to_le_bytes()
         ],
         bump
     )]
@@ -574,7 +948,6 @@ pub fn close_pool_handler(
     ctx: Context<ClosePool>,
     _disaster_id: String,
     _pool_id: String,
-    _timestamp: i64,
 ) -> Result<()> {
     let clock = Clock::get()?;
     let pool = &mut ctx.accounts.pool;
@@ -584,6 +957,15 @@ pub fn close_pool_handler(
     pool.is_active = false;
     pool.closed_at = Some(clock.unix_timestamp);
 
+    let released = release_undistributed_to_donors(pool)?;
+
+    let disaster = &mut ctx.accounts.disaster;
+    disaster.active_pool_count = disaster
+        .active_pool_count
+        .checked_sub(1)
+        .ok_or(ErrorCode::ArithmeticUnderflow)?;
+    disaster.updated_at = clock.unix_timestamp;
+
     let activity_log = &mut ctx.accounts.activity_log;
     activity_log.action_type = ActivityType::FundPoolClosed;
     activity_log.actor = ctx.accounts.authority.key();
@@ -591,10 +973,25 @@ pub fn close_pool_handler(
     activity_log.amount = Some(pool.total_deposited);
     activity_log.timestamp = clock.unix_timestamp;
     activity_log.metadata = format!(
-        "Pool: {} | Deposited: {} | Distributed: {}",
-        pool.name, pool.total_deposited, pool.total_distributed
+        "Pool: {} | Deposited: {} | Distributed: {} | Released for refunds: {}",
+        pool.name, pool.total_deposited, pool.total_distributed, released
     );
+    activity_log.rent_payer = ctx.accounts.authority.key();
     activity_log.bump = ctx.bumps.activity_log;
+    activity_log.record_sequence(pool.key(), &mut pool.activity_count)?;
+
+    emit!(PoolClosed {
+        pool: pool.key(),
+        disaster_id: pool.disaster_id.clone(),
+        pool_id: pool.pool_id.clone(),
+        name: pool.name.clone(),
+        total_deposited: pool.total_deposited,
+        total_distributed: pool.total_distributed,
+        total_claimed: pool.total_claimed,
+        released_for_refunds: released,
+        closed_by: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
 
     msg!("Pool closed successfully");
     msg!("Pool: {}", pool.name);
@@ -602,6 +999,224 @@ pub fn close_pool_handler(
     msg!("Total distributed: {}", pool.total_distributed);
     msg!("Total claimed: {}", pool.total_claimed);
     msg!("Unclaimed: {}", pool.total_distributed - pool.total_claimed);
+    msg!("Released for donor refunds: {}", released);
+
+    Ok(())
+}
+
+pub(crate) fn release_undistributed_to_donors(pool: &mut FundPool) -> Result<u64> {
+    let undistributed = pool
+        .total_deposited
+        .checked_sub(pool.total_distributed)
+        .and_then(|v| v.checked_sub(pool.total_transferred_out))
+        .and_then(|v| v.checked_sub(pool.donor_refund_total))
+        .ok_or(ErrorCode::ArithmeticUnderflow)?;
+
+    pool.donor_refund_total = pool
+        .donor_refund_total
+        .checked_add(undistributed)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    pool.reclaimed_amount = 0;
+
+    Ok(undistributed)
+}
+
+#[derive(Accounts)]
+#[instruction(disaster_id: String, pool_id: String, donor_salt: Option<[u8; 32]>)]
+pub struct RefundDonation<'info> {
+    #[account(
+        mut,
+        constraint = donation_record.pool == Some(pool.key()) @ ErrorCode::AccountDataMismatch,
+        constraint = donation_record.is_owned_by(&donor.key(), donor_salt) @ ErrorCode::InvalidAccountOwner
+    )]
+    pub donation_record: Box<Account<'info, DonationRecord>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"pool",
+            disaster_id.as_bytes(),
+            pool_id.as_bytes()
+        ],
+        bump = pool.bump,
+        has_one = token_mint @ ErrorCode::InvalidTokenMint,
+    )]
+    pub pool: Box<Account<'info, FundPool>>,
+
+    #[account(
+        mut,
+        constraint = pool_token_account.key() == pool.token_account @ ErrorCode::TokenAccountMismatch
+    )]
+    pub pool_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = donor_token_account.mint == pool.token_mint @ ErrorCode::InvalidTokenMint,
+        constraint = donor_token_account.owner == donor.key() @ ErrorCode::InvalidAccountOwner
+    )]
+    pub donor_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [b"disaster", disaster_id.as_bytes()],
+        bump = disaster.bump,
+    )]
+    pub disaster: Box<Account<'info, DisasterEvent>>,
+
+    #[account(
+        seeds = [b"ngo", pool.authority.as_ref()],
+        bump = ngo.bump,
+    )]
+    pub ngo: Box<Account<'info, NGO>>,
+
+    #[account(
+        init,
+        payer = donor,
+        space = ActivityLog::SPACE,
+        seeds = [
+            b"activity",
+            pool.key().as_ref(),
+            &pool.activity_count.to_le_bytes()
+        ],
+        bump
+    )]
+    pub activity_log: Account<'info, ActivityLog>,
+
+    #[account(mut)]
+    pub donor: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn refund_donation_handler(
+    ctx: Context<RefundDonation>,
+    _disaster_id: String,
+    _pool_id: String,
+    _donor_salt: Option<[u8; 32]>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    let pool = &mut ctx.accounts.pool;
+    let donation_record = &mut ctx.accounts.donation_record;
+
+    require!(
+        !donation_record.is_refunded,
+        ErrorCode::DonationAlreadyRefunded
+    );
+
+    if pool.closed_at.is_none() && ctx.accounts.ngo.is_blacklisted {
+        pool.is_active = false;
+        pool.closed_at = Some(clock.unix_timestamp);
+
+        let released = release_undistributed_to_donors(pool)?;
+
+        let disaster = &mut ctx.accounts.disaster;
+        disaster.active_pool_count = disaster
+            .active_pool_count
+            .checked_sub(1)
+            .ok_or(ErrorCode::ArithmeticUnderflow)?;
+        disaster.updated_at = clock.unix_timestamp;
+
+        emit!(PoolClosed {
+            pool: pool.key(),
+            disaster_id: pool.disaster_id.clone(),
+            pool_id: pool.pool_id.clone(),
+            name: pool.name.clone(),
+            total_deposited: pool.total_deposited,
+            total_distributed: pool.total_distributed,
+            total_claimed: pool.total_claimed,
+            released_for_refunds: released,
+            closed_by: ctx.accounts.donor.key(),
+            timestamp: clock.unix_timestamp,
+        });
+
+        msg!("Pool closed due to blacklisted NGO");
+        msg!("Released for donor refunds: {}", released);
+    }
+
+    require!(pool.donor_refund_total > 0, ErrorCode::NoRefundAvailable);
+
+    let donated_total = pool
+        .total_deposited
+        .checked_sub(pool.total_transferred_in)
+        .ok_or(ErrorCode::ArithmeticUnderflow)?;
+
+    let entitled = (pool.donor_refund_total as u128)
+        .checked_mul(donation_record.net_amount as u128)
+        .ok_or(ErrorCode::ArithmeticOverflow)?
+        .checked_div(donated_total as u128)
+        .ok_or(ErrorCode::DivisionByZero)? as u64;
+
+    let refund_amount = entitled.saturating_sub(donation_record.refunded_amount);
+
+    require!(refund_amount > 0, ErrorCode::NoRefundAvailable);
+
+    let disaster_id_bytes = pool.disaster_id.as_bytes();
+    let pool_id_bytes = pool.pool_id.as_bytes();
+    let seeds = &[b"pool", disaster_id_bytes, pool_id_bytes, &[pool.bump]];
+    let signer_seeds = &[&seeds[..]];
+
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        TransferChecked {
+            from: ctx.accounts.pool_token_account.to_account_info(),
+            mint: ctx.accounts.token_mint.to_account_info(),
+            to: ctx.accounts.donor_token_account.to_account_info(),
+            authority: pool.to_account_info(),
+        },
+        signer_seeds,
+    );
+    token_interface::transfer_checked(
+        transfer_ctx,
+        refund_amount,
+        ctx.accounts.token_mint.decimals,
+    )?;
+
+    donation_record.refunded_amount = donation_record
+        .refunded_amount
+        .checked_add(refund_amount)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    donation_record.is_refunded = donation_record.refunded_amount >= donation_record.net_amount;
+
+    pool.donor_refunded = pool
+        .donor_refunded
+        .checked_add(refund_amount)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    let activity_log = &mut ctx.accounts.activity_log;
+    activity_log.action_type = ActivityType::DonationRefunded;
+    activity_log.actor = ctx.accounts.donor.key();
+    activity_log.target = donation_record.key();
+    activity_log.amount = Some(refund_amount);
+    activity_log.timestamp = clock.unix_timestamp;
+    activity_log.metadata = format!(
+        "Pool: {} | Donated: {} | Refunded: {}",
+        pool.name, donation_record.net_amount, refund_amount
+    );
+    activity_log.rent_payer = ctx.accounts.donor.key();
+    activity_log.bump = ctx.bumps.activity_log;
+    activity_log.record_sequence(pool.key(), &mut pool.activity_count)?;
+
+    emit!(DonationRefunded {
+        donation_record: donation_record.key(),
+        donor: ctx.accounts.donor.key(),
+        pool: pool.key(),
+        refund_amount,
+        total_refunded: donation_record.refunded_amount,
+        is_fully_refunded: donation_record.is_refunded,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Donation refunded successfully");
+    msg!("Pool: {}", pool.name);
+    msg!("Refund amount: {}", refund_amount);
+    msg!(
+        "Total refunded to donor: {}",
+        donation_record.refunded_amount
+    );
 
     Ok(())
 }
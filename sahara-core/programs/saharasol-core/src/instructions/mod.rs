@@ -1,6 +1,8 @@
 #![allow(ambiguous_glob_reexports)]
 
 pub mod admin;
+pub mod aid_card;
+pub mod archive;
 pub mod beneficiary;
 pub mod disaster;
 pub mod distribution;
@@ -9,9 +11,13 @@ pub mod fund_pool;
 pub mod ngo;
 pub mod platform;
 pub mod pool_registration;
+pub mod price_feed;
+pub mod receipt;
 pub mod verification;
 
 pub use admin::*;
+pub use aid_card::*;
+pub use archive::*;
 pub use beneficiary::*;
 pub use disaster::*;
 pub use distribution::*;
@@ -20,4 +26,6 @@ pub use fund_pool::*;
 pub use ngo::*;
 pub use platform::*;
 pub use pool_registration::*;
+pub use price_feed::*;
+pub use receipt::*;
 pub use verification::*;
@@ -1,5 +1,12 @@
 use crate::errors::ErrorCode;
-use crate::state::{ActivityLog, ActivityType, FieldWorker, PlatformConfig, NGO};
+use crate::events::{
+    FieldWorkerAssignedToDisaster, FieldWorkerAssignmentRevoked, FieldWorkerRegistered,
+    FieldWorkerStatusUpdated, FieldWorkerUpdated, NgoRegistered, NgoUpdated,
+};
+use crate::state::{
+    ActivityLog, ActivityType, DisasterEvent, FieldWorker, FieldWorkerAssignment, PlatformConfig,
+    NGO,
+};
 use anchor_lang::prelude::*;
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
@@ -164,6 +171,7 @@ pub fn handler(ctx: Context<RegisterNGO>, params: RegisterNGOParams) -> Result<(
     ngo.last_activity_at = clock.unix_timestamp;
     ngo.notes = String::new();
     ngo.bump = ctx.bumps.ngo;
+    ngo.activity_count = 0;
 
     config.total_ngos = config
         .total_ngos
@@ -171,6 +179,14 @@ pub fn handler(ctx: Context<RegisterNGO>, params: RegisterNGOParams) -> Result<(
         .ok_or(ErrorCode::ArithmeticOverflow)?;
     config.updated_at = clock.unix_timestamp;
 
+    emit!(NgoRegistered {
+        ngo: ngo.key(),
+        authority: ngo.authority,
+        name: ngo.name.clone(),
+        registration_number: ngo.registration_number.clone(),
+        timestamp: clock.unix_timestamp,
+    });
+
     msg!("NGO registered successfully");
     msg!("Name: {}", ngo.name);
     msg!("Registration number: {}", ngo.registration_number);
@@ -301,6 +317,7 @@ pub fn register_field_worker_handler(
     field_worker.registered_by = ctx.accounts.ngo_authority.key();
     field_worker.notes = String::new();
     field_worker.bump = ctx.bumps.field_worker;
+    field_worker.activity_count = 0;
 
     ngo.field_workers_count = ngo
         .field_workers_count
@@ -314,6 +331,16 @@ pub fn register_field_worker_handler(
         .ok_or(ErrorCode::ArithmeticOverflow)?;
     config.updated_at = clock.unix_timestamp;
 
+    emit!(FieldWorkerRegistered {
+        field_worker: field_worker.key(),
+        authority: field_worker.authority,
+        name: field_worker.name.clone(),
+        organization: field_worker.organization.clone(),
+        ngo: ngo_key,
+        ngo_name: ngo_name.clone(),
+        timestamp: clock.unix_timestamp,
+    });
+
     msg!("Field worker registered successfully");
     msg!("Name: {}", field_worker.name);
     msg!("Organization: {}", field_worker.organization);
@@ -377,6 +404,13 @@ pub fn update_field_worker_status_handler(
 
     field_worker.last_activity_at = clock.unix_timestamp;
 
+    emit!(FieldWorkerStatusUpdated {
+        field_worker: field_worker.key(),
+        name: field_worker.name.clone(),
+        is_active: field_worker.is_active,
+        timestamp: clock.unix_timestamp,
+    });
+
     msg!("Field worker status updated successfully");
     msg!("Name: {}", field_worker.name);
     msg!("Active: {}", field_worker.is_active);
@@ -402,7 +436,7 @@ pub struct UpdateNGOParams {
 }
 
 #[derive(Accounts)]
-#[instruction(params: UpdateNGOParams, timestamp: i64)]
+#[instruction(params: UpdateNGOParams)]
 pub struct UpdateNGO<'info> {
     #[account(
         mut,
@@ -418,8 +452,8 @@ pub struct UpdateNGO<'info> {
         space = ActivityLog::SPACE,
         seeds = [
             b"activity",
-            authority.key().as_ref(),
-            &timestamp.to_le_bytes()
+            ngo.key().as_ref(),
+            &ngo.activity_count.to_le_bytes()
         ],
         bump
     )]
@@ -437,11 +471,7 @@ pub struct UpdateNGO<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn update_ngo_handler(
-    ctx: Context<UpdateNGO>,
-    params: UpdateNGOParams,
-    _timestamp: i64,
-) -> Result<()> {
+pub fn update_ngo_handler(ctx: Context<UpdateNGO>, params: UpdateNGOParams) -> Result<()> {
     let ngo = &mut ctx.accounts.ngo;
     let config = &ctx.accounts.config;
     let clock = Clock::get()?;
@@ -584,7 +614,17 @@ pub fn update_ngo_handler(
     } else {
         format!("NGO: {} | Profile updated", ngo.name)
     };
+    activity_log.rent_payer = ctx.accounts.authority.key();
     activity_log.bump = ctx.bumps.activity_log;
+    activity_log.record_sequence(ngo.key(), &mut ngo.activity_count)?;
+
+    emit!(NgoUpdated {
+        ngo: ngo.key(),
+        name: ngo.name.clone(),
+        verification_removed: was_verified && !ngo.is_verified,
+        actor: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
 
     msg!("NGO profile updated successfully");
     msg!("NGO: {}", ngo.name);
@@ -605,7 +645,7 @@ pub struct UpdateFieldWorkerParams {
 }
 
 #[derive(Accounts)]
-#[instruction(params: UpdateFieldWorkerParams, timestamp: i64)]
+#[instruction(params: UpdateFieldWorkerParams)]
 pub struct UpdateFieldWorker<'info> {
     #[account(
         mut,
@@ -619,8 +659,8 @@ pub struct UpdateFieldWorker<'info> {
         space = ActivityLog::SPACE,
         seeds = [
             b"activity",
-            authority.key().as_ref(),
-            &timestamp.to_le_bytes()
+            field_worker.key().as_ref(),
+            &field_worker.activity_count.to_le_bytes()
         ],
         bump
     )]
@@ -651,7 +691,6 @@ pub struct UpdateFieldWorker<'info> {
 pub fn update_field_worker_handler(
     ctx: Context<UpdateFieldWorker>,
     params: UpdateFieldWorkerParams,
-    _timestamp: i64,
 ) -> Result<()> {
     let field_worker = &mut ctx.accounts.field_worker;
     let config = &ctx.accounts.config;
@@ -711,7 +750,17 @@ pub fn update_field_worker_handler(
         "Field Worker: {} | NGO: {} | Profile updated",
         field_worker.name, ctx.accounts.ngo.name
     );
+    activity_log.rent_payer = ctx.accounts.authority.key();
     activity_log.bump = ctx.bumps.activity_log;
+    activity_log.record_sequence(field_worker.key(), &mut field_worker.activity_count)?;
+
+    emit!(FieldWorkerUpdated {
+        field_worker: field_worker.key(),
+        name: field_worker.name.clone(),
+        ngo: ctx.accounts.ngo.key(),
+        actor: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
 
     msg!("Field worker profile updated successfully");
     msg!("Field worker: {}", field_worker.name);
@@ -719,3 +768,204 @@ pub fn update_field_worker_handler(
 
     Ok(())
 }
+
+#[derive(Accounts)]
+#[instruction(disaster_id: String)]
+pub struct AssignFieldWorkerToDisaster<'info> {
+    #[account(
+        init,
+        payer = ngo_authority,
+        space = FieldWorkerAssignment::SPACE,
+        seeds = [
+            b"field-worker-assignment",
+            field_worker.authority.as_ref(),
+            disaster_id.as_bytes()
+        ],
+        bump
+    )]
+    pub assignment: Account<'info, FieldWorkerAssignment>,
+
+    #[account(
+        mut,
+        seeds = [b"field-worker", field_worker.authority.as_ref()],
+        bump = field_worker.bump,
+        constraint = field_worker.ngo == Some(ngo.key()) @ ErrorCode::UnauthorizedFieldWorker,
+        constraint = field_worker.is_active @ ErrorCode::FieldWorkerNotActive
+    )]
+    pub field_worker: Account<'info, FieldWorker>,
+
+    #[account(
+        seeds = [b"disaster", disaster_id.as_bytes()],
+        bump = disaster.bump,
+        constraint = disaster.is_active @ ErrorCode::DisasterNotActive
+    )]
+    pub disaster: Account<'info, DisasterEvent>,
+
+    #[account(
+        seeds = [b"ngo", ngo_authority.key().as_ref()],
+        bump = ngo.bump,
+        constraint = ngo.is_active @ ErrorCode::NGONotActive,
+        constraint = !ngo.is_blacklisted @ ErrorCode::NGOBlacklisted
+    )]
+    pub ngo: Account<'info, NGO>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, PlatformConfig>,
+
+    #[account(
+        init,
+        payer = ngo_authority,
+        space = ActivityLog::SPACE,
+        seeds = [
+            b"activity",
+            field_worker.key().as_ref(),
+            &field_worker.activity_count.to_le_bytes()
+        ],
+        bump
+    )]
+    pub activity_log: Account<'info, ActivityLog>,
+
+    #[account(mut)]
+    pub ngo_authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn assign_field_worker_to_disaster_handler(
+    ctx: Context<AssignFieldWorkerToDisaster>,
+    disaster_id: String,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let config = &ctx.accounts.config;
+
+    require!(!config.is_paused, ErrorCode::PlatformPaused);
+
+    require!(
+        disaster_id.len() <= FieldWorkerAssignment::MAX_DISASTER_ID_LEN,
+        ErrorCode::StringTooLong
+    );
+
+    let assignment = &mut ctx.accounts.assignment;
+    assignment.field_worker = ctx.accounts.field_worker.authority;
+    assignment.ngo = ctx.accounts.ngo.key();
+    assignment.disaster_id = disaster_id;
+    assignment.assigned_by = ctx.accounts.ngo_authority.key();
+    assignment.assigned_at = clock.unix_timestamp;
+    assignment.bump = ctx.bumps.assignment;
+
+    let activity_log = &mut ctx.accounts.activity_log;
+    activity_log.action_type = ActivityType::FieldWorkerAssigned;
+    activity_log.actor = ctx.accounts.ngo_authority.key();
+    activity_log.target = ctx.accounts.field_worker.key();
+    activity_log.amount = None;
+    activity_log.timestamp = clock.unix_timestamp;
+    activity_log.metadata = format!(
+        "Field Worker: {} | Disaster: {}",
+        ctx.accounts.field_worker.name, ctx.accounts.disaster.name
+    );
+    activity_log.rent_payer = ctx.accounts.ngo_authority.key();
+    activity_log.bump = ctx.bumps.activity_log;
+    let field_worker = &mut ctx.accounts.field_worker;
+    activity_log.record_sequence(field_worker.key(), &mut field_worker.activity_count)?;
+
+    emit!(FieldWorkerAssignedToDisaster {
+        field_worker: ctx.accounts.field_worker.key(),
+        name: ctx.accounts.field_worker.name.clone(),
+        ngo: assignment.ngo,
+        disaster_id: assignment.disaster_id.clone(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Field worker assigned to disaster");
+    msg!("Field worker: {}", ctx.accounts.field_worker.name);
+    msg!("Disaster: {}", assignment.disaster_id);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(disaster_id: String)]
+pub struct RevokeFieldWorkerAssignment<'info> {
+    #[account(
+        mut,
+        close = ngo_authority,
+        seeds = [
+            b"field-worker-assignment",
+            field_worker.authority.as_ref(),
+            disaster_id.as_bytes()
+        ],
+        bump = assignment.bump,
+        constraint = assignment.ngo == ngo.key() @ ErrorCode::UnauthorizedNGO
+    )]
+    pub assignment: Account<'info, FieldWorkerAssignment>,
+
+    #[account(
+        mut,
+        seeds = [b"field-worker", field_worker.authority.as_ref()],
+        bump = field_worker.bump,
+    )]
+    pub field_worker: Account<'info, FieldWorker>,
+
+    #[account(
+        seeds = [b"ngo", ngo_authority.key().as_ref()],
+        bump = ngo.bump,
+    )]
+    pub ngo: Account<'info, NGO>,
+
+    #[account(
+        init,
+        payer = ngo_authority,
+        space = ActivityLog::SPACE,
+        seeds = [
+            b"activity",
+            field_worker.key().as_ref(),
+            &field_worker.activity_count.to_le_bytes()
+        ],
+        bump
+    )]
+    pub activity_log: Account<'info, ActivityLog>,
+
+    #[account(mut)]
+    pub ngo_authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn revoke_field_worker_assignment_handler(
+    ctx: Context<RevokeFieldWorkerAssignment>,
+    disaster_id: String,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    let activity_log = &mut ctx.accounts.activity_log;
+    activity_log.action_type = ActivityType::FieldWorkerUnassigned;
+    activity_log.actor = ctx.accounts.ngo_authority.key();
+    activity_log.target = ctx.accounts.field_worker.key();
+    activity_log.amount = None;
+    activity_log.timestamp = clock.unix_timestamp;
+    activity_log.metadata = format!(
+        "Field Worker: {} | Disaster: {}",
+        ctx.accounts.field_worker.name, disaster_id
+    );
+    activity_log.rent_payer = ctx.accounts.ngo_authority.key();
+    activity_log.bump = ctx.bumps.activity_log;
+    let field_worker = &mut ctx.accounts.field_worker;
+    activity_log.record_sequence(field_worker.key(), &mut field_worker.activity_count)?;
+
+    emit!(FieldWorkerAssignmentRevoked {
+        field_worker: ctx.accounts.field_worker.key(),
+        name: ctx.accounts.field_worker.name.clone(),
+        ngo: ctx.accounts.ngo.key(),
+        disaster_id: disaster_id.clone(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Field worker assignment revoked");
+    msg!("Field worker: {}", ctx.accounts.field_worker.name);
+    msg!("Disaster: {}", disaster_id);
+
+    Ok(())
+}
@@ -1,6 +1,10 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::ErrorCode;
+use crate::events::{
+    AllowedTokenAdded, AllowedTokenRemoved, ManagerAdded, ManagerRemoved, PlatformConfigUpdated,
+    PlatformInitialized,
+};
 use crate::state::{AdminAction, AdminActionType, PlatformConfig};
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
@@ -117,6 +121,31 @@ pub fn handler(ctx: Context<InitializePlatform>, params: InitializePlatformParam
 
     config.bump = ctx.bumps.config;
 
+    config.min_claim_window_seconds = PlatformConfig::DEFAULT_MIN_CLAIM_WINDOW_SECONDS;
+    config.max_claim_window_seconds = PlatformConfig::DEFAULT_MAX_CLAIM_WINDOW_SECONDS;
+
+    config.location_density_threshold = 0;
+
+    config.exclude_registering_verifier = false;
+    config.min_verifier_ngos = 0;
+
+    config.verification_window_seconds = PlatformConfig::DEFAULT_VERIFICATION_WINDOW_SECONDS;
+
+    config.activity_archival_enabled = false;
+
+    config.total_donations_usd = 0;
+    config.total_aid_distributed_usd = 0;
+    config.admin_action_count = 0;
+
+    emit!(PlatformInitialized {
+        config: config.key(),
+        admin: config.admin,
+        platform_fee_percentage: config.platform_fee_percentage,
+        verification_threshold: config.verification_threshold,
+        max_verifiers: config.max_verifiers,
+        timestamp: clock.unix_timestamp,
+    });
+
     msg!("Platform initialized successfully");
     msg!("Admin: {}", config.admin);
     msg!("Platform fee: {}bps", config.platform_fee_percentage);
@@ -140,6 +169,13 @@ pub struct UpdatePlatformConfigParams {
     pub usdc_mint: Option<Pubkey>,
     pub is_paused: Option<bool>,
     pub sol_usd_oracle: Option<Pubkey>,
+    pub min_claim_window_seconds: Option<i64>,
+    pub max_claim_window_seconds: Option<i64>,
+    pub location_density_threshold: Option<u32>,
+    pub exclude_registering_verifier: Option<bool>,
+    pub min_verifier_ngos: Option<u8>,
+    pub verification_window_seconds: Option<i64>,
+    pub activity_archival_enabled: Option<bool>,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
@@ -150,7 +186,6 @@ pub struct UpdatePlatformConfigWithAuditParams {
 }
 
 #[derive(Accounts)]
-#[instruction(timestamp: i64)]
 pub struct UpdatePlatformConfig<'info> {
     #[account(
         mut,
@@ -167,7 +202,7 @@ pub struct UpdatePlatformConfig<'info> {
         seeds = [
             b"admin-action",
             admin.key().as_ref(),
-            &timestamp.to_le_bytes()
+            &config.admin_action_count.to_le_bytes()
         ],
         bump
     )]
@@ -181,7 +216,6 @@ pub struct UpdatePlatformConfig<'info> {
 
 pub fn update_platform_config_handler(
     ctx: Context<UpdatePlatformConfig>,
-    _timestamp: i64,
     params: UpdatePlatformConfigWithAuditParams,
 ) -> Result<()> {
     let config = &mut ctx.accounts.config;
@@ -217,6 +251,13 @@ pub fn update_platform_config_handler(
             threshold > 0 && threshold <= config.max_verifiers,
             ErrorCode::VerificationThresholdNotMet
         );
+        require!(
+            threshold
+                >= config_params
+                    .min_verifier_ngos
+                    .unwrap_or(config.min_verifier_ngos),
+            ErrorCode::InvalidVerifierNgoThreshold
+        );
         config.verification_threshold = threshold;
         msg!("Verification threshold updated to: {}", threshold);
     }
@@ -268,6 +309,54 @@ pub fn update_platform_config_handler(
         msg!("SOL/USD oracle updated to: {}", oracle);
     }
 
+    if let Some(min_window) = config_params.min_claim_window_seconds {
+        require!(
+            min_window > 0 && min_window <= config.max_claim_window_seconds,
+            ErrorCode::InvalidClaimWindow
+        );
+        config.min_claim_window_seconds = min_window;
+        msg!("Min claim window updated to: {}s", min_window);
+    }
+
+    if let Some(max_window) = config_params.max_claim_window_seconds {
+        require!(
+            max_window >= config.min_claim_window_seconds,
+            ErrorCode::InvalidClaimWindow
+        );
+        config.max_claim_window_seconds = max_window;
+        msg!("Max claim window updated to: {}s", max_window);
+    }
+
+    if let Some(threshold) = config_params.location_density_threshold {
+        config.location_density_threshold = threshold;
+        msg!("Location density threshold updated to: {}", threshold);
+    }
+
+    if let Some(exclude) = config_params.exclude_registering_verifier {
+        config.exclude_registering_verifier = exclude;
+        msg!("Exclude registering verifier: {}", exclude);
+    }
+
+    if let Some(min_ngos) = config_params.min_verifier_ngos {
+        require!(
+            min_ngos <= config.verification_threshold,
+            ErrorCode::InvalidVerifierNgoThreshold
+        );
+        config.min_verifier_ngos = min_ngos;
+        msg!("Min verifier NGOs updated to: {}", min_ngos);
+    }
+
+    if let Some(window) = config_params.verification_window_seconds {
+        require!(window >= 0, ErrorCode::InvalidInput);
+        config.verification_window_seconds = window;
+        msg!("Verification window updated to: {}s", window);
+    }
+
+    if let Some(enabled) = config_params.activity_archival_enabled {
+        config.activity_archival_enabled = enabled;
+        msg!("Activity archival enabled: {}", enabled);
+    }
+
     config.updated_at = clock.unix_timestamp;
 
     admin_action.action_type = action_type;
@@ -277,6 +366,15 @@ pub fn update_platform_config_handler(
     admin_action.timestamp = clock.unix_timestamp;
     admin_action.metadata = params.metadata;
     admin_action.bump = ctx.bumps.admin_action;
+    admin_action.record_sequence(&mut config.admin_action_count)?;
+
+    emit!(PlatformConfigUpdated {
+        config: config.key(),
+        action_type: admin_action.action_type.clone(),
+        params: config_params,
+        admin: ctx.accounts.admin.key(),
+        timestamp: clock.unix_timestamp,
+    });
 
     msg!("Platform configuration updated successfully");
     msg!("Admin action logged: {:?}", admin_action.action_type);
@@ -285,7 +383,6 @@ pub fn update_platform_config_handler(
 }
 
 #[derive(Accounts)]
-#[instruction(timestamp: i64)]
 pub struct ManageAllowedTokens<'info> {
     #[account(
         mut,
@@ -302,7 +399,7 @@ pub struct ManageAllowedTokens<'info> {
         seeds = [
             b"admin-action",
             admin.key().as_ref(),
-            &timestamp.to_le_bytes()
+            &config.admin_action_count.to_le_bytes()
         ],
         bump
     )]
@@ -316,7 +413,6 @@ pub struct ManageAllowedTokens<'info> {
 
 pub fn add_allowed_token_handler(
     ctx: Context<ManageAllowedTokens>,
-    _timestamp: i64,
     token_mint: Pubkey,
     reason: String,
 ) -> Result<()> {
@@ -353,6 +449,15 @@ pub fn add_allowed_token_handler(
         config.allowed_tokens.len()
     );
     admin_action.bump = ctx.bumps.admin_action;
+    admin_action.record_sequence(&mut config.admin_action_count)?;
+
+    emit!(AllowedTokenAdded {
+        token_mint,
+        total_allowed_tokens: config.allowed_tokens.len() as u8,
+        reason: reason.clone(),
+        admin: ctx.accounts.admin.key(),
+        timestamp: clock.unix_timestamp,
+    });
 
     msg!("Token added to whitelist: {}", token_mint);
     msg!("Total allowed tokens: {}", config.allowed_tokens.len());
@@ -363,7 +468,6 @@ pub fn add_allowed_token_handler(
 
 pub fn remove_allowed_token_handler(
     ctx: Context<ManageAllowedTokens>,
-    _timestamp: i64,
     token_mint: Pubkey,
     reason: String,
 ) -> Result<()> {
@@ -401,6 +505,15 @@ pub fn remove_allowed_token_handler(
         config.allowed_tokens.len()
     );
     admin_action.bump = ctx.bumps.admin_action;
+    admin_action.record_sequence(&mut config.admin_action_count)?;
+
+    emit!(AllowedTokenRemoved {
+        token_mint,
+        total_allowed_tokens: config.allowed_tokens.len() as u8,
+        reason: reason.clone(),
+        admin: ctx.accounts.admin.key(),
+        timestamp: clock.unix_timestamp,
+    });
 
     msg!("Token removed from whitelist: {}", token_mint);
     msg!("Total allowed tokens: {}", config.allowed_tokens.len());
@@ -410,7 +523,6 @@ pub fn remove_allowed_token_handler(
 }
 
 #[derive(Accounts)]
-#[instruction(timestamp: i64)]
 pub struct AddManager<'info> {
     #[account(
         mut,
@@ -427,7 +539,7 @@ pub struct AddManager<'info> {
         seeds = [
             b"admin-action",
             admin.key().as_ref(),
-            &timestamp.to_le_bytes()
+            &config.admin_action_count.to_le_bytes()
         ],
         bump
     )]
@@ -441,7 +553,6 @@ pub struct AddManager<'info> {
 
 pub fn add_manager_handler(
     ctx: Context<AddManager>,
-    _timestamp: i64,
     manager: Pubkey,
     reason: String,
 ) -> Result<()> {
@@ -480,6 +591,15 @@ pub fn add_manager_handler(
         config.managers.len()
     );
     admin_action.bump = ctx.bumps.admin_action;
+    admin_action.record_sequence(&mut config.admin_action_count)?;
+
+    emit!(ManagerAdded {
+        manager,
+        total_managers: config.managers.len() as u8,
+        reason: reason.clone(),
+        admin: ctx.accounts.admin.key(),
+        timestamp: clock.unix_timestamp,
+    });
 
     msg!("Manager added: {}", manager);
     msg!("Total managers: {}", config.managers.len());
@@ -489,7 +609,6 @@ pub fn add_manager_handler(
 }
 
 #[derive(Accounts)]
-#[instruction(timestamp: i64)]
 pub struct RemoveManager<'info> {
     #[account(
         mut,
@@ -506,7 +625,7 @@ pub struct RemoveManager<'info> {
         seeds = [
             b"admin-action",
             admin.key().as_ref(),
-            &timestamp.to_le_bytes()
+            &config.admin_action_count.to_le_bytes()
         ],
         bump
     )]
@@ -520,7 +639,6 @@ pub struct RemoveManager<'info> {
 
 pub fn remove_manager_handler(
     ctx: Context<RemoveManager>,
-    _timestamp: i64,
     manager: Pubkey,
     reason: String,
 ) -> Result<()> {
@@ -553,6 +671,15 @@ pub fn remove_manager_handler(
         config.managers.len()
     );
     admin_action.bump = ctx.bumps.admin_action;
+    admin_action.record_sequence(&mut config.admin_action_count)?;
+
+    emit!(ManagerRemoved {
+        manager,
+        total_managers: config.managers.len() as u8,
+        reason: reason.clone(),
+        admin: ctx.accounts.admin.key(),
+        timestamp: clock.unix_timestamp,
+    });
 
     msg!("Manager removed: {}", manager);
     msg!("Total managers: {}", config.managers.len());
@@ -1,7 +1,8 @@
 use crate::errors::ErrorCode;
+use crate::events::{BeneficiaryRegisteredForPool, PoolRegistrationClosed, PoolRegistrationLocked};
 use crate::state::{
     ActivityLog, ActivityType, Beneficiary, DisasterEvent, DistributionType, FundPool,
-    PoolRegistration, VerificationStatus,
+    HouseholdRegistry, PoolRegistration, VerificationStatus,
 };
 use anchor_lang::prelude::*;
 
@@ -11,7 +12,7 @@ pub struct RegisterBeneficiaryForPoolParams {
 }
 
 #[derive(Accounts)]
-#[instruction(disaster_id: String, pool_id: String, params: RegisterBeneficiaryForPoolParams, timestamp: i64)]
+#[instruction(disaster_id: String, pool_id: String, params: RegisterBeneficiaryForPoolParams)]
 pub struct RegisterBeneficiaryForPool<'info> {
     #[account(
         mut,
@@ -50,6 +51,9 @@ pub struct RegisterBeneficiaryForPool<'info> {
     )]
     pub beneficiary: Account<'info, Beneficiary>,
 
+    #[account(mut)]
+    pub household_registry: Option<Account<'info, HouseholdRegistry>>,
+
     #[account(
         mut,
         seeds = [b"disaster", disaster_id.as_bytes()],
@@ -64,8 +68,7 @@ pub struct RegisterBeneficiaryForPool<'info> {
         seeds = [
             b"activity",
             pool.key().as_ref(),
-            params.beneficiary_authority.as_ref(),
-            &timestamp.to_le_bytes()
+            &pool.activity_count.to_le_bytes()
         ],
         bump
     )]
@@ -83,12 +86,11 @@ pub struct RegisterBeneficiaryForPool<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn register_beneficiary_for_pool_handler(
-    ctx: Context<RegisterBeneficiaryForPool>,
+pub fn register_beneficiary_for_pool_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RegisterBeneficiaryForPool<'info>>,
     _disaster_id: String,
     _pool_id: String,
     _params: RegisterBeneficiaryForPoolParams,
-    _timestamp: i64,
 ) -> Result<()> {
     let clock = Clock::get()?;
 
@@ -111,6 +113,49 @@ pub fn register_beneficiary_for_pool_handler(
         );
     }
 
+    if let Some(ref household_id) = beneficiary.household_id {
+        let household_registry = ctx
+            .accounts
+            .household_registry
+            .as_mut()
+            .ok_or(ErrorCode::ResourceNotFound)?;
+
+        require!(
+            household_registry.disaster_id == beneficiary.disaster_id
+                && household_registry.household_id == *household_id
+                && household_registry.members.contains(&beneficiary.authority),
+            ErrorCode::AccountDataMismatch
+        );
+
+        require!(
+            !household_registry.pools.contains(&pool.key()),
+            ErrorCode::HouseholdAlreadyRegisteredForPool
+        );
+
+        require!(
+            ctx.remaining_accounts.len() <= HouseholdRegistry::MAX_POOLS,
+            ErrorCode::BatchSizeTooLarge
+        );
+
+        for pool_info in ctx.remaining_accounts.iter() {
+            let closed_pool: Account<FundPool> = Account::try_from(pool_info)?;
+
+            if closed_pool.closed_at.is_none() {
+                msg!("Pool {}: still open, not pruned", closed_pool.name);
+                continue;
+            }
+
+            household_registry.pools.retain(|key| key != pool_info.key);
+        }
+
+        require!(
+            household_registry.pools.len() < HouseholdRegistry::MAX_POOLS,
+            ErrorCode::VectorTooLong
+        );
+
+        household_registry.pools.push(pool.key());
+    }
+
     let allocation_weight: u64 = match pool.distribution_type {
         DistributionType::Equal => 1,
         DistributionType::WeightedFamily => beneficiary.family_size as u64,
@@ -123,6 +168,7 @@ pub fn register_beneficiary_for_pool_handler(
     pool_registration.registered_at = clock.unix_timestamp;
     pool_registration.is_distributed = false;
     pool_registration.bump = ctx.bumps.pool_registration;
+    pool_registration.rent_payer = ctx.accounts.payer.key();
 
     pool.total_allocation_weight = pool
         .total_allocation_weight
@@ -146,7 +192,20 @@ pub fn register_beneficiary_for_pool_handler(
         "Registered for pool: {} | Weight: {} | Type: {:?}",
         pool.name, allocation_weight, pool.distribution_type
     );
+    activity_log.rent_payer = ctx.accounts.payer.key();
     activity_log.bump = ctx.bumps.activity_log;
+    activity_log.record_sequence(pool.key(), &mut pool.activity_count)?;
+
+    emit!(BeneficiaryRegisteredForPool {
+        pool: pool.key(),
+        beneficiary: beneficiary.key(),
+        name: beneficiary.name.clone(),
+        allocation_weight,
+        pool_total_weight: pool.total_allocation_weight,
+        registered_count: pool.registered_beneficiary_count,
+        authority: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
 
     msg!("Beneficiary registered for pool successfully");
     msg!("Beneficiary: {}", beneficiary.name);
@@ -158,7 +217,7 @@ pub fn register_beneficiary_for_pool_handler(
 }
 
 #[derive(Accounts)]
-#[instruction(disaster_id: String, pool_id: String, timestamp: i64)]
+#[instruction(disaster_id: String, pool_id: String)]
 pub struct LockPoolRegistration<'info> {
     #[account(
         mut,
@@ -181,7 +240,7 @@ pub struct LockPoolRegistration<'info> {
         seeds = [
             b"activity",
             pool.key().as_ref(),
-            &timestamp.to_le_bytes()
+            &pool.activity_count.to_le_bytes()
         ],
         bump
     )]
@@ -197,7 +256,6 @@ pub fn lock_pool_registration_handler(
     ctx: Context<LockPoolRegistration>,
     _disaster_id: String,
     _pool_id: String,
-    _timestamp: i64,
 ) -> Result<()> {
     let clock = Clock::get()?;
     let pool = &mut ctx.accounts.pool;
@@ -219,7 +277,18 @@ pub fn lock_pool_registration_handler(
         "Registration locked for pool: {} | Total weight: {} | Registered: {}",
         pool.name, pool.total_allocation_weight, pool.registered_beneficiary_count
     );
+    activity_log.rent_payer = ctx.accounts.authority.key();
     activity_log.bump = ctx.bumps.activity_log;
+    activity_log.record_sequence(pool.key(), &mut pool.activity_count)?;
+
+    emit!(PoolRegistrationLocked {
+        pool: pool.key(),
+        name: pool.name.clone(),
+        total_allocation_weight: pool.total_allocation_weight,
+        registered_count: pool.registered_beneficiary_count,
+        authority: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
 
     msg!("Pool registration locked successfully");
     msg!("Pool: {}", pool.name);
@@ -232,3 +301,59 @@ pub fn lock_pool_registration_handler(
 
     Ok(())
 }
+
+#[derive(Accounts)]
+#[instruction(disaster_id: String, pool_id: String, beneficiary_authority: Pubkey)]
+pub struct ClosePoolRegistration<'info> {
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [
+            b"pool-registration",
+            pool.key().as_ref(),
+            beneficiary_authority.as_ref()
+        ],
+        bump = pool_registration.bump,
+        constraint = pool_registration.is_distributed @ ErrorCode::AccountStillInUse
+    )]
+    pub pool_registration: Account<'info, PoolRegistration>,
+
+    #[account(
+        seeds = [
+            b"pool",
+            disaster_id.as_bytes(),
+            pool_id.as_bytes()
+        ],
+        bump = pool.bump,
+    )]
+    pub pool: Account<'info, FundPool>,
+
+    #[account(
+        mut,
+        address = pool_registration.rent_payer @ ErrorCode::AccountDataMismatch
+    )]
+    pub rent_payer: SystemAccount<'info>,
+}
+
+pub fn close_pool_registration_handler(
+    ctx: Context<ClosePoolRegistration>,
+    _disaster_id: String,
+    _pool_id: String,
+    _beneficiary_authority: Pubkey,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    emit!(PoolRegistrationClosed {
+        pool_registration: ctx.accounts.pool_registration.key(),
+        pool: ctx.accounts.pool.key(),
+        beneficiary: ctx.accounts.pool_registration.beneficiary,
+        rent_payer: ctx.accounts.rent_payer.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Pool registration closed, rent returned to payer");
+    msg!("Pool: {}", ctx.accounts.pool.name);
+    msg!("Rent payer: {}", ctx.accounts.rent_payer.key());
+
+    Ok(())
+}
@@ -0,0 +1,425 @@
+use crate::errors::ErrorCode;
+use crate::events::{PriceFeedRegistered, PriceFeedUpdated, StandInOraclePriceSet};
+use crate::state::{AdminAction, AdminActionType, PlatformConfig, PriceFeed, StandInOracle};
+use anchor_lang::prelude::*;
+use anchor_spl::token::spl_token;
+use anchor_spl::token_2022::spl_token_2022;
+use anchor_spl::token_interface::Mint;
+
+pub(crate) fn usd_value(
+    config: &PlatformConfig,
+    token_mint: Pubkey,
+    price_feed: Option<&Account<PriceFeed>>,
+    price_oracle: Option<&Account<StandInOracle>>,
+    amount: u64,
+    now: i64,
+) -> Result<u64> {
+    if token_mint == config.usdc_mint {
+        return Ok(amount);
+    }
+
+    let price_feed = price_feed.ok_or(ErrorCode::PriceFeedRequired)?;
+    let price_oracle = price_oracle.ok_or(ErrorCode::PriceFeedRequired)?;
+
+    check_price_accounts(config, token_mint, price_feed, price_oracle)?;
+
+    price_feed.usd_value(price_oracle, amount, now)
+}
+
+pub(crate) fn claim_usd_value(
+    config: &PlatformConfig,
+    token_mint: Pubkey,
+    price_feed: Option<&Account<PriceFeed>>,
+    price_oracle: Option<&Account<StandInOracle>>,
+    amount: u64,
+    now: i64,
+) -> Result<Option<u64>> {
+    if token_mint == config.usdc_mint {
+        return Ok(Some(amount));
+    }
+
+    let (Some(price_feed), Some(price_oracle)) = (price_feed, price_oracle) else {
+        msg!("No price feed supplied, USD value not recorded");
+        return Ok(None);
+    };
+
+    check_price_accounts(config, token_mint, price_feed, price_oracle)?;
+
+    let value = price_feed.usd_value(price_oracle, amount, now).ok();
+    if value.is_none() {
+        msg!("Price feed unavailable, USD value not recorded");
+    }
+
+    Ok(value)
+}
+
+fn check_price_accounts(
+    config: &PlatformConfig,
+    token_mint: Pubkey,
+    price_feed: &Account<PriceFeed>,
+    price_oracle: &Account<StandInOracle>,
+) -> Result<()> {
+    require!(
+        price_feed.token_mint == token_mint,
+        ErrorCode::InvalidTokenMint
+    );
+
+    let is_wrapped_sol =
+        token_mint == spl_token::native_mint::ID || token_mint == spl_token_2022::native_mint::ID;
+    let expected_oracle = match config.sol_usd_oracle {
+        Some(sol_usd_oracle) if is_wrapped_sol => sol_usd_oracle,
+        _ => price_feed.oracle,
+    };
+    require!(
+        price_oracle.key() == expected_oracle,
+        ErrorCode::OracleAccountMismatch
+    );
+
+    Ok(())
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SetStandInOraclePriceParams {
+    pub price: i64,
+    pub exponent: i32,
+    pub publish_time: Option<i64>,
+}
+
+fn apply_stand_in_price(
+    oracle: &mut StandInOracle,
+    params: &SetStandInOraclePriceParams,
+    now: i64,
+) -> Result<()> {
+    require!(params.price > 0, ErrorCode::InvalidOraclePrice);
+    require!(
+        params.exponent >= StandInOracle::MIN_EXPONENT
+            && params.exponent <= StandInOracle::MAX_EXPONENT,
+        ErrorCode::InvalidOraclePrice
+    );
+
+    let publish_time = params.publish_time.unwrap_or(now);
+    require!(publish_time <= now, ErrorCode::InvalidTimestamp);
+
+    oracle.price = params.price;
+    oracle.exponent = params.exponent;
+    oracle.publish_time = publish_time;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeStandInOracle<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = StandInOracle::SPACE,
+        seeds = [b"stand-in-oracle", token_mint.key().as_ref()],
+        bump
+    )]
+    pub price_oracle: Account<'info, StandInOracle>,
+
+    pub token_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == admin.key() @ ErrorCode::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, PlatformConfig>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_stand_in_oracle_handler(
+    ctx: Context<InitializeStandInOracle>,
+    params: SetStandInOraclePriceParams,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let price_oracle = &mut ctx.accounts.price_oracle;
+
+    price_oracle.token_mint = ctx.accounts.token_mint.key();
+    price_oracle.authority = ctx.accounts.admin.key();
+    price_oracle.bump = ctx.bumps.price_oracle;
+    apply_stand_in_price(price_oracle, &params, clock.unix_timestamp)?;
+
+    emit!(StandInOraclePriceSet {
+        price_oracle: price_oracle.key(),
+        token_mint: price_oracle.token_mint,
+        price: price_oracle.price,
+        exponent: price_oracle.exponent,
+        publish_time: price_oracle.publish_time,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Stand-in oracle initialized");
+    msg!("Token mint: {}", price_oracle.token_mint);
+    msg!(
+        "Price: {} x 10^{}",
+        price_oracle.price,
+        price_oracle.exponent
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateStandInOracle<'info> {
+    #[account(
+        mut,
+        seeds = [b"stand-in-oracle", price_oracle.token_mint.as_ref()],
+        bump = price_oracle.bump,
+        constraint = price_oracle.authority == authority.key() @ ErrorCode::UnauthorizedModification
+    )]
+    pub price_oracle: Account<'info, StandInOracle>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn update_stand_in_oracle_handler(
+    ctx: Context<UpdateStandInOracle>,
+    params: SetStandInOraclePriceParams,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let price_oracle = &mut ctx.accounts.price_oracle;
+
+    apply_stand_in_price(price_oracle, &params, clock.unix_timestamp)?;
+
+    emit!(StandInOraclePriceSet {
+        price_oracle: price_oracle.key(),
+        token_mint: price_oracle.token_mint,
+        price: price_oracle.price,
+        exponent: price_oracle.exponent,
+        publish_time: price_oracle.publish_time,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Stand-in oracle updated");
+    msg!(
+        "Price: {} x 10^{}",
+        price_oracle.price,
+        price_oracle.exponent
+    );
+
+    Ok(())
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RegisterPriceFeedParams {
+    pub oracle: Pubkey,
+    pub max_staleness_seconds: i64,
+    pub reason: String,
+}
+
+#[derive(Accounts)]
+#[instruction(params: RegisterPriceFeedParams)]
+pub struct RegisterPriceFeed<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = PriceFeed::SPACE,
+        seeds = [b"price-feed", token_mint.key().as_ref()],
+        bump
+    )]
+    pub price_feed: Account<'info, PriceFeed>,
+
+    pub token_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == admin.key() @ ErrorCode::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, PlatformConfig>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = AdminAction::SPACE,
+        seeds = [
+            b"admin-action",
+            admin.key().as_ref(),
+            &config.admin_action_count.to_le_bytes()
+        ],
+        bump
+    )]
+    pub admin_action: Account<'info, AdminAction>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn register_price_feed_handler(
+    ctx: Context<RegisterPriceFeed>,
+    params: RegisterPriceFeedParams,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let config = &ctx.accounts.config;
+    let token_mint = &ctx.accounts.token_mint;
+
+    require!(
+        params.reason.len() <= AdminAction::MAX_REASON_LEN,
+        ErrorCode::StringTooLong
+    );
+
+    require!(
+        config.allowed_tokens.contains(&token_mint.key()),
+        ErrorCode::TokenNotInAllowedList
+    );
+
+    require!(params.max_staleness_seconds > 0, ErrorCode::InvalidInput);
+
+    require!(
+        token_mint.decimals <= PriceFeed::MAX_TOKEN_DECIMALS,
+        ErrorCode::InvalidInput
+    );
+
+    let price_feed = &mut ctx.accounts.price_feed;
+    price_feed.token_mint = token_mint.key();
+    price_feed.oracle = params.oracle;
+    price_feed.token_decimals = token_mint.decimals;
+    price_feed.max_staleness_seconds = params.max_staleness_seconds;
+    price_feed.is_active = true;
+    price_feed.created_at = clock.unix_timestamp;
+    price_feed.updated_at = clock.unix_timestamp;
+    price_feed.bump = ctx.bumps.price_feed;
+
+    let admin_action = &mut ctx.accounts.admin_action;
+    admin_action.action_type = AdminActionType::RegisterPriceFeed;
+    admin_action.target = price_feed.key();
+    admin_action.admin = ctx.accounts.admin.key();
+    admin_action.reason = params.reason;
+    admin_action.timestamp = clock.unix_timestamp;
+    admin_action.metadata = format!(
+        "Mint: {} | Oracle: {} | Max staleness: {}s",
+        price_feed.token_mint, price_feed.oracle, price_feed.max_staleness_seconds
+    );
+    admin_action.bump = ctx.bumps.admin_action;
+    admin_action.record_sequence(&mut ctx.accounts.config.admin_action_count)?;
+
+    emit!(PriceFeedRegistered {
+        price_feed: price_feed.key(),
+        token_mint: price_feed.token_mint,
+        oracle: price_feed.oracle,
+        token_decimals: price_feed.token_decimals,
+        max_staleness_seconds: price_feed.max_staleness_seconds,
+        admin: ctx.accounts.admin.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Price feed registered");
+    msg!("Token mint: {}", price_feed.token_mint);
+    msg!("Oracle: {}", price_feed.oracle);
+
+    Ok(())
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdatePriceFeedParams {
+    pub oracle: Option<Pubkey>,
+    pub max_staleness_seconds: Option<i64>,
+    pub is_active: Option<bool>,
+    pub reason: String,
+}
+
+#[derive(Accounts)]
+#[instruction(params: UpdatePriceFeedParams)]
+pub struct UpdatePriceFeed<'info> {
+    #[account(
+        mut,
+        seeds = [b"price-feed", price_feed.token_mint.as_ref()],
+        bump = price_feed.bump,
+    )]
+    pub price_feed: Account<'info, PriceFeed>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == admin.key() @ ErrorCode::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, PlatformConfig>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = AdminAction::SPACE,
+        seeds = [
+            b"admin-action",
+            admin.key().as_ref(),
+            &config.admin_action_count.to_le_bytes()
+        ],
+        bump
+    )]
+    pub admin_action: Account<'info, AdminAction>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn update_price_feed_handler(
+    ctx: Context<UpdatePriceFeed>,
+    params: UpdatePriceFeedParams,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let price_feed = &mut ctx.accounts.price_feed;
+
+    require!(
+        params.reason.len() <= AdminAction::MAX_REASON_LEN,
+        ErrorCode::StringTooLong
+    );
+
+    if let Some(oracle) = params.oracle {
+        price_feed.oracle = oracle;
+    }
+
+    if let Some(max_staleness_seconds) = params.max_staleness_seconds {
+        require!(max_staleness_seconds > 0, ErrorCode::InvalidInput);
+        price_feed.max_staleness_seconds = max_staleness_seconds;
+    }
+
+    if let Some(is_active) = params.is_active {
+        price_feed.is_active = is_active;
+    }
+
+    price_feed.updated_at = clock.unix_timestamp;
+
+    let admin_action = &mut ctx.accounts.admin_action;
+    admin_action.action_type = AdminActionType::UpdatePriceFeed;
+    admin_action.target = price_feed.key();
+    admin_action.admin = ctx.accounts.admin.key();
+    admin_action.reason = params.reason;
+    admin_action.timestamp = clock.unix_timestamp;
+    admin_action.metadata = format!(
+        "Mint: {} | Oracle: {} | Max staleness: {}s | Active: {}",
+        price_feed.token_mint,
+        price_feed.oracle,
+        price_feed.max_staleness_seconds,
+        price_feed.is_active
+    );
+    admin_action.bump = ctx.bumps.admin_action;
+    admin_action.record_sequence(&mut ctx.accounts.config.admin_action_count)?;
+
+    emit!(PriceFeedUpdated {
+        price_feed: price_feed.key(),
+        token_mint: price_feed.token_mint,
+        oracle: price_feed.oracle,
+        max_staleness_seconds: price_feed.max_staleness_seconds,
+        is_active: price_feed.is_active,
+        admin: ctx.accounts.admin.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Price feed updated");
+    msg!("Token mint: {}", price_feed.token_mint);
+    msg!("Active: {}", price_feed.is_active);
+
+    Ok(())
+}
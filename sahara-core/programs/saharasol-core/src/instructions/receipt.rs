@@ -0,0 +1,183 @@
+use crate::errors::ErrorCode;
+use crate::events::DonationReceiptIssued;
+use crate::state::{ActivityLog, ActivityType, DonationRecord, FundPool, NGO};
+use crate::utils::civil_date;
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct DonorProof {
+    pub donor: Pubkey,
+    pub salt: [u8; 32],
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct IssueReceiptParams {
+    pub receipt_number: String,
+    pub fiscal_year: u16,
+    pub donor_name: Option<String>,
+    pub donor_email: Option<String>,
+    pub donor_proof: Option<DonorProof>,
+}
+
+#[derive(Accounts)]
+#[instruction(disaster_id: String, pool_id: String)]
+pub struct IssueReceipt<'info> {
+    #[account(
+        mut,
+        constraint = donation_record.pool == Some(pool.key()) @ ErrorCode::AccountDataMismatch
+    )]
+    pub donation_record: Box<Account<'info, DonationRecord>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"pool",
+            disaster_id.as_bytes(),
+            pool_id.as_bytes()
+        ],
+        bump = pool.bump,
+        constraint = pool.authority == ngo_authority.key() @ ErrorCode::UnauthorizedModification
+    )]
+    pub pool: Box<Account<'info, FundPool>>,
+
+    #[account(
+        seeds = [b"ngo", ngo_authority.key().as_ref()],
+        bump = ngo.bump,
+        constraint = ngo.is_active @ ErrorCode::NGONotActive,
+        constraint = !ngo.is_blacklisted @ ErrorCode::NGOBlacklisted
+    )]
+    pub ngo: Box<Account<'info, NGO>>,
+
+    #[account(
+        init,
+        payer = ngo_authority,
+        space = ActivityLog::SPACE,
+        seeds = [
+            b"activity",
+            pool.key().as_ref(),
+            &pool.activity_count.to_le_bytes()
+        ],
+        bump
+    )]
+    pub activity_log: Account<'info, ActivityLog>,
+
+    #[account(mut)]
+    pub ngo_authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn issue_receipt_handler(
+    ctx: Context<IssueReceipt>,
+    _disaster_id: String,
+    _pool_id: String,
+    params: IssueReceiptParams,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    let pool = &mut ctx.accounts.pool;
+    let ngo = &ctx.accounts.ngo;
+    let donation_record = &mut ctx.accounts.donation_record;
+
+    require!(
+        !donation_record.receipt_sent,
+        ErrorCode::ReceiptAlreadyIssued
+    );
+
+    require!(
+        !donation_record.is_refunded,
+        ErrorCode::DonationAlreadyRefunded
+    );
+
+    require!(!ngo.tax_id.is_empty(), ErrorCode::NGOTaxIdMissing);
+
+    require!(
+        !params.receipt_number.is_empty(),
+        ErrorCode::InvalidReceiptNumber
+    );
+
+    require!(
+        params.receipt_number.len() <= DonationRecord::MAX_RECEIPT_NUMBER_LEN,
+        ErrorCode::StringTooLong
+    );
+
+    let (donation_year, _, _) = civil_date(donation_record.timestamp);
+    let fiscal_year = i64::from(params.fiscal_year);
+    require!(
+        fiscal_year == donation_year || fiscal_year == donation_year + 1,
+        ErrorCode::InvalidFiscalYear
+    );
+
+    if donation_record.is_anonymous {
+        match &params.donor_proof {
+            Some(proof) => require!(
+                donation_record.is_owned_by(&proof.donor, Some(proof.salt)),
+                ErrorCode::InvalidDonorProof
+            ),
+            None => require!(
+                params.donor_name.is_none() && params.donor_email.is_none(),
+                ErrorCode::AnonymousDonorDetails
+            ),
+        }
+    }
+
+    if let Some(donor_name) = &params.donor_name {
+        require!(
+            donor_name.len() <= DonationRecord::MAX_DONOR_NAME_LEN,
+            ErrorCode::StringTooLong
+        );
+    }
+
+    if let Some(donor_email) = &params.donor_email {
+        require!(
+            donor_email.len() <= DonationRecord::MAX_DONOR_EMAIL_LEN,
+            ErrorCode::StringTooLong
+        );
+    }
+
+    let receipt_amount = donation_record
+        .net_amount
+        .checked_sub(donation_record.refunded_amount)
+        .ok_or(ErrorCode::ArithmeticUnderflow)?;
+
+    donation_record.donor_name = params.donor_name;
+    donation_record.donor_email = params.donor_email;
+    donation_record.receipt_number = Some(params.receipt_number.clone());
+    donation_record.receipt_tax_id = Some(ngo.tax_id.clone());
+    donation_record.receipt_fiscal_year = Some(params.fiscal_year);
+    donation_record.receipt_issued_at = Some(clock.unix_timestamp);
+    donation_record.receipt_sent = true;
+
+    let activity_log = &mut ctx.accounts.activity_log;
+    activity_log.action_type = ActivityType::ReceiptIssued;
+    activity_log.actor = ctx.accounts.ngo_authority.key();
+    activity_log.target = donation_record.key();
+    activity_log.amount = Some(receipt_amount);
+    activity_log.timestamp = clock.unix_timestamp;
+    activity_log.metadata = format!(
+        "Pool: {} | Receipt: {} | Fiscal year: {}",
+        pool.name, params.receipt_number, params.fiscal_year
+    );
+    activity_log.rent_payer = ctx.accounts.ngo_authority.key();
+    activity_log.bump = ctx.bumps.activity_log;
+    activity_log.record_sequence(pool.key(), &mut pool.activity_count)?;
+
+    emit!(DonationReceiptIssued {
+        donation_record: donation_record.key(),
+        donor: donation_record.donor,
+        pool: pool.key(),
+        ngo: ngo.key(),
+        receipt_number: params.receipt_number,
+        tax_id: ngo.tax_id.clone(),
+        fiscal_year: params.fiscal_year,
+        amount: receipt_amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Donation receipt issued");
+    msg!("NGO: {}", ngo.name);
+    msg!("Fiscal year: {}", params.fiscal_year);
+    msg!("Amount: {}", receipt_amount);
+
+    Ok(())
+}
@@ -1,12 +1,21 @@
 use crate::errors::ErrorCode;
+use crate::events::{
+    AidCardFrozen, BeneficiaryFlagged, BeneficiaryVerificationExpired,
+    BeneficiaryVerificationRevoked, BeneficiaryVerified, FlaggedBeneficiaryReviewed,
+    VerificationApprovalAdded,
+};
+use crate::instructions::aid_card::{freeze_aid_card, mint_aid_card, AidCardAccounts};
+use crate::instructions::beneficiary::require_field_worker_coverage;
 use crate::state::{
-    ActivityLog, ActivityType, Beneficiary, DisasterEvent, FieldWorker, PlatformConfig,
-    VerificationStatus,
+    ActivityLog, ActivityType, AdminAction, AdminActionType, Beneficiary, DisasterEvent,
+    FieldWorker, FieldWorkerAssignment, PlatformConfig, VerificationStatus, NGO,
 };
 use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_2022::Token2022;
 
 #[derive(Accounts)]
-#[instruction(beneficiary_authority: Pubkey, disaster_id: String, timestamp: i64)]
+#[instruction(beneficiary_authority: Pubkey, disaster_id: String)]
 pub struct VerifyBeneficiary<'info> {
     #[account(
         mut,
@@ -34,6 +43,8 @@ pub struct VerifyBeneficiary<'info> {
     )]
     pub field_worker: Account<'info, FieldWorker>,
 
+    pub field_worker_assignment: Option<Account<'info, FieldWorkerAssignment>>,
+
     #[account(
         mut,
         seeds = [b"config"],
@@ -47,16 +58,34 @@ pub struct VerifyBeneficiary<'info> {
         space = ActivityLog::SPACE,
         seeds = [
             b"activity",
-            field_worker_authority.key().as_ref(),
-            &timestamp.to_le_bytes()
+            beneficiary.key().as_ref(),
+            &beneficiary.activity_count.to_le_bytes()
         ],
         bump
     )]
     pub activity_log: Account<'info, ActivityLog>,
 
+    /// CHECK: Created and initialized as a Token-2022 mint when verification completes
+    #[account(
+        mut,
+        seeds = [b"aid-card", beneficiary.key().as_ref()],
+        bump
+    )]
+    pub aid_card_mint: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Checked against the beneficiary's associated token address in the handler
+    #[account(mut)]
+    pub aid_card_token_account: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Must be the beneficiary authority that receives the card
+    #[account(address = beneficiary_authority @ ErrorCode::AidCardAccountMismatch)]
+    pub beneficiary_wallet: Option<UncheckedAccount<'info>>,
+
     #[account(mut)]
     pub field_worker_authority: Signer<'info>,
 
+    pub token_2022_program: Option<Program<'info, Token2022>>,
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
     pub system_program: Program<'info, System>,
 }
 
@@ -64,7 +93,6 @@ pub fn handler(
     ctx: Context<VerifyBeneficiary>,
     _beneficiary_authority: Pubkey,
     _disaster_id: String,
-    _timestamp: i64,
 ) -> Result<()> {
     let clock = Clock::get()?;
 
@@ -90,7 +118,34 @@ pub fn handler(
         ErrorCode::BeneficiaryFlagged
     );
 
+    require!(
+        beneficiary.verification_status != VerificationStatus::Expired,
+        ErrorCode::VerificationExpired
+    );
+
+    if let Some(deadline) = disaster.verification_deadline(config, beneficiary.registered_at) {
+        require!(
+            clock.unix_timestamp <= deadline,
+            ErrorCode::VerificationExpired
+        );
+    }
+
+    require_field_worker_coverage(
+        field_worker,
+        ctx.accounts.field_worker_assignment.as_ref(),
+        disaster,
+        &beneficiary.location,
+    )?;
+
     let field_worker_key = ctx.accounts.field_worker_authority.key();
+
+    if config.exclude_registering_verifier {
+        require!(
+            beneficiary.registered_by != field_worker_key,
+            ErrorCode::SelfVerificationNotAllowed
+        );
+    }
+
     require!(
         !beneficiary.verifier_approvals.contains(&field_worker_key),
         ErrorCode::DuplicateApproval
@@ -101,9 +156,42 @@ pub fn handler(
         ErrorCode::MaxVerifiersReached
     );
 
+    let adds_new_ngo = field_worker
+        .ngo
+        .is_some_and(|ngo| !beneficiary.verifier_ngos.contains(&ngo));
+    if !adds_new_ngo {
+        let remaining_slots = config.max_verifiers as usize - beneficiary.verifier_approvals.len();
+        let missing_ngos =
+            (config.min_verifier_ngos as usize).saturating_sub(beneficiary.verifier_ngos.len());
+        require!(
+            remaining_slots > missing_ngos,
+            ErrorCode::IndependentVerifierRequired
+        );
+    }
+
     beneficiary.verifier_approvals.push(field_worker_key);
 
+    let verifier_ngo = field_worker.ngo;
+    if let Some(ngo) = verifier_ngo {
+        if !beneficiary.verifier_ngos.contains(&ngo) {
+            beneficiary.verifier_ngos.push(ngo);
+        }
+    }
+
     let approval_count = beneficiary.verifier_approvals.len() as u8;
+    let distinct_ngos = beneficiary.verifier_ngos.len() as u8;
+
+    emit!(VerificationApprovalAdded {
+        beneficiary: beneficiary.key(),
+        field_worker: field_worker_key,
+        field_worker_name: field_worker.name.clone(),
+        verifier_ngo,
+        approval_count,
+        verification_threshold: config.verification_threshold,
+        distinct_ngos,
+        min_verifier_ngos: config.min_verifier_ngos,
+        timestamp: clock.unix_timestamp,
+    });
 
     msg!("Approval added from field worker: {}", field_worker.name);
     msg!(
@@ -112,7 +200,16 @@ pub fn handler(
         config.verification_threshold
     );
 
-    if approval_count >= config.verification_threshold {
+    if config.min_verifier_ngos > 0 {
+        msg!(
+            "Distinct NGOs: {}/{}",
+            distinct_ngos,
+            config.min_verifier_ngos
+        );
+    }
+
+    if approval_count >= config.verification_threshold && distinct_ngos >= config.min_verifier_ngos
+    {
         beneficiary.verification_status = VerificationStatus::Verified;
         beneficiary.verified_at = Some(clock.unix_timestamp);
 
@@ -128,9 +225,55 @@ pub fn handler(
             .ok_or(ErrorCode::ArithmeticOverflow)?;
         config.updated_at = clock.unix_timestamp;
 
+        emit!(BeneficiaryVerified {
+            beneficiary: beneficiary.key(),
+            authority: beneficiary.authority,
+            disaster_id: beneficiary.disaster_id.clone(),
+            name: beneficiary.name.clone(),
+            approval_count,
+            distinct_ngos,
+            verified_at: clock.unix_timestamp,
+        });
+
         msg!("✓ Beneficiary VERIFIED!");
         msg!("Name: {}", beneficiary.name);
         msg!("Verified at: {}", clock.unix_timestamp);
+
+        if let (
+            Some(aid_card_mint),
+            Some(aid_card_token_account),
+            Some(beneficiary_wallet),
+            Some(token_2022_program),
+            Some(associated_token_program),
+        ) = (
+            ctx.accounts.aid_card_mint.as_ref(),
+            ctx.accounts.aid_card_token_account.as_ref(),
+            ctx.accounts.beneficiary_wallet.as_ref(),
+            ctx.accounts.token_2022_program.as_ref(),
+            ctx.accounts.associated_token_program.as_ref(),
+        ) {
+            mint_aid_card(
+                AidCardAccounts {
+                    mint: &aid_card_mint.to_account_info(),
+                    token_account: &aid_card_token_account.to_account_info(),
+                    owner: &beneficiary_wallet.to_account_info(),
+                    payer: &ctx.accounts.field_worker_authority.to_account_info(),
+                    token_program: &token_2022_program.to_account_info(),
+                    associated_token_program: &associated_token_program.to_account_info(),
+                    system_program: &ctx.accounts.system_program.to_account_info(),
+                },
+                beneficiary,
+                ctx.bumps.aid_card_mint.ok_or(ErrorCode::InvalidBumpSeed)?,
+                clock.unix_timestamp,
+            )?;
+        } else {
+            msg!("Aid card accounts not supplied, card can be issued with issue_aid_card");
+        }
+    } else if approval_count >= config.verification_threshold {
+        msg!(
+            "Approval recorded. Approvals from {} more NGO(s) needed.",
+            config.min_verifier_ngos - distinct_ngos
+        );
     } else {
         msg!(
             "Approval recorded. {} more approval(s) needed.",
@@ -154,7 +297,111 @@ pub fn handler(
         "Beneficiary: {} | Approvals: {}/{}",
         beneficiary.name, approval_count, config.verification_threshold
     );
+    activity_log.rent_payer = ctx.accounts.field_worker_authority.key();
+    activity_log.bump = ctx.bumps.activity_log;
+    activity_log.record_sequence(beneficiary.key(), &mut beneficiary.activity_count)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(beneficiary_authority: Pubkey, disaster_id: String)]
+pub struct ExpireBeneficiaryVerification<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"beneficiary",
+            beneficiary_authority.as_ref(),
+            disaster_id.as_bytes()
+        ],
+        bump = beneficiary.bump,
+        constraint = beneficiary.verification_status == VerificationStatus::Pending @ ErrorCode::InvalidStatusTransition
+    )]
+    pub beneficiary: Account<'info, Beneficiary>,
+
+    #[account(
+        seeds = [b"disaster", disaster_id.as_bytes()],
+        bump = disaster.bump,
+    )]
+    pub disaster: Account<'info, DisasterEvent>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, PlatformConfig>,
+
+    #[account(
+        init,
+        payer = caller,
+        space = ActivityLog::SPACE,
+        seeds = [
+            b"activity",
+            beneficiary.key().as_ref(),
+            &beneficiary.activity_count.to_le_bytes()
+        ],
+        bump
+    )]
+    pub activity_log: Account<'info, ActivityLog>,
+
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn expire_beneficiary_verification_handler(
+    ctx: Context<ExpireBeneficiaryVerification>,
+    _beneficiary_authority: Pubkey,
+    _disaster_id: String,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    let beneficiary = &mut ctx.accounts.beneficiary;
+
+    let deadline = ctx
+        .accounts
+        .disaster
+        .verification_deadline(&ctx.accounts.config, beneficiary.registered_at)
+        .ok_or(ErrorCode::VerificationNotExpired)?;
+
+    require!(
+        clock.unix_timestamp > deadline,
+        ErrorCode::VerificationNotExpired
+    );
+
+    beneficiary.verification_status = VerificationStatus::Expired;
+
+    let activity_log = &mut ctx.accounts.activity_log;
+    activity_log.action_type = ActivityType::BeneficiaryVerificationExpired;
+    activity_log.actor = ctx.accounts.caller.key();
+    activity_log.target = beneficiary.key();
+    activity_log.amount = None;
+    activity_log.timestamp = clock.unix_timestamp;
+    activity_log.metadata = format!(
+        "Beneficiary: {} | Approvals: {} | Deadline: {}",
+        beneficiary.name,
+        beneficiary.verifier_approvals.len(),
+        deadline
+    );
+    activity_log.rent_payer = ctx.accounts.caller.key();
     activity_log.bump = ctx.bumps.activity_log;
+    activity_log.record_sequence(beneficiary.key(), &mut beneficiary.activity_count)?;
+
+    emit!(BeneficiaryVerificationExpired {
+        beneficiary: beneficiary.key(),
+        name: beneficiary.name.clone(),
+        approval_count: beneficiary.verifier_approvals.len() as u8,
+        registered_at: beneficiary.registered_at,
+        deadline,
+        caller: ctx.accounts.caller.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Beneficiary verification EXPIRED");
+    msg!("Name: {}", beneficiary.name);
+    msg!("Registered at: {}", beneficiary.registered_at);
+    msg!("Deadline: {}", deadline);
 
     Ok(())
 }
@@ -233,6 +480,15 @@ pub fn flag_beneficiary_handler(
         .ok_or(ErrorCode::ArithmeticOverflow)?;
     field_worker.last_activity_at = clock.unix_timestamp;
 
+    emit!(BeneficiaryFlagged {
+        beneficiary: beneficiary.key(),
+        name: beneficiary.name.clone(),
+        reason: params.reason.clone(),
+        flagged_by: ctx.accounts.field_worker_authority.key(),
+        field_worker_name: field_worker.name.clone(),
+        timestamp: clock.unix_timestamp,
+    });
+
     msg!("⚠ Beneficiary FLAGGED for review");
     msg!("Name: {}", beneficiary.name);
     msg!("Flagged by: {}", field_worker.name);
@@ -249,7 +505,7 @@ pub struct ReviewFlaggedBeneficiaryParams {
 }
 
 #[derive(Accounts)]
-#[instruction(beneficiary_authority: Pubkey, disaster_id: String)]
+#[instruction(beneficiary_authority: Pubkey, disaster_id: String, params: ReviewFlaggedBeneficiaryParams)]
 pub struct ReviewFlaggedBeneficiary<'info> {
     #[account(
         mut,
@@ -274,11 +530,28 @@ pub struct ReviewFlaggedBeneficiary<'info> {
         mut,
         seeds = [b"config"],
         bump = config.bump,
-        constraint = config.admin == admin.key() @ ErrorCode::UnauthorizedAdmin
     )]
     pub config: Account<'info, PlatformConfig>,
 
+    pub ngo: Option<Account<'info, NGO>>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = AdminAction::SPACE,
+        seeds = [
+            b"admin-action",
+            admin.key().as_ref(),
+            &config.admin_action_count.to_le_bytes()
+        ],
+        bump
+    )]
+    pub admin_action: Account<'info, AdminAction>,
+
+    #[account(mut)]
     pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
 }
 
 pub fn review_flagged_beneficiary_handler(
@@ -291,6 +564,38 @@ pub fn review_flagged_beneficiary_handler(
 
     let beneficiary = &mut ctx.accounts.beneficiary;
     let disaster = &mut ctx.accounts.disaster;
+    let config = &ctx.accounts.config;
+    let admin_action = &mut ctx.accounts.admin_action;
+    let reviewer = ctx.accounts.admin.key();
+
+    if !config.is_admin_or_manager(&reviewer) {
+        require!(
+            disaster.review_delegated && disaster.authority == reviewer,
+            ErrorCode::UnauthorizedAdminOrManager
+        );
+
+        let ngo = ctx
+            .accounts
+            .ngo
+            .as_ref()
+            .ok_or(ErrorCode::UnauthorizedNGO)?;
+        require!(ngo.authority == reviewer, ErrorCode::UnauthorizedNGO);
+        require!(ngo.is_active, ErrorCode::NGONotActive);
+        require!(!ngo.is_blacklisted, ErrorCode::NGOBlacklisted);
+    }
+
+    if let Some(ref notes) = params.notes {
+        require!(
+            notes.len() <= Beneficiary::MAX_ADMIN_NOTES_LEN,
+            ErrorCode::StringTooLong
+        );
+        require!(
+            notes.len() <= AdminAction::MAX_REASON_LEN,
+            ErrorCode::StringTooLong
+        );
+    }
+
+    let flagged_reason = beneficiary.flagged_reason.clone().unwrap_or_default();
 
     if params.approve {
         beneficiary.verification_status = VerificationStatus::Pending;
@@ -298,24 +603,221 @@ pub fn review_flagged_beneficiary_handler(
         beneficiary.flagged_by = None;
         beneficiary.flagged_at = None;
 
-        msg!("✓ Flagged beneficiary APPROVED by admin");
+        msg!("✓ Flagged beneficiary APPROVED");
         msg!("Status returned to: Pending");
         msg!("Beneficiary can now be verified by field workers");
     } else {
         beneficiary.verification_status = VerificationStatus::Rejected;
 
-        msg!("✗ Flagged beneficiary REJECTED by admin");
+        msg!("✗ Flagged beneficiary REJECTED");
         msg!("Beneficiary cannot receive aid");
     }
 
-    if let Some(notes) = params.notes {
-        beneficiary.admin_notes = Some(notes);
+    if let Some(ref notes) = params.notes {
+        beneficiary.admin_notes = Some(notes.clone());
     }
 
     disaster.updated_at = clock.unix_timestamp;
 
+    admin_action.action_type = if params.approve {
+        AdminActionType::VerifyBeneficiary
+    } else {
+        AdminActionType::RejectBeneficiary
+    };
+    admin_action.target = beneficiary.key();
+    admin_action.admin = reviewer;
+    admin_action.reason = params.notes.unwrap_or_default();
+    admin_action.timestamp = clock.unix_timestamp;
+    admin_action.metadata = format!(
+        "Beneficiary: {} | Disaster: {} | Flagged reason: {}",
+        beneficiary.name, beneficiary.disaster_id, flagged_reason
+    );
+    admin_action.bump = ctx.bumps.admin_action;
+    admin_action.record_sequence(&mut ctx.accounts.config.admin_action_count)?;
+
+    emit!(FlaggedBeneficiaryReviewed {
+        beneficiary: beneficiary.key(),
+        name: beneficiary.name.clone(),
+        approved: params.approve,
+        verification_status: beneficiary.verification_status.clone(),
+        flagged_reason,
+        reviewer,
+        timestamp: clock.unix_timestamp,
+    });
+
     msg!("Name: {}", beneficiary.name);
+    msg!("Reviewed by: {}", reviewer);
     msg!("Reviewed at: {}", clock.unix_timestamp);
 
     Ok(())
 }
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RevokeBeneficiaryVerificationParams {
+    pub reason: String,
+}
+
+#[derive(Accounts)]
+#[instruction(beneficiary_authority: Pubkey, disaster_id: String, params: RevokeBeneficiaryVerificationParams)]
+pub struct RevokeBeneficiaryVerification<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"beneficiary",
+            beneficiary_authority.as_ref(),
+            disaster_id.as_bytes()
+        ],
+        bump = beneficiary.bump,
+        constraint = beneficiary.verification_status == VerificationStatus::Verified @ ErrorCode::BeneficiaryNotVerified
+    )]
+    pub beneficiary: Account<'info, Beneficiary>,
+
+    #[account(
+        mut,
+        seeds = [b"disaster", disaster_id.as_bytes()],
+        bump = disaster.bump,
+    )]
+    pub disaster: Account<'info, DisasterEvent>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.is_admin_or_manager(&admin.key()) @ ErrorCode::UnauthorizedAdminOrManager
+    )]
+    pub config: Account<'info, PlatformConfig>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = AdminAction::SPACE,
+        seeds = [
+            b"admin-action",
+            admin.key().as_ref(),
+            &config.admin_action_count.to_le_bytes()
+        ],
+        bump
+    )]
+    pub admin_action: Account<'info, AdminAction>,
+
+    /// CHECK: Checked against the beneficiary's recorded aid card in the handler
+    #[account(
+        mut,
+        seeds = [b"aid-card", beneficiary.key().as_ref()],
+        bump
+    )]
+    pub aid_card_mint: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Checked against the beneficiary's associated token address in the handler
+    #[account(mut)]
+    pub aid_card_token_account: Option<UncheckedAccount<'info>>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub token_2022_program: Option<Program<'info, Token2022>>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn revoke_beneficiary_verification_handler(
+    ctx: Context<RevokeBeneficiaryVerification>,
+    _beneficiary_authority: Pubkey,
+    _disaster_id: String,
+    params: RevokeBeneficiaryVerificationParams,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    let beneficiary = &mut ctx.accounts.beneficiary;
+    let disaster = &mut ctx.accounts.disaster;
+    let config = &mut ctx.accounts.config;
+    let admin_action = &mut ctx.accounts.admin_action;
+
+    require!(!params.reason.is_empty(), ErrorCode::InvalidInput);
+
+    require!(
+        params.reason.len() <= AdminAction::MAX_REASON_LEN,
+        ErrorCode::StringTooLong
+    );
+
+    require!(
+        params.reason.len() <= Beneficiary::MAX_ADMIN_NOTES_LEN,
+        ErrorCode::StringTooLong
+    );
+
+    if beneficiary.nft_mint.is_some() {
+        let (Some(aid_card_mint), Some(aid_card_token_account), Some(token_2022_program)) = (
+            ctx.accounts.aid_card_mint.as_ref(),
+            ctx.accounts.aid_card_token_account.as_ref(),
+            ctx.accounts.token_2022_program.as_ref(),
+        ) else {
+            return err!(ErrorCode::AidCardAccountsRequired);
+        };
+
+        freeze_aid_card(
+            &aid_card_mint.to_account_info(),
+            &aid_card_token_account.to_account_info(),
+            &token_2022_program.to_account_info(),
+            beneficiary,
+            ctx.bumps.aid_card_mint.ok_or(ErrorCode::InvalidBumpSeed)?,
+        )?;
+
+        emit!(AidCardFrozen {
+            beneficiary: beneficiary.key(),
+            mint: aid_card_mint.key(),
+            token_account: aid_card_token_account.key(),
+            admin: ctx.accounts.admin.key(),
+            timestamp: clock.unix_timestamp,
+        });
+
+        msg!("Aid card frozen: {}", aid_card_mint.key());
+    }
+
+    let approvals_revoked = beneficiary.verifier_approvals.len();
+
+    beneficiary.verification_status = VerificationStatus::Rejected;
+    beneficiary.verified_at = None;
+    beneficiary.verifier_approvals = Vec::new();
+    beneficiary.verifier_ngos = Vec::new();
+    beneficiary.admin_notes = Some(params.reason.clone());
+
+    disaster.verified_beneficiaries = disaster
+        .verified_beneficiaries
+        .checked_sub(1)
+        .ok_or(ErrorCode::ArithmeticUnderflow)?;
+    disaster.updated_at = clock.unix_timestamp;
+
+    config.total_verified_beneficiaries = config
+        .total_verified_beneficiaries
+        .checked_sub(1)
+        .ok_or(ErrorCode::ArithmeticUnderflow)?;
+    config.updated_at = clock.unix_timestamp;
+
+    admin_action.action_type = AdminActionType::RevokeBeneficiaryVerification;
+    admin_action.target = beneficiary.key();
+    admin_action.admin = ctx.accounts.admin.key();
+    admin_action.reason = params.reason;
+    admin_action.timestamp = clock.unix_timestamp;
+    admin_action.metadata = format!(
+        "Beneficiary: {} | Disaster: {} | Approvals revoked: {}",
+        beneficiary.name, beneficiary.disaster_id, approvals_revoked
+    );
+    admin_action.bump = ctx.bumps.admin_action;
+    admin_action.record_sequence(&mut config.admin_action_count)?;
+
+    emit!(BeneficiaryVerificationRevoked {
+        beneficiary: beneficiary.key(),
+        name: beneficiary.name.clone(),
+        disaster_id: beneficiary.disaster_id.clone(),
+        approvals_revoked: approvals_revoked as u8,
+        reason: admin_action.reason.clone(),
+        admin: ctx.accounts.admin.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("✗ Beneficiary verification REVOKED");
+    msg!("Name: {}", beneficiary.name);
+    msg!("Revoked by: {}", ctx.accounts.admin.key());
+    msg!("Pending claims on open distributions are now blocked");
+
+    Ok(())
+}
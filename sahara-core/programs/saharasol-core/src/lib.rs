@@ -5,10 +5,13 @@
 use anchor_lang::prelude::*;
 
 pub mod errors;
+pub mod events;
 pub mod instructions;
 pub mod state;
+pub mod utils;
 
 pub use errors::ErrorCode;
+pub use events::*;
 pub use instructions::*;
 pub use state::*;
 
@@ -26,73 +29,74 @@ pub mod saharasol_core {
         instructions::platform::handler(ctx, params)
     }
 
-    pub fn add_manager(
-        ctx: Context<AddManager>,
-        timestamp: i64,
-        manager: Pubkey,
-        reason: String,
-    ) -> Result<()> {
-        instructions::platform::add_manager_handler(ctx, timestamp, manager, reason)
+    pub fn add_manager(ctx: Context<AddManager>, manager: Pubkey, reason: String) -> Result<()> {
+        instructions::platform::add_manager_handler(ctx, manager, reason)
     }
 
     pub fn remove_manager(
         ctx: Context<RemoveManager>,
-        timestamp: i64,
         manager: Pubkey,
         reason: String,
     ) -> Result<()> {
-        instructions::platform::remove_manager_handler(ctx, timestamp, manager, reason)
+        instructions::platform::remove_manager_handler(ctx, manager, reason)
     }
 
     pub fn update_platform_config(
         ctx: Context<UpdatePlatformConfig>,
-        timestamp: i64,
         params: UpdatePlatformConfigWithAuditParams,
     ) -> Result<()> {
-        instructions::platform::update_platform_config_handler(ctx, timestamp, params)
+        instructions::platform::update_platform_config_handler(ctx, params)
     }
 
     pub fn add_allowed_token(
         ctx: Context<ManageAllowedTokens>,
-        timestamp: i64,
         token_mint: Pubkey,
         reason: String,
     ) -> Result<()> {
-        instructions::platform::add_allowed_token_handler(ctx, timestamp, token_mint, reason)
+        instructions::platform::add_allowed_token_handler(ctx, token_mint, reason)
     }
 
     pub fn remove_allowed_token(
         ctx: Context<ManageAllowedTokens>,
-        timestamp: i64,
         token_mint: Pubkey,
         reason: String,
     ) -> Result<()> {
-        instructions::platform::remove_allowed_token_handler(ctx, timestamp, token_mint, reason)
+        instructions::platform::remove_allowed_token_handler(ctx, token_mint, reason)
     }
 
     pub fn initialize_disaster(
         ctx: Context<InitializeDisaster>,
         params: InitializeDisasterParams,
-        timestamp: i64,
     ) -> Result<()> {
-        instructions::disaster::handler(ctx, params, timestamp)
+        instructions::disaster::handler(ctx, params)
     }
 
     pub fn update_disaster(
         ctx: Context<UpdateDisaster>,
         event_id: String,
-        timestamp: i64,
         params: UpdateDisasterParams,
     ) -> Result<()> {
-        instructions::disaster::update_disaster_handler(ctx, event_id, timestamp, params)
+        instructions::disaster::update_disaster_handler(ctx, event_id, params)
+    }
+
+    pub fn close_disaster(ctx: Context<CloseDisaster>, event_id: String) -> Result<()> {
+        instructions::disaster::close_disaster_handler(ctx, event_id)
+    }
+
+    pub fn force_close_disaster<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ForceCloseDisaster<'info>>,
+        event_id: String,
+        params: ForceCloseDisasterParams,
+    ) -> Result<()> {
+        instructions::disaster::force_close_disaster_handler(ctx, event_id, params)
     }
 
-    pub fn close_disaster(
-        ctx: Context<CloseDisaster>,
+    pub fn delegate_disaster_review(
+        ctx: Context<DelegateDisasterReview>,
         event_id: String,
-        timestamp: i64,
+        params: DelegateDisasterReviewParams,
     ) -> Result<()> {
-        instructions::disaster::close_disaster_handler(ctx, event_id, timestamp)
+        instructions::disaster::delegate_disaster_review_handler(ctx, event_id, params)
     }
 
     pub fn register_ngo(ctx: Context<RegisterNGO>, params: RegisterNGOParams) -> Result<()> {
@@ -113,28 +117,36 @@ pub mod saharasol_core {
         instructions::ngo::update_field_worker_status_handler(ctx, params)
     }
 
-    pub fn update_ngo(
-        ctx: Context<UpdateNGO>,
-        params: UpdateNGOParams,
-        timestamp: i64,
-    ) -> Result<()> {
-        instructions::ngo::update_ngo_handler(ctx, params, timestamp)
+    pub fn update_ngo(ctx: Context<UpdateNGO>, params: UpdateNGOParams) -> Result<()> {
+        instructions::ngo::update_ngo_handler(ctx, params)
     }
 
     pub fn update_field_worker(
         ctx: Context<UpdateFieldWorker>,
         params: UpdateFieldWorkerParams,
-        timestamp: i64,
     ) -> Result<()> {
-        instructions::ngo::update_field_worker_handler(ctx, params, timestamp)
+        instructions::ngo::update_field_worker_handler(ctx, params)
+    }
+
+    pub fn assign_field_worker_to_disaster(
+        ctx: Context<AssignFieldWorkerToDisaster>,
+        disaster_id: String,
+    ) -> Result<()> {
+        instructions::ngo::assign_field_worker_to_disaster_handler(ctx, disaster_id)
+    }
+
+    pub fn revoke_field_worker_assignment(
+        ctx: Context<RevokeFieldWorkerAssignment>,
+        disaster_id: String,
+    ) -> Result<()> {
+        instructions::ngo::revoke_field_worker_assignment_handler(ctx, disaster_id)
     }
 
     pub fn register_beneficiary<'info>(
         ctx: Context<'_, '_, 'info, 'info, RegisterBeneficiary<'info>>,
         params: RegisterBeneficiaryParams,
-        timestamp: i64,
     ) -> Result<()> {
-        instructions::beneficiary::handler(ctx, params, timestamp)
+        instructions::beneficiary::handler(ctx, params)
     }
 
     pub fn update_beneficiary(
@@ -151,13 +163,66 @@ pub mod saharasol_core {
         )
     }
 
+    pub fn change_beneficiary_phone(
+        ctx: Context<ChangeBeneficiaryPhone>,
+        beneficiary_authority: Pubkey,
+        disaster_id: String,
+        new_phone_number: String,
+    ) -> Result<()> {
+        instructions::beneficiary::change_beneficiary_phone_handler(
+            ctx,
+            beneficiary_authority,
+            disaster_id,
+            new_phone_number,
+        )
+    }
+
+    pub fn correct_beneficiary_national_id(
+        ctx: Context<CorrectBeneficiaryNationalId>,
+        beneficiary_authority: Pubkey,
+        disaster_id: String,
+        params: CorrectNationalIdParams,
+    ) -> Result<()> {
+        instructions::beneficiary::correct_beneficiary_national_id_handler(
+            ctx,
+            beneficiary_authority,
+            disaster_id,
+            params,
+        )
+    }
+
     pub fn verify_beneficiary(
         ctx: Context<VerifyBeneficiary>,
         beneficiary_authority: Pubkey,
         disaster_id: String,
-        timestamp: i64,
     ) -> Result<()> {
-        instructions::verification::handler(ctx, beneficiary_authority, disaster_id, timestamp)
+        instructions::verification::handler(ctx, beneficiary_authority, disaster_id)
+    }
+
+    pub fn revoke_beneficiary_verification(
+        ctx: Context<RevokeBeneficiaryVerification>,
+        beneficiary_authority: Pubkey,
+        disaster_id: String,
+        params: RevokeBeneficiaryVerificationParams,
+    ) -> Result<()> {
+        instructions::verification::revoke_beneficiary_verification_handler(
+            ctx,
+            beneficiary_authority,
+            disaster_id,
+            params,
+        )
+    }
+
+    pub fn expire_beneficiary_verification(
+        ctx: Context<ExpireBeneficiaryVerification>,
+        beneficiary_authority: Pubkey,
+        disaster_id: String,
+    ) -> Result<()> {
+        instructions::verification::expire_beneficiary_verification_handler(
+            ctx,
+            beneficiary_authority,
+            disaster_id,
+        )
     }
 
     pub fn flag_beneficiary(
@@ -192,10 +257,9 @@ pub mod saharasol_core {
         ctx: Context<CreateFundPool>,
         disaster_id: String,
         pool_id: String,
-        timestamp: i64,
         params: Box<CreateFundPoolParams>,
     ) -> Result<()> {
-        instructions::fund_pool::handler(ctx, disaster_id, pool_id, timestamp, params)
+        instructions::fund_pool::handler(ctx, disaster_id, pool_id, params)
     }
 
     pub fn donate_to_pool<'info>(
@@ -223,13 +287,8 @@ pub mod saharasol_core {
         instructions::fund_pool::update_pool_config_handler(ctx, disaster_id, pool_id, params)
     }
 
-    pub fn close_pool(
-        ctx: Context<ClosePool>,
-        disaster_id: String,
-        pool_id: String,
-        timestamp: i64,
-    ) -> Result<()> {
-        instructions::fund_pool::close_pool_handler(ctx, disaster_id, pool_id, timestamp)
+    pub fn close_pool(ctx: Context<ClosePool>, disaster_id: String, pool_id: String) -> Result<()> {
+        instructions::fund_pool::close_pool_handler(ctx, disaster_id, pool_id)
     }
 
     pub fn donate_direct(
@@ -251,13 +310,27 @@ pub mod saharasol_core {
         instructions::distribution::handler(ctx, disaster_id, pool_id, params)
     }
 
+    pub fn batch_distribute_from_pool<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BatchDistributeFromPool<'info>>,
+        disaster_id: String,
+        pool_id: String,
+    ) -> Result<()> {
+        instructions::distribution::batch_distribute_from_pool_handler(ctx, disaster_id, pool_id)
+    }
+
     pub fn claim_distribution(
         ctx: Context<ClaimDistribution>,
         disaster_id: String,
         pool_id: String,
-        timestamp: i64,
     ) -> Result<()> {
-        instructions::distribution::claim_distribution_handler(ctx, disaster_id, pool_id, timestamp)
+        instructions::distribution::claim_distribution_handler(ctx, disaster_id, pool_id)
+    }
+
+    pub fn batch_claim_distributions<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BatchClaimDistributions<'info>>,
+        disaster_id: String,
+    ) -> Result<()> {
+        instructions::distribution::batch_claim_distributions_handler(ctx, disaster_id)
     }
 
     pub fn reclaim_expired_distribution(
@@ -274,102 +347,225 @@ pub mod saharasol_core {
         )
     }
 
-    pub fn register_beneficiary_for_pool(
-        ctx: Context<RegisterBeneficiaryForPool>,
+    pub fn extend_claim_deadline(
+        ctx: Context<ExtendClaimDeadline>,
         disaster_id: String,
         pool_id: String,
-        params: RegisterBeneficiaryForPoolParams,
-        timestamp: i64,
+        beneficiary_authority: Pubkey,
+        params: ExtendClaimDeadlineParams,
     ) -> Result<()> {
-        instructions::pool_registration::register_beneficiary_for_pool_handler(
+        instructions::distribution::extend_claim_deadline_handler(
             ctx,
             disaster_id,
             pool_id,
+            beneficiary_authority,
             params,
-            timestamp,
         )
     }
 
-    pub fn lock_pool_registration(
-        ctx: Context<LockPoolRegistration>,
+    pub fn route_reclaimed_funds(
+        ctx: Context<RouteReclaimedFunds>,
         disaster_id: String,
         pool_id: String,
-        timestamp: i64,
+        params: RouteReclaimedFundsParams,
+    ) -> Result<()> {
+        instructions::distribution::route_reclaimed_funds_handler(ctx, disaster_id, pool_id, params)
+    }
+
+    pub fn claim_redistribution(
+        ctx: Context<ClaimRedistribution>,
+        disaster_id: String,
+        pool_id: String,
+    ) -> Result<()> {
+        instructions::distribution::claim_redistribution_handler(ctx, disaster_id, pool_id)
+    }
+
+    pub fn refund_donation(
+        ctx: Context<RefundDonation>,
+        disaster_id: String,
+        pool_id: String,
+        donor_salt: Option<[u8; 32]>,
     ) -> Result<()> {
-        instructions::pool_registration::lock_pool_registration_handler(
+        instructions::fund_pool::refund_donation_handler(ctx, disaster_id, pool_id, donor_salt)
+    }
+
+    pub fn register_beneficiary_for_pool<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RegisterBeneficiaryForPool<'info>>,
+        disaster_id: String,
+        pool_id: String,
+        params: RegisterBeneficiaryForPoolParams,
+    ) -> Result<()> {
+        instructions::pool_registration::register_beneficiary_for_pool_handler(
             ctx,
             disaster_id,
             pool_id,
-            timestamp,
+            params,
         )
     }
 
+    pub fn lock_pool_registration(
+        ctx: Context<LockPoolRegistration>,
+        disaster_id: String,
+        pool_id: String,
+    ) -> Result<()> {
+        instructions::pool_registration::lock_pool_registration_handler(ctx, disaster_id, pool_id)
+    }
+
     pub fn verify_ngo(
         ctx: Context<VerifyNGO>,
         ngo_authority: Pubkey,
         params: VerifyNGOParams,
-        action_id: u64,
     ) -> Result<()> {
-        instructions::admin::verify_ngo_handler(ctx, ngo_authority, params, action_id)
+        instructions::admin::verify_ngo_handler(ctx, ngo_authority, params)
     }
 
     pub fn revoke_ngo_verification(
         ctx: Context<RevokeVerification>,
         ngo_authority: Pubkey,
         params: RevokeVerificationParams,
-        action_id: u64,
     ) -> Result<()> {
-        instructions::admin::revoke_verification_handler(ctx, ngo_authority, params, action_id)
+        instructions::admin::revoke_verification_handler(ctx, ngo_authority, params)
     }
 
     pub fn update_ngo_status(
         ctx: Context<UpdateNGOStatus>,
         ngo_authority: Pubkey,
         params: UpdateNGOStatusParams,
-        action_id: u64,
     ) -> Result<()> {
-        instructions::admin::update_ngo_status_handler(ctx, ngo_authority, params, action_id)
+        instructions::admin::update_ngo_status_handler(ctx, ngo_authority, params)
     }
 
     pub fn blacklist_ngo(
         ctx: Context<BlacklistNGO>,
         ngo_authority: Pubkey,
         params: BlacklistNGOParams,
-        action_id: u64,
     ) -> Result<()> {
-        instructions::admin::blacklist_ngo_handler(ctx, ngo_authority, params, action_id)
+        instructions::admin::blacklist_ngo_handler(ctx, ngo_authority, params)
     }
 
     pub fn remove_blacklist(
         ctx: Context<RemoveBlacklist>,
         ngo_authority: Pubkey,
         params: RemoveBlacklistParams,
-        action_id: u64,
     ) -> Result<()> {
-        instructions::admin::remove_blacklist_handler(ctx, ngo_authority, params, action_id)
+        instructions::admin::remove_blacklist_handler(ctx, ngo_authority, params)
     }
 
     pub fn initiate_admin_transfer(
         ctx: Context<InitiateAdminTransfer>,
         params: InitiateAdminTransferParams,
-        action_id: u64,
     ) -> Result<()> {
-        instructions::admin::initiate_admin_transfer_handler(ctx, params, action_id)
+        instructions::admin::initiate_admin_transfer_handler(ctx, params)
     }
 
     pub fn accept_admin_transfer(
         ctx: Context<AcceptAdminTransfer>,
         params: AcceptAdminTransferParams,
-        action_id: u64,
     ) -> Result<()> {
-        instructions::admin::accept_admin_transfer_handler(ctx, params, action_id)
+        instructions::admin::accept_admin_transfer_handler(ctx, params)
     }
 
     pub fn cancel_admin_transfer(
         ctx: Context<CancelAdminTransfer>,
         params: CancelAdminTransferParams,
-        action_id: u64,
     ) -> Result<()> {
-        instructions::admin::cancel_admin_transfer_handler(ctx, params, action_id)
+        instructions::admin::cancel_admin_transfer_handler(ctx, params)
+    }
+
+    pub fn close_distribution(
+        ctx: Context<CloseDistribution>,
+        disaster_id: String,
+        pool_id: String,
+        beneficiary_authority: Pubkey,
+    ) -> Result<()> {
+        instructions::distribution::close_distribution_handler(
+            ctx,
+            disaster_id,
+            pool_id,
+            beneficiary_authority,
+        )
+    }
+
+    pub fn close_pool_registration(
+        ctx: Context<ClosePoolRegistration>,
+        disaster_id: String,
+        pool_id: String,
+        beneficiary_authority: Pubkey,
+    ) -> Result<()> {
+        instructions::pool_registration::close_pool_registration_handler(
+            ctx,
+            disaster_id,
+            pool_id,
+            beneficiary_authority,
+        )
+    }
+
+    pub fn close_phone_registry(
+        ctx: Context<ClosePhoneRegistry>,
+        disaster_id: String,
+        phone_number: String,
+    ) -> Result<()> {
+        instructions::beneficiary::close_phone_registry_handler(ctx, disaster_id, phone_number)
+    }
+
+    pub fn close_national_id_registry(
+        ctx: Context<CloseNationalIdRegistry>,
+        disaster_id: String,
+        national_id: String,
+    ) -> Result<()> {
+        instructions::beneficiary::close_national_id_registry_handler(ctx, disaster_id, national_id)
+    }
+
+    pub fn initialize_activity_archive(ctx: Context<InitializeActivityArchive>) -> Result<()> {
+        instructions::archive::initialize_activity_archive_handler(ctx)
+    }
+
+    pub fn archive_activity_log(ctx: Context<ArchiveActivityLog>) -> Result<()> {
+        instructions::archive::archive_activity_log_handler(ctx)
+    }
+
+    pub fn initialize_stand_in_oracle(
+        ctx: Context<InitializeStandInOracle>,
+        params: SetStandInOraclePriceParams,
+    ) -> Result<()> {
+        instructions::price_feed::initialize_stand_in_oracle_handler(ctx, params)
+    }
+
+    pub fn update_stand_in_oracle(
+        ctx: Context<UpdateStandInOracle>,
+        params: SetStandInOraclePriceParams,
+    ) -> Result<()> {
+        instructions::price_feed::update_stand_in_oracle_handler(ctx, params)
+    }
+
+    pub fn register_price_feed(
+        ctx: Context<RegisterPriceFeed>,
+        params: RegisterPriceFeedParams,
+    ) -> Result<()> {
+        instructions::price_feed::register_price_feed_handler(ctx, params)
+    }
+
+    pub fn update_price_feed(
+        ctx: Context<UpdatePriceFeed>,
+        params: UpdatePriceFeedParams,
+    ) -> Result<()> {
+        instructions::price_feed::update_price_feed_handler(ctx, params)
+    }
+
+    pub fn issue_aid_card(
+        ctx: Context<IssueAidCard>,
+        beneficiary_authority: Pubkey,
+        disaster_id: String,
+    ) -> Result<()> {
+        instructions::aid_card::issue_aid_card_handler(ctx, beneficiary_authority, disaster_id)
+    }
+
+    pub fn issue_receipt(
+        ctx: Context<IssueReceipt>,
+        disaster_id: String,
+        pool_id: String,
+        params: IssueReceiptParams,
+    ) -> Result<()> {
+        instructions::receipt::issue_receipt_handler(ctx, disaster_id, pool_id, params)
     }
 }
@@ -7,11 +7,15 @@ pub mod donation_record;
 pub mod duplicate_registry;
 pub mod enums;
 pub mod field_worker;
+pub mod field_worker_assignment;
 pub mod fund_pool;
 pub mod location;
 pub mod ngo;
 pub mod platform_config;
 pub mod pool_registration;
+pub mod price_feed;
+pub mod token_stats;
+pub mod vesting;
 
 pub use activity_log::*;
 pub use admin_action::*;
@@ -22,8 +26,12 @@ pub use donation_record::*;
 pub use duplicate_registry::*;
 pub use enums::*;
 pub use field_worker::*;
+pub use field_worker_assignment::*;
 pub use fund_pool::*;
 pub use location::*;
 pub use ngo::*;
 pub use platform_config::*;
 pub use pool_registration::*;
+pub use price_feed::*;
+pub use token_stats::*;
+pub use vesting::*;
@@ -1,3 +1,4 @@
+use crate::errors::ErrorCode;
 use anchor_lang::prelude::*;
 
 #[account]
@@ -9,6 +10,11 @@ pub struct ActivityLog {
     pub timestamp: i64,
     pub metadata: String,
     pub bump: u8,
+
+    pub stream: Pubkey,
+    pub sequence: u64,
+
+    pub rent_payer: Pubkey,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
@@ -48,10 +54,51 @@ pub enum ActivityType {
     AdminTransferInitiated,
     AdminTransferAccepted,
     AdminTransferCancelled,
+
+    ReclaimedFundsRedistributed,
+    ReclaimedFundsMovedToPool,
+    ReclaimedFundsRefundedToDonors,
+    DonationRefunded,
+
+    ClaimDeadlineExtended,
+
+    FieldWorkerAssigned,
+    FieldWorkerUnassigned,
+
+    BeneficiaryVerificationExpired,
+
+    AidCardIssued,
+    ReceiptIssued,
+
+    DistributionReclaimed,
 }
 
 impl ActivityLog {
     pub const MAX_METADATA_LEN: usize = 500;
 
-    pub const SPACE: usize = 8 + 1 + 32 + 32 + 1 + 8 + 8 + 4 + Self::MAX_METADATA_LEN + 1;
+    pub const SPACE: usize =
+        8 + 1 + 32 + 32 + 1 + 8 + 8 + 4 + Self::MAX_METADATA_LEN + 1 + 32 + 8 + 32;
+
+    pub fn record_sequence(&mut self, stream: Pubkey, activity_count: &mut u64) -> Result<()> {
+        self.stream = stream;
+        self.sequence = *activity_count;
+        *activity_count = activity_count
+            .checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(())
+    }
+}
+
+#[account]
+pub struct ActivityArchive {
+    pub actor: Pubkey,
+    pub root: [u8; 32],
+    pub archived_count: u64,
+    pub created_at: i64,
+    pub last_archived_at: i64,
+    pub bump: u8,
+}
+
+impl ActivityArchive {
+    pub const SPACE: usize = 8 + 32 + 32 + 8 + 8 + 8 + 1;
 }
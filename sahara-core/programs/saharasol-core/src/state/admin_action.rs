@@ -1,3 +1,4 @@
+use crate::errors::ErrorCode;
 use anchor_lang::prelude::*;
 
 #[account]
@@ -9,6 +10,8 @@ pub struct AdminAction {
     pub timestamp: i64,
     pub metadata: String,
     pub bump: u8,
+
+    pub sequence: u64,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
@@ -50,6 +53,17 @@ pub enum AdminActionType {
 
     AddManager,
     RemoveManager,
+
+    ForceCloseDisaster,
+
+    CorrectNationalId,
+
+    RevokeBeneficiaryVerification,
+
+    DelegateDisasterReview,
+
+    RegisterPriceFeed,
+    UpdatePriceFeed,
 }
 
 impl AdminAction {
@@ -57,5 +71,13 @@ impl AdminAction {
     pub const MAX_METADATA_LEN: usize = 1000;
 
     pub const SPACE: usize =
-        8 + 1 + 32 + 32 + 4 + Self::MAX_REASON_LEN + 8 + 4 + Self::MAX_METADATA_LEN + 1;
+        8 + 1 + 32 + 32 + 4 + Self::MAX_REASON_LEN + 8 + 4 + Self::MAX_METADATA_LEN + 1 + 8;
+
+    pub fn record_sequence(&mut self, admin_action_count: &mut u64) -> Result<()> {
+        self.sequence = *admin_action_count;
+        *admin_action_count = admin_action_count
+            .checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(())
+    }
 }
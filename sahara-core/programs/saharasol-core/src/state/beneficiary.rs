@@ -30,6 +30,9 @@ pub struct Beneficiary {
     pub flagged_at: Option<i64>,
     pub admin_notes: Option<String>,
     pub bump: u8,
+
+    pub verifier_ngos: Vec<Pubkey>,
+    pub activity_count: u64,
 }
 
 impl Beneficiary {
@@ -94,5 +97,8 @@ impl Beneficiary {
         + 1
         + 4
         + Self::MAX_ADMIN_NOTES_LEN
-        + 1;
+        + 1
+        + 4
+        + (Self::MAX_VERIFIER_APPROVALS * 32)
+        + 8;
 }
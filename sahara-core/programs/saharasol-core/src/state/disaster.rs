@@ -1,4 +1,4 @@
-use crate::state::{DisasterType, Location};
+use crate::state::{DisasterType, Location, PlatformConfig};
 use anchor_lang::prelude::*;
 
 #[account]
@@ -20,6 +20,12 @@ pub struct DisasterEvent {
     pub created_at: i64,
     pub updated_at: i64,
     pub bump: u8,
+    pub active_pool_count: u32,
+    pub verification_window_seconds: i64,
+    pub review_delegated: bool,
+    pub closed_at: Option<i64>,
+    pub activity_count: u64,
+    pub total_aid_distributed_usd: u64,
 }
 
 impl DisasterEvent {
@@ -50,5 +56,35 @@ impl DisasterEvent {
         + 8                                                                         // total_aid_distributed
         + 8                                                                         // created_at
         + 8                                                                         // updated_at
-        + 1; // bump
+        + 1                                                                         // bump
+        + 4                                                                         // active_pool_count
+        + 8                                                                         // verification_window_seconds
+        + 1                                                                         // review_delegated
+        + 1 + 8                                                                     // closed_at
+        + 8                                                                         // activity_count
+        + 8; // total_aid_distributed_usd
+
+    pub fn verification_deadline(
+        &self,
+        config: &PlatformConfig,
+        registered_at: i64,
+    ) -> Option<i64> {
+        let window = if self.verification_window_seconds > 0 {
+            self.verification_window_seconds
+        } else {
+            config.verification_window_seconds
+        };
+
+        if window > 0 {
+            registered_at.checked_add(window)
+        } else {
+            None
+        }
+    }
+
+    pub fn covers_location(&self, location: &Location) -> bool {
+        self.affected_areas
+            .iter()
+            .any(|area| location.is_within(area))
+    }
 }
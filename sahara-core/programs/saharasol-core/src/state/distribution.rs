@@ -20,10 +20,20 @@ pub struct Distribution {
     pub claim_deadline: Option<i64>,
     pub is_expired: bool,
     pub expired_at: Option<i64>,
+
+    pub redistribution_round_claimed: u8,
+    pub redistribution_received: u64,
+
+    pub deadline_extension: i64,
+
+    pub tranches_claimed: u8,
+
+    pub rent_payer: Pubkey,
 }
 
 impl Distribution {
     pub const MAX_NOTES_LEN: usize = 200;
+    pub const MAX_BATCH_SIZE: usize = 20;
 
     pub const SPACE: usize = 8
         + 32
@@ -48,5 +58,10 @@ impl Distribution {
         + 8
         + 1
         + 1
-        + 8;
+        + 8
+        + 1
+        + 8
+        + 8
+        + 1
+        + 32;
 }
@@ -1,5 +1,6 @@
-use crate::state::DonationType;
+use crate::state::{DonationType, NGO};
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
 
 #[account]
 pub struct DonationRecord {
@@ -20,6 +21,14 @@ pub struct DonationRecord {
     pub donor_email: Option<String>,
     pub receipt_sent: bool,
     pub bump: u8,
+    pub refunded_amount: u64,
+    pub is_refunded: bool,
+    pub receipt_number: Option<String>,
+    pub receipt_tax_id: Option<String>,
+    pub receipt_fiscal_year: Option<u16>,
+    pub receipt_issued_at: Option<i64>,
+    pub donor_commitment: Option<[u8; 32]>,
+    pub activity_count: u64,
 }
 
 impl DonationRecord {
@@ -28,6 +37,8 @@ impl DonationRecord {
     pub const MAX_MESSAGE_LEN: usize = 500;
     pub const MAX_DONOR_NAME_LEN: usize = 100;
     pub const MAX_DONOR_EMAIL_LEN: usize = 100;
+    pub const MAX_RECEIPT_NUMBER_LEN: usize = 50;
+    pub const DONOR_COMMITMENT_DOMAIN: &'static [u8] = b"sahara-donor";
 
     pub const SPACE: usize = 8
         + 32
@@ -54,5 +65,36 @@ impl DonationRecord {
         + 4
         + Self::MAX_DONOR_EMAIL_LEN
         + 1
-        + 1;
+        + 1
+        + 8
+        + 1
+        + 1
+        + 4
+        + Self::MAX_RECEIPT_NUMBER_LEN
+        + 1
+        + 4
+        + NGO::MAX_TAX_ID_LEN
+        + 1
+        + 2
+        + 1
+        + 8
+        + 1
+        + 32
+        + 8;
+
+    pub fn commit_donor(donor: &Pubkey, salt: &[u8; 32]) -> [u8; 32] {
+        hashv(&[Self::DONOR_COMMITMENT_DOMAIN, donor.as_ref(), salt]).to_bytes()
+    }
+
+    pub fn donor_seed(donor: &Pubkey, donor_commitment: Option<[u8; 32]>) -> [u8; 32] {
+        donor_commitment.unwrap_or_else(|| donor.to_bytes())
+    }
+
+    pub fn is_owned_by(&self, donor: &Pubkey, salt: Option<[u8; 32]>) -> bool {
+        match (self.donor_commitment, salt) {
+            (Some(commitment), Some(salt)) => commitment == Self::commit_donor(donor, &salt),
+            (None, None) => self.donor == *donor,
+            _ => false,
+        }
+    }
 }
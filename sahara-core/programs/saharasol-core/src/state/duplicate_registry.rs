@@ -7,6 +7,7 @@ pub struct PhoneRegistry {
     pub beneficiary: Pubkey,
     pub registered_at: i64,
     pub bump: u8,
+    pub rent_payer: Pubkey,
 }
 
 impl PhoneRegistry {
@@ -14,7 +15,7 @@ impl PhoneRegistry {
     pub const MAX_PHONE_LEN: usize = 20;
 
     pub const SPACE: usize =
-        8 + 4 + Self::MAX_DISASTER_ID_LEN + 4 + Self::MAX_PHONE_LEN + 32 + 8 + 1;
+        8 + 4 + Self::MAX_DISASTER_ID_LEN + 4 + Self::MAX_PHONE_LEN + 32 + 8 + 1 + 32;
 }
 
 #[account]
@@ -24,6 +25,7 @@ pub struct NationalIdRegistry {
     pub beneficiary: Pubkey,
     pub registered_at: i64,
     pub bump: u8,
+    pub rent_payer: Pubkey,
 }
 
 impl NationalIdRegistry {
@@ -31,5 +33,52 @@ impl NationalIdRegistry {
     pub const MAX_NATIONAL_ID_LEN: usize = 50;
 
     pub const SPACE: usize =
-        8 + 4 + Self::MAX_DISASTER_ID_LEN + 4 + Self::MAX_NATIONAL_ID_LEN + 32 + 8 + 1;
+        8 + 4 + Self::MAX_DISASTER_ID_LEN + 4 + Self::MAX_NATIONAL_ID_LEN + 32 + 8 + 1 + 32;
+}
+
+#[account]
+pub struct HouseholdRegistry {
+    pub disaster_id: String,
+    pub household_id: String,
+    pub members: Vec<Pubkey>,
+    pub pools: Vec<Pubkey>,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl HouseholdRegistry {
+    pub const MAX_DISASTER_ID_LEN: usize = 50;
+    pub const MAX_HOUSEHOLD_ID_LEN: usize = 50;
+    pub const MAX_MEMBERS: usize = 10;
+    pub const MAX_POOLS: usize = 20;
+
+    pub const SPACE: usize = 8
+        + 4
+        + Self::MAX_DISASTER_ID_LEN
+        + 4
+        + Self::MAX_HOUSEHOLD_ID_LEN
+        + 4
+        + (Self::MAX_MEMBERS * 32)
+        + 4
+        + (Self::MAX_POOLS * 32)
+        + 8
+        + 1;
+}
+
+#[account]
+pub struct LocationRegistry {
+    pub disaster_id: String,
+    pub geohash: String,
+    pub registration_count: u32,
+    pub created_at: i64,
+    pub last_registration_at: i64,
+    pub bump: u8,
+}
+
+impl LocationRegistry {
+    pub const MAX_DISASTER_ID_LEN: usize = 50;
+    pub const MAX_GEOHASH_LEN: usize = 12;
+
+    pub const SPACE: usize =
+        8 + 4 + Self::MAX_DISASTER_ID_LEN + 4 + Self::MAX_GEOHASH_LEN + 4 + 8 + 8 + 1;
 }
@@ -47,6 +47,7 @@ pub enum VerificationStatus {
     Verified,
     Rejected,
     Flagged,
+    Expired,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
@@ -62,3 +63,10 @@ pub enum DonationType {
     Pool,
     Anonymous,
 }
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub enum ReclaimDestination {
+    RedistributionRound,
+    AnotherPool,
+    DonorRefund,
+}
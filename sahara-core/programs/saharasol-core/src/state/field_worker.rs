@@ -1,3 +1,4 @@
+use crate::state::Location;
 use anchor_lang::prelude::*;
 
 #[account]
@@ -21,6 +22,7 @@ pub struct FieldWorker {
     pub registered_by: Pubkey,
     pub notes: String,
     pub bump: u8,
+    pub activity_count: u64,
 }
 
 impl FieldWorker {
@@ -62,5 +64,12 @@ impl FieldWorker {
         + 32
         + 4
         + Self::MAX_NOTES_LEN
-        + 1;
+        + 1
+        + 8;
+
+    pub fn covers_location(&self, location: &Location) -> bool {
+        self.assigned_districts
+            .iter()
+            .any(|district| location.is_within(district))
+    }
 }
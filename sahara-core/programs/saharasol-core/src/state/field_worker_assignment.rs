@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct FieldWorkerAssignment {
+    pub field_worker: Pubkey,
+    pub ngo: Pubkey,
+    pub disaster_id: String,
+    pub assigned_by: Pubkey,
+    pub assigned_at: i64,
+    pub bump: u8,
+}
+
+impl FieldWorkerAssignment {
+    pub const MAX_DISASTER_ID_LEN: usize = 50;
+
+    pub const SPACE: usize = 8 + 32 + 32 + 4 + Self::MAX_DISASTER_ID_LEN + 32 + 8 + 1;
+}
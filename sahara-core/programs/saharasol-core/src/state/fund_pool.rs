@@ -1,4 +1,4 @@
-use crate::state::DistributionType;
+use crate::state::{DistributionType, VestingTranche};
 use anchor_lang::prelude::*;
 
 #[account]
@@ -33,6 +33,22 @@ pub struct FundPool {
     pub expected_beneficiary_count: Option<u32>,
     pub registered_beneficiary_count: u32,
     pub bump: u8,
+
+    pub reclaimed_amount: u64,
+    pub expired_allocation_weight: u64,
+    pub redistribution_round: u8,
+    pub redistribution_amount: u64,
+    pub redistribution_claimed: u64,
+    pub redistribution_weight: u64,
+    pub donor_refund_total: u64,
+    pub donor_refunded: u64,
+    pub total_transferred_in: u64,
+    pub total_transferred_out: u64,
+    pub claim_window_seconds: i64,
+    pub vesting_schedule: Vec<VestingTranche>,
+    pub activity_count: u64,
+    pub total_deposited_usd: u64,
+    pub total_claimed_usd: u64,
 }
 
 impl FundPool {
@@ -41,6 +57,9 @@ impl FundPool {
     pub const MAX_NAME_LEN: usize = 100;
     pub const MAX_ELIGIBILITY_LEN: usize = 500;
     pub const MAX_DESCRIPTION_LEN: usize = 500;
+    pub const DEFAULT_CLAIM_WINDOW_SECONDS: i64 = 90 * 24 * 60 * 60;
+    pub const MAX_VESTING_TRANCHES: usize = 12;
+    pub const MAX_CASCADE_CLOSE: usize = 20;
 
     pub const SPACE: usize = 8
         + 4
@@ -83,5 +102,33 @@ impl FundPool {
         + 1
         + 1
         + 4
-        + 1;
+        + 1
+        + 8
+        + 8
+        + 1
+        + 8
+        + 8
+        + 8
+        + 8
+        + 8
+        + 8
+        + 8
+        + 8
+        + 4
+        + (Self::MAX_VESTING_TRANCHES * VestingTranche::SPACE)
+        + 8
+        + 8
+        + 8;
+
+    pub fn last_unlock_offset(&self) -> i64 {
+        if self.distribution_percentage_locked == 0 {
+            return 0;
+        }
+
+        self.vesting_schedule
+            .last()
+            .map(|tranche| tranche.unlock_offset)
+            .or(self.time_lock_duration)
+            .unwrap_or(0)
+    }
 }
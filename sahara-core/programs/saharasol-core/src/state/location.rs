@@ -15,4 +15,46 @@ impl Location {
     pub const MAX_REGION_LEN: usize = 100; // State/Province/District
     pub const MAX_CITY_LEN: usize = 100; // City/Municipality
     pub const MAX_AREA_LEN: usize = 200; // Specific area/neighborhood
+    pub const GEOHASH_PRECISION: usize = 7; // ~150m x 150m cell
+
+    const GEOHASH_ALPHABET: &'static [u8; 32] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+
+    pub fn is_within(&self, area: &str) -> bool {
+        self.region.eq_ignore_ascii_case(area) || self.city.eq_ignore_ascii_case(area)
+    }
+
+    pub fn geohash(&self, precision: usize) -> String {
+        let mut lat_range = (-90.0_f64, 90.0_f64);
+        let mut lon_range = (-180.0_f64, 180.0_f64);
+        let mut hash = String::with_capacity(precision);
+        let mut is_lon = true;
+        let mut bit = 0;
+        let mut index = 0usize;
+
+        while hash.len() < precision {
+            let (range, value) = if is_lon {
+                (&mut lon_range, self.longitude)
+            } else {
+                (&mut lat_range, self.latitude)
+            };
+            let mid = (range.0 + range.1) / 2.0;
+            index <<= 1;
+            if value >= mid {
+                index |= 1;
+                range.0 = mid;
+            } else {
+                range.1 = mid;
+            }
+            is_lon = !is_lon;
+
+            bit += 1;
+            if bit == 5 {
+                hash.push(Self::GEOHASH_ALPHABET[index] as char);
+                bit = 0;
+                index = 0;
+            }
+        }
+
+        hash
+    }
 }
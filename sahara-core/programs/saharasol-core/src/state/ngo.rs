@@ -33,6 +33,7 @@ pub struct NGO {
     pub blacklisted_at: Option<i64>,
     pub blacklisted_by: Option<Pubkey>,
     pub bump: u8,
+    pub activity_count: u64,
 }
 
 impl NGO {
@@ -106,5 +107,6 @@ impl NGO {
         + 8
         + 1
         + 32
-        + 1;
+        + 1
+        + 8;
 }
@@ -39,6 +39,23 @@ pub struct PlatformConfig {
     pub admin_transfer_initiated_at: Option<i64>,
     pub admin_transfer_timeout: i64,
     pub bump: u8,
+
+    pub min_claim_window_seconds: i64,
+    pub max_claim_window_seconds: i64,
+
+    pub location_density_threshold: u32,
+
+    pub exclude_registering_verifier: bool,
+    pub min_verifier_ngos: u8,
+
+    pub verification_window_seconds: i64,
+
+    pub activity_archival_enabled: bool,
+
+    pub total_donations_usd: u64,
+    pub total_aid_distributed_usd: u64,
+
+    pub admin_action_count: u64,
 }
 
 impl PlatformConfig {
@@ -47,6 +64,9 @@ impl PlatformConfig {
     pub const MAX_EMERGENCY_CONTACTS: usize = 5;
     pub const MAX_PLATFORM_NAME_LEN: usize = 50;
     pub const MAX_VERSION_LEN: usize = 20;
+    pub const DEFAULT_MIN_CLAIM_WINDOW_SECONDS: i64 = 7 * 24 * 60 * 60;
+    pub const DEFAULT_MAX_CLAIM_WINDOW_SECONDS: i64 = 365 * 24 * 60 * 60;
+    pub const DEFAULT_VERIFICATION_WINDOW_SECONDS: i64 = 30 * 24 * 60 * 60;
 
     pub const SPACE: usize = 8
         + 32
@@ -93,7 +113,17 @@ impl PlatformConfig {
         + 1
         + 8
         + 8
-        + 1;
+        + 1
+        + 8
+        + 8
+        + 4
+        + 1
+        + 1
+        + 8
+        + 1
+        + 8
+        + 8
+        + 8;
 
     pub fn is_admin_or_manager(&self, pubkey: &Pubkey) -> bool {
         self.admin == *pubkey || self.managers.contains(pubkey)
@@ -8,8 +8,9 @@ pub struct PoolRegistration {
     pub registered_at: i64,
     pub is_distributed: bool,
     pub bump: u8,
+    pub rent_payer: Pubkey,
 }
 
 impl PoolRegistration {
-    pub const SPACE: usize = 8 + 32 + 32 + 8 + 8 + 1 + 1;
+    pub const SPACE: usize = 8 + 32 + 32 + 8 + 8 + 1 + 1 + 32;
 }
@@ -0,0 +1,69 @@
+use crate::errors::ErrorCode;
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct PriceFeed {
+    pub token_mint: Pubkey,
+    pub oracle: Pubkey,
+    pub token_decimals: u8,
+    pub max_staleness_seconds: i64,
+    pub is_active: bool,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl PriceFeed {
+    pub const USD_DECIMALS: u8 = 6;
+    pub const MAX_TOKEN_DECIMALS: u8 = 18;
+
+    pub const SPACE: usize = 8 + 32 + 32 + 1 + 8 + 1 + 8 + 8 + 1;
+
+    pub fn usd_value(&self, oracle: &StandInOracle, amount: u64, now: i64) -> Result<u64> {
+        require!(self.is_active, ErrorCode::PriceFeedInactive);
+        require!(oracle.price > 0, ErrorCode::InvalidOraclePrice);
+
+        let age = now
+            .checked_sub(oracle.publish_time)
+            .ok_or(ErrorCode::ArithmeticUnderflow)?;
+        require!(
+            age >= 0 && age <= self.max_staleness_seconds,
+            ErrorCode::StalePriceFeed
+        );
+
+        let scale = oracle.exponent as i64 + Self::USD_DECIMALS as i64 - self.token_decimals as i64;
+        let factor = 10u128
+            .checked_pow(scale.unsigned_abs() as u32)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        let value = (amount as u128)
+            .checked_mul(oracle.price as u128)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        let value = if scale >= 0 {
+            value
+                .checked_mul(factor)
+                .ok_or(ErrorCode::ArithmeticOverflow)?
+        } else {
+            value.checked_div(factor).ok_or(ErrorCode::DivisionByZero)?
+        };
+
+        u64::try_from(value).map_err(|_| error!(ErrorCode::ArithmeticOverflow))
+    }
+}
+
+#[account]
+pub struct StandInOracle {
+    pub token_mint: Pubkey,
+    pub authority: Pubkey,
+    pub price: i64,
+    pub exponent: i32,
+    pub publish_time: i64,
+    pub bump: u8,
+}
+
+impl StandInOracle {
+    pub const MIN_EXPONENT: i32 = -18;
+    pub const MAX_EXPONENT: i32 = 0;
+
+    pub const SPACE: usize = 8 + 32 + 32 + 8 + 4 + 8 + 1;
+}
@@ -0,0 +1,92 @@
+use crate::errors::ErrorCode;
+use crate::state::DisasterEvent;
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct TokenStats {
+    pub token_mint: Pubkey,
+    pub disaster_id: Option<String>,
+    pub total_donations: u64,
+    pub total_donations_usd: u64,
+    pub total_fees_collected: u64,
+    pub total_aid_distributed: u64,
+    pub total_aid_distributed_usd: u64,
+    pub donation_count: u64,
+    pub distribution_count: u64,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl TokenStats {
+    pub const SPACE: usize =
+        8 + 32 + 1 + 4 + DisasterEvent::MAX_EVENT_ID_LEN + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 1;
+
+    pub fn initialize_if_needed(
+        &mut self,
+        token_mint: Pubkey,
+        disaster_id: Option<String>,
+        bump: u8,
+        now: i64,
+    ) {
+        if self.created_at != 0 {
+            return;
+        }
+
+        self.token_mint = token_mint;
+        self.disaster_id = disaster_id;
+        self.created_at = now;
+        self.updated_at = now;
+        self.bump = bump;
+    }
+
+    pub fn record_donation(
+        &mut self,
+        net_amount: u64,
+        net_amount_usd: u64,
+        platform_fee: u64,
+        now: i64,
+    ) -> Result<()> {
+        self.total_donations = self
+            .total_donations
+            .checked_add(net_amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        self.total_donations_usd = self
+            .total_donations_usd
+            .checked_add(net_amount_usd)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        self.total_fees_collected = self
+            .total_fees_collected
+            .checked_add(platform_fee)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        self.donation_count = self
+            .donation_count
+            .checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        self.updated_at = now;
+        Ok(())
+    }
+
+    pub fn record_aid_distributed(
+        &mut self,
+        amount: u64,
+        amount_usd: u64,
+        distributions: u64,
+        now: i64,
+    ) -> Result<()> {
+        self.total_aid_distributed = self
+            .total_aid_distributed
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        self.total_aid_distributed_usd = self
+            .total_aid_distributed_usd
+            .checked_add(amount_usd)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        self.distribution_count = self
+            .distribution_count
+            .checked_add(distributions)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        self.updated_at = now;
+        Ok(())
+    }
+}
@@ -0,0 +1,11 @@
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct VestingTranche {
+    pub percentage: u8,
+    pub unlock_offset: i64,
+}
+
+impl VestingTranche {
+    pub const SPACE: usize = 1 + 8;
+}
@@ -7,7 +7,6 @@ import {
   derivePlatformConfigPDA,
   createTokenMint,
   airdropSOL,
-  getCurrentTimestamp,
 } from "./helpers/test-utils";
 import { createMockPlatformParams } from "./helpers/mock-data";
 import { expectError } from "./helpers/assertions";
@@ -121,11 +120,10 @@ describe("01 - Platform Configuration", () => {
   describe("update_platform_config", () => {
     describe("platform_fee_percentage", () => {
       it("should update platform fee percentage", async () => {
-        const timestamp = getCurrentTimestamp();
         const newFee = 250; // 2.5%
 
         await program.methods
-          .updatePlatformConfig(new anchor.BN(timestamp), {
+          .updatePlatformConfig({
             configParams: {
               platformFeePercentage: newFee,
               platformFeeRecipient: null,
@@ -136,6 +134,13 @@ describe("01 - Platform Configuration", () => {
               usdcMint: null,
               isPaused: null,
               solUsdOracle: null,
+              minClaimWindowSeconds: null,
+              maxClaimWindowSeconds: null,
+              locationDensityThreshold: null,
+              excludeRegisteringVerifier: null,
+              minVerifierNgos: null,
+              verificationWindowSeconds: null,
+              activityArchivalEnabled: null,
             },
             reason: "Adjusting platform fee",
             metadata: "{}",
@@ -151,11 +156,9 @@ describe("01 - Platform Configuration", () => {
       });
 
       it("should fail with fee > 1000 bps (10%)", async () => {
-        const timestamp = getCurrentTimestamp();
-
         await expectError(
           program.methods
-            .updatePlatformConfig(new anchor.BN(timestamp), {
+            .updatePlatformConfig({
               configParams: {
                 platformFeePercentage: 1001,
                 platformFeeRecipient: null,
@@ -166,6 +169,13 @@ describe("01 - Platform Configuration", () => {
                 usdcMint: null,
                 isPaused: null,
                 solUsdOracle: null,
+                minClaimWindowSeconds: null,
+                maxClaimWindowSeconds: null,
+                locationDensityThreshold: null,
+                excludeRegisteringVerifier: null,
+                minVerifierNgos: null,
+                verificationWindowSeconds: null,
+                activityArchivalEnabled: null,
               },
               reason: "Invalid fee test",
               metadata: "{}",
@@ -180,10 +190,8 @@ describe("01 - Platform Configuration", () => {
       });
 
       it("should allow fee of 0 (no fee)", async () => {
-        const timestamp = getCurrentTimestamp();
-
         await program.methods
-          .updatePlatformConfig(new anchor.BN(timestamp), {
+          .updatePlatformConfig({
             configParams: {
               platformFeePercentage: 0,
               platformFeeRecipient: null,
@@ -194,6 +202,13 @@ describe("01 - Platform Configuration", () => {
               usdcMint: null,
               isPaused: null,
               solUsdOracle: null,
+              minClaimWindowSeconds: null,
+              maxClaimWindowSeconds: null,
+              locationDensityThreshold: null,
+              excludeRegisteringVerifier: null,
+              minVerifierNgos: null,
+              verificationWindowSeconds: null,
+              activityArchivalEnabled: null,
             },
             reason: "Setting zero fee",
             metadata: "{}",
@@ -210,10 +225,8 @@ describe("01 - Platform Configuration", () => {
       });
 
       it("should allow max fee of 1000 bps (10%)", async () => {
-        const timestamp = getCurrentTimestamp();
-
         await program.methods
-          .updatePlatformConfig(new anchor.BN(timestamp), {
+          .updatePlatformConfig({
             configParams: {
               platformFeePercentage: 1000,
               platformFeeRecipient: null,
@@ -224,6 +237,13 @@ describe("01 - Platform Configuration", () => {
               usdcMint: null,
               isPaused: null,
               solUsdOracle: null,
+              minClaimWindowSeconds: null,
+              maxClaimWindowSeconds: null,
+              locationDensityThreshold: null,
+              excludeRegisteringVerifier: null,
+              minVerifierNgos: null,
+              verificationWindowSeconds: null,
+              activityArchivalEnabled: null,
             },
             reason: "Setting max fee",
             metadata: "{}",
@@ -241,11 +261,10 @@ describe("01 - Platform Configuration", () => {
 
     describe("platform_fee_recipient", () => {
       it("should update platform fee recipient", async () => {
-        const timestamp = getCurrentTimestamp();
         const newRecipient = Keypair.generate().publicKey;
 
         await program.methods
-          .updatePlatformConfig(new anchor.BN(timestamp), {
+          .updatePlatformConfig({
             configParams: {
               platformFeePercentage: null,
               platformFeeRecipient: newRecipient,
@@ -256,6 +275,13 @@ describe("01 - Platform Configuration", () => {
               usdcMint: null,
               isPaused: null,
               solUsdOracle: null,
+              minClaimWindowSeconds: null,
+              maxClaimWindowSeconds: null,
+              locationDensityThreshold: null,
+              excludeRegisteringVerifier: null,
+              minVerifierNgos: null,
+              verificationWindowSeconds: null,
+              activityArchivalEnabled: null,
             },
             reason: "Changing fee recipient",
             metadata: "{}",
@@ -274,10 +300,8 @@ describe("01 - Platform Configuration", () => {
 
     describe("verification_threshold", () => {
       it("should update verification threshold", async () => {
-        const timestamp = getCurrentTimestamp();
-
         await program.methods
-          .updatePlatformConfig(new anchor.BN(timestamp), {
+          .updatePlatformConfig({
             configParams: {
               platformFeePercentage: null,
               platformFeeRecipient: null,
@@ -288,6 +312,13 @@ describe("01 - Platform Configuration", () => {
               usdcMint: null,
               isPaused: null,
               solUsdOracle: null,
+              minClaimWindowSeconds: null,
+              maxClaimWindowSeconds: null,
+              locationDensityThreshold: null,
+              excludeRegisteringVerifier: null,
+              minVerifierNgos: null,
+              verificationWindowSeconds: null,
+              activityArchivalEnabled: null,
             },
             reason: "Lowering threshold",
             metadata: "{}",
@@ -303,11 +334,9 @@ describe("01 - Platform Configuration", () => {
       });
 
       it("should fail with threshold of 0", async () => {
-        const timestamp = getCurrentTimestamp();
-
         await expectError(
           program.methods
-            .updatePlatformConfig(new anchor.BN(timestamp), {
+            .updatePlatformConfig({
               configParams: {
                 platformFeePercentage: null,
                 platformFeeRecipient: null,
@@ -318,6 +347,13 @@ describe("01 - Platform Configuration", () => {
                 usdcMint: null,
                 isPaused: null,
                 solUsdOracle: null,
+                minClaimWindowSeconds: null,
+                maxClaimWindowSeconds: null,
+                locationDensityThreshold: null,
+                excludeRegisteringVerifier: null,
+                minVerifierNgos: null,
+                verificationWindowSeconds: null,
+                activityArchivalEnabled: null,
               },
               reason: "Invalid threshold",
               metadata: "{}",
@@ -332,12 +368,11 @@ describe("01 - Platform Configuration", () => {
       });
 
       it("should fail with threshold > max_verifiers", async () => {
-        const timestamp = getCurrentTimestamp();
         const config = await program.account.platformConfig.fetch(platformConfigPDA);
 
         await expectError(
           program.methods
-            .updatePlatformConfig(new anchor.BN(timestamp), {
+            .updatePlatformConfig({
               configParams: {
                 platformFeePercentage: null,
                 platformFeeRecipient: null,
@@ -348,6 +383,13 @@ describe("01 - Platform Configuration", () => {
                 usdcMint: null,
                 isPaused: null,
                 solUsdOracle: null,
+                minClaimWindowSeconds: null,
+                maxClaimWindowSeconds: null,
+                locationDensityThreshold: null,
+                excludeRegisteringVerifier: null,
+                minVerifierNgos: null,
+                verificationWindowSeconds: null,
+                activityArchivalEnabled: null,
               },
               reason: "Invalid threshold",
               metadata: "{}",
@@ -360,14 +402,48 @@ describe("01 - Platform Configuration", () => {
           "VerificationThresholdNotMet"
         );
       });
+
+      it("should fail with min verifier NGOs > verification threshold", async () => {
+        const config = await program.account.platformConfig.fetch(platformConfigPDA);
+
+        await expectError(
+          program.methods
+            .updatePlatformConfig({
+              configParams: {
+                platformFeePercentage: null,
+                platformFeeRecipient: null,
+                verificationThreshold: null,
+                maxVerifiers: null,
+                minDonationAmount: null,
+                maxDonationAmount: null,
+                usdcMint: null,
+                isPaused: null,
+                solUsdOracle: null,
+                minClaimWindowSeconds: null,
+                maxClaimWindowSeconds: null,
+                locationDensityThreshold: null,
+                excludeRegisteringVerifier: null,
+                minVerifierNgos: config.verificationThreshold + 1,
+                verificationWindowSeconds: null,
+                activityArchivalEnabled: null,
+              },
+              reason: "Invalid verifier NGO threshold",
+              metadata: "{}",
+            })
+            .accountsPartial({
+              admin: admin.publicKey,
+              config: platformConfigPDA,
+            })
+            .rpc(),
+          "InvalidVerifierNgoThreshold"
+        );
+      });
     });
 
     describe("max_verifiers", () => {
       it("should update max verifiers", async () => {
-        const timestamp = getCurrentTimestamp();
-
         await program.methods
-          .updatePlatformConfig(new anchor.BN(timestamp), {
+          .updatePlatformConfig({
             configParams: {
               platformFeePercentage: null,
               platformFeeRecipient: null,
@@ -378,6 +454,13 @@ describe("01 - Platform Configuration", () => {
               usdcMint: null,
               isPaused: null,
               solUsdOracle: null,
+              minClaimWindowSeconds: null,
+              maxClaimWindowSeconds: null,
+              locationDensityThreshold: null,
+              excludeRegisteringVerifier: null,
+              minVerifierNgos: null,
+              verificationWindowSeconds: null,
+              activityArchivalEnabled: null,
             },
             reason: "Increasing max verifiers",
             metadata: "{}",
@@ -393,12 +476,11 @@ describe("01 - Platform Configuration", () => {
       });
 
       it("should fail with max_verifiers < current threshold", async () => {
-        const timestamp = getCurrentTimestamp();
         const config = await program.account.platformConfig.fetch(platformConfigPDA);
 
         await expectError(
           program.methods
-            .updatePlatformConfig(new anchor.BN(timestamp), {
+            .updatePlatformConfig({
               configParams: {
                 platformFeePercentage: null,
                 platformFeeRecipient: null,
@@ -409,6 +491,13 @@ describe("01 - Platform Configuration", () => {
                 usdcMint: null,
                 isPaused: null,
                 solUsdOracle: null,
+                minClaimWindowSeconds: null,
+                maxClaimWindowSeconds: null,
+                locationDensityThreshold: null,
+                excludeRegisteringVerifier: null,
+                minVerifierNgos: null,
+                verificationWindowSeconds: null,
+                activityArchivalEnabled: null,
               },
               reason: "Invalid max verifiers",
               metadata: "{}",
@@ -425,11 +514,10 @@ describe("01 - Platform Configuration", () => {
 
     describe("min_donation_amount", () => {
       it("should update min donation amount", async () => {
-        const timestamp = getCurrentTimestamp();
         const newMin = new anchor.BN(500000); // 0.5 USDC
 
         await program.methods
-          .updatePlatformConfig(new anchor.BN(timestamp), {
+          .updatePlatformConfig({
             configParams: {
               platformFeePercentage: null,
               platformFeeRecipient: null,
@@ -440,6 +528,13 @@ describe("01 - Platform Configuration", () => {
               usdcMint: null,
               isPaused: null,
               solUsdOracle: null,
+              minClaimWindowSeconds: null,
+              maxClaimWindowSeconds: null,
+              locationDensityThreshold: null,
+              excludeRegisteringVerifier: null,
+              minVerifierNgos: null,
+              verificationWindowSeconds: null,
+              activityArchivalEnabled: null,
             },
             reason: "Lowering min donation",
             metadata: "{}",
@@ -455,11 +550,9 @@ describe("01 - Platform Configuration", () => {
       });
 
       it("should fail with min = 0", async () => {
-        const timestamp = getCurrentTimestamp();
-
         await expectError(
           program.methods
-            .updatePlatformConfig(new anchor.BN(timestamp), {
+            .updatePlatformConfig({
               configParams: {
                 platformFeePercentage: null,
                 platformFeeRecipient: null,
@@ -470,6 +563,13 @@ describe("01 - Platform Configuration", () => {
                 usdcMint: null,
                 isPaused: null,
                 solUsdOracle: null,
+                minClaimWindowSeconds: null,
+                maxClaimWindowSeconds: null,
+                locationDensityThreshold: null,
+                excludeRegisteringVerifier: null,
+                minVerifierNgos: null,
+                verificationWindowSeconds: null,
+                activityArchivalEnabled: null,
               },
               reason: "Invalid min",
               metadata: "{}",
@@ -484,12 +584,11 @@ describe("01 - Platform Configuration", () => {
       });
 
       it("should fail with min >= max", async () => {
-        const timestamp = getCurrentTimestamp();
         const config = await program.account.platformConfig.fetch(platformConfigPDA);
 
         await expectError(
           program.methods
-            .updatePlatformConfig(new anchor.BN(timestamp), {
+            .updatePlatformConfig({
               configParams: {
                 platformFeePercentage: null,
                 platformFeeRecipient: null,
@@ -500,6 +599,13 @@ describe("01 - Platform Configuration", () => {
                 usdcMint: null,
                 isPaused: null,
                 solUsdOracle: null,
+                minClaimWindowSeconds: null,
+                maxClaimWindowSeconds: null,
+                locationDensityThreshold: null,
+                excludeRegisteringVerifier: null,
+                minVerifierNgos: null,
+                verificationWindowSeconds: null,
+                activityArchivalEnabled: null,
               },
               reason: "Invalid min",
               metadata: "{}",
@@ -516,11 +622,10 @@ describe("01 - Platform Configuration", () => {
 
     describe("max_donation_amount", () => {
       it("should update max donation amount", async () => {
-        const timestamp = getCurrentTimestamp();
         const newMax = new anchor.BN(5000000000000); // 5M USDC
 
         await program.methods
-          .updatePlatformConfig(new anchor.BN(timestamp), {
+          .updatePlatformConfig({
             configParams: {
               platformFeePercentage: null,
               platformFeeRecipient: null,
@@ -531,6 +636,13 @@ describe("01 - Platform Configuration", () => {
               usdcMint: null,
               isPaused: null,
               solUsdOracle: null,
+              minClaimWindowSeconds: null,
+              maxClaimWindowSeconds: null,
+              locationDensityThreshold: null,
+              excludeRegisteringVerifier: null,
+              minVerifierNgos: null,
+              verificationWindowSeconds: null,
+              activityArchivalEnabled: null,
             },
             reason: "Increasing max donation",
             metadata: "{}",
@@ -546,12 +658,11 @@ describe("01 - Platform Configuration", () => {
       });
 
       it("should fail with max <= min", async () => {
-        const timestamp = getCurrentTimestamp();
         const config = await program.account.platformConfig.fetch(platformConfigPDA);
 
         await expectError(
           program.methods
-            .updatePlatformConfig(new anchor.BN(timestamp), {
+            .updatePlatformConfig({
               configParams: {
                 platformFeePercentage: null,
                 platformFeeRecipient: null,
@@ -562,6 +673,13 @@ describe("01 - Platform Configuration", () => {
                 usdcMint: null,
                 isPaused: null,
                 solUsdOracle: null,
+                minClaimWindowSeconds: null,
+                maxClaimWindowSeconds: null,
+                locationDensityThreshold: null,
+                excludeRegisteringVerifier: null,
+                minVerifierNgos: null,
+                verificationWindowSeconds: null,
+                activityArchivalEnabled: null,
               },
               reason: "Invalid max",
               metadata: "{}",
@@ -578,11 +696,10 @@ describe("01 - Platform Configuration", () => {
 
     describe("usdc_mint", () => {
       it("should update USDC mint", async () => {
-        const timestamp = getCurrentTimestamp();
         const newMint = await createTokenMint(provider.connection, admin.payer, 6);
 
         await program.methods
-          .updatePlatformConfig(new anchor.BN(timestamp), {
+          .updatePlatformConfig({
             configParams: {
               platformFeePercentage: null,
               platformFeeRecipient: null,
@@ -593,6 +710,13 @@ describe("01 - Platform Configuration", () => {
               usdcMint: newMint,
               isPaused: null,
               solUsdOracle: null,
+              minClaimWindowSeconds: null,
+              maxClaimWindowSeconds: null,
+              locationDensityThreshold: null,
+              excludeRegisteringVerifier: null,
+              minVerifierNgos: null,
+              verificationWindowSeconds: null,
+              activityArchivalEnabled: null,
             },
             reason: "Updating USDC mint",
             metadata: "{}",
@@ -610,10 +734,8 @@ describe("01 - Platform Configuration", () => {
 
     describe("is_paused", () => {
       it("should pause platform", async () => {
-        const timestamp = getCurrentTimestamp();
-
         await program.methods
-          .updatePlatformConfig(new anchor.BN(timestamp), {
+          .updatePlatformConfig({
             configParams: {
               platformFeePercentage: null,
               platformFeeRecipient: null,
@@ -624,6 +746,13 @@ describe("01 - Platform Configuration", () => {
               usdcMint: null,
               isPaused: true,
               solUsdOracle: null,
+              minClaimWindowSeconds: null,
+              maxClaimWindowSeconds: null,
+              locationDensityThreshold: null,
+              excludeRegisteringVerifier: null,
+              minVerifierNgos: null,
+              verificationWindowSeconds: null,
+              activityArchivalEnabled: null,
             },
             reason: "Pausing for maintenance",
             metadata: "{}",
@@ -639,10 +768,8 @@ describe("01 - Platform Configuration", () => {
       });
 
       it("should unpause platform", async () => {
-        const timestamp = getCurrentTimestamp();
-
         await program.methods
-          .updatePlatformConfig(new anchor.BN(timestamp), {
+          .updatePlatformConfig({
             configParams: {
               platformFeePercentage: null,
               platformFeeRecipient: null,
@@ -653,6 +780,13 @@ describe("01 - Platform Configuration", () => {
               usdcMint: null,
               isPaused: false,
               solUsdOracle: null,
+              minClaimWindowSeconds: null,
+              maxClaimWindowSeconds: null,
+              locationDensityThreshold: null,
+              excludeRegisteringVerifier: null,
+              minVerifierNgos: null,
+              verificationWindowSeconds: null,
+              activityArchivalEnabled: null,
             },
             reason: "Resuming operations",
             metadata: "{}",
@@ -670,11 +804,10 @@ describe("01 - Platform Configuration", () => {
 
     describe("sol_usd_oracle", () => {
       it("should set SOL/USD oracle", async () => {
-        const timestamp = getCurrentTimestamp();
         const oracleAddress = Keypair.generate().publicKey;
 
         await program.methods
-          .updatePlatformConfig(new anchor.BN(timestamp), {
+          .updatePlatformConfig({
             configParams: {
               platformFeePercentage: null,
               platformFeeRecipient: null,
@@ -685,6 +818,13 @@ describe("01 - Platform Configuration", () => {
               usdcMint: null,
               isPaused: null,
               solUsdOracle: oracleAddress,
+              minClaimWindowSeconds: null,
+              maxClaimWindowSeconds: null,
+              locationDensityThreshold: null,
+              excludeRegisteringVerifier: null,
+              minVerifierNgos: null,
+              verificationWindowSeconds: null,
+              activityArchivalEnabled: null,
             },
             reason: "Setting oracle",
             metadata: "{}",
@@ -705,11 +845,9 @@ describe("01 - Platform Configuration", () => {
         const nonAdmin = Keypair.generate();
         await airdropSOL(provider.connection, nonAdmin.publicKey);
 
-        const timestamp = getCurrentTimestamp();
-
         await expectError(
           program.methods
-            .updatePlatformConfig(new anchor.BN(timestamp), {
+            .updatePlatformConfig({
               configParams: {
                 platformFeePercentage: 500,
                 platformFeeRecipient: null,
@@ -720,6 +858,13 @@ describe("01 - Platform Configuration", () => {
                 usdcMint: null,
                 isPaused: null,
                 solUsdOracle: null,
+                minClaimWindowSeconds: null,
+                maxClaimWindowSeconds: null,
+                locationDensityThreshold: null,
+                excludeRegisteringVerifier: null,
+                minVerifierNgos: null,
+                verificationWindowSeconds: null,
+                activityArchivalEnabled: null,
               },
               reason: "Unauthorized",
               metadata: "{}",
@@ -737,10 +882,8 @@ describe("01 - Platform Configuration", () => {
 
     describe("audit trail", () => {
       it("should create admin action record on update", async () => {
-        const timestamp = getCurrentTimestamp();
-
         const tx = await program.methods
-          .updatePlatformConfig(new anchor.BN(timestamp), {
+          .updatePlatformConfig({
             configParams: {
               platformFeePercentage: 150,
               platformFeeRecipient: null,
@@ -751,6 +894,13 @@ describe("01 - Platform Configuration", () => {
               usdcMint: null,
               isPaused: null,
               solUsdOracle: null,
+              minClaimWindowSeconds: null,
+              maxClaimWindowSeconds: null,
+              locationDensityThreshold: null,
+              excludeRegisteringVerifier: null,
+              minVerifierNgos: null,
+              verificationWindowSeconds: null,
+              activityArchivalEnabled: null,
             },
             reason: "Audit test update",
             metadata: '{"test": true}',
@@ -766,12 +916,11 @@ describe("01 - Platform Configuration", () => {
       });
 
       it("should fail with reason too long", async () => {
-        const timestamp = getCurrentTimestamp();
         const longReason = "x".repeat(300); // MAX_REASON_LEN is likely 256
 
         await expectError(
           program.methods
-            .updatePlatformConfig(new anchor.BN(timestamp), {
+            .updatePlatformConfig({
               configParams: {
                 platformFeePercentage: 100,
                 platformFeeRecipient: null,
@@ -782,6 +931,13 @@ describe("01 - Platform Configuration", () => {
                 usdcMint: null,
                 isPaused: null,
                 solUsdOracle: null,
+                minClaimWindowSeconds: null,
+                maxClaimWindowSeconds: null,
+                locationDensityThreshold: null,
+                excludeRegisteringVerifier: null,
+                minVerifierNgos: null,
+                verificationWindowSeconds: null,
+                activityArchivalEnabled: null,
               },
               reason: longReason,
               metadata: "{}",
@@ -798,10 +954,8 @@ describe("01 - Platform Configuration", () => {
 
     describe("multiple updates", () => {
       it("should update multiple fields at once", async () => {
-        const timestamp = getCurrentTimestamp();
-
         await program.methods
-          .updatePlatformConfig(new anchor.BN(timestamp), {
+          .updatePlatformConfig({
             configParams: {
               platformFeePercentage: 200,
               platformFeeRecipient: null,
@@ -812,6 +966,13 @@ describe("01 - Platform Configuration", () => {
               usdcMint: null,
               isPaused: null,
               solUsdOracle: null,
+              minClaimWindowSeconds: null,
+              maxClaimWindowSeconds: null,
+              locationDensityThreshold: null,
+              excludeRegisteringVerifier: null,
+              minVerifierNgos: null,
+              verificationWindowSeconds: null,
+              activityArchivalEnabled: null,
             },
             reason: "Bulk update",
             metadata: "{}",
@@ -844,13 +1005,11 @@ describe("01 - Platform Configuration", () => {
 
     describe("add_allowed_token", () => {
       it("should add a new allowed token", async () => {
-        const timestamp = getCurrentTimestamp();
         const configBefore = await program.account.platformConfig.fetch(platformConfigPDA);
         const tokenCountBefore = configBefore.allowedTokens.length;
 
         await program.methods
           .addAllowedToken(
-            new anchor.BN(timestamp),
             testToken1,
             "Adding test token for donations"
           )
@@ -870,12 +1029,9 @@ describe("01 - Platform Configuration", () => {
       });
 
       it("should fail to add duplicate token", async () => {
-        const timestamp = getCurrentTimestamp();
-
         await expectError(
           program.methods
             .addAllowedToken(
-              new anchor.BN(timestamp),
               testToken1,
               "Duplicate token"
             )
@@ -889,11 +1045,8 @@ describe("01 - Platform Configuration", () => {
       });
 
       it("should add multiple different tokens", async () => {
-        const timestamp = getCurrentTimestamp();
-
         await program.methods
           .addAllowedToken(
-            new anchor.BN(timestamp),
             testToken2,
             "Adding second test token"
           )
@@ -914,12 +1067,10 @@ describe("01 - Platform Configuration", () => {
         const nonAdmin = Keypair.generate();
         await airdropSOL(provider.connection, nonAdmin.publicKey);
         const newToken = await createTokenMint(provider.connection, admin.payer, 6);
-        const timestamp = getCurrentTimestamp();
 
         await expectError(
           program.methods
             .addAllowedToken(
-              new anchor.BN(timestamp),
               newToken,
               "Unauthorized add"
             )
@@ -935,11 +1086,9 @@ describe("01 - Platform Configuration", () => {
 
       it("should create admin action record when adding token", async () => {
         const newToken = await createTokenMint(provider.connection, admin.payer, 6);
-        const timestamp = getCurrentTimestamp();
 
         const tx = await program.methods
           .addAllowedToken(
-            new anchor.BN(timestamp),
             newToken,
             "Adding token with audit"
           )
@@ -955,13 +1104,11 @@ describe("01 - Platform Configuration", () => {
 
     describe("remove_allowed_token", () => {
       it("should remove an allowed token", async () => {
-        const timestamp = getCurrentTimestamp();
         const configBefore = await program.account.platformConfig.fetch(platformConfigPDA);
         const tokenCountBefore = configBefore.allowedTokens.length;
 
         await program.methods
           .removeAllowedToken(
-            new anchor.BN(timestamp),
             testToken1,
             "Removing test token"
           )
@@ -981,13 +1128,11 @@ describe("01 - Platform Configuration", () => {
       });
 
       it("should fail to remove non-existent token", async () => {
-        const timestamp = getCurrentTimestamp();
         const nonExistentToken = Keypair.generate().publicKey;
 
         await expectError(
           program.methods
             .removeAllowedToken(
-              new anchor.BN(timestamp),
               nonExistentToken,
               "Removing non-existent"
             )
@@ -1001,13 +1146,11 @@ describe("01 - Platform Configuration", () => {
       });
 
       it("should fail to remove primary USDC token", async () => {
-        const timestamp = getCurrentTimestamp();
         const config = await program.account.platformConfig.fetch(platformConfigPDA);
 
         await expectError(
           program.methods
             .removeAllowedToken(
-              new anchor.BN(timestamp),
               config.usdcMint,
               "Trying to remove USDC"
             )
@@ -1023,12 +1166,10 @@ describe("01 - Platform Configuration", () => {
       it("should fail when non-admin tries to remove token", async () => {
         const nonAdmin = Keypair.generate();
         await airdropSOL(provider.connection, nonAdmin.publicKey);
-        const timestamp = getCurrentTimestamp();
 
         await expectError(
           program.methods
             .removeAllowedToken(
-              new anchor.BN(timestamp),
               testToken2,
               "Unauthorized remove"
             )
@@ -1043,11 +1184,8 @@ describe("01 - Platform Configuration", () => {
       });
 
       it("should create admin action record when removing token", async () => {
-        const timestamp = getCurrentTimestamp();
-
         const tx = await program.methods
           .removeAllowedToken(
-            new anchor.BN(timestamp),
             testToken2,
             "Removing token with audit"
           )
@@ -1095,12 +1233,9 @@ describe("01 - Platform Configuration", () => {
 
     describe("initiate_admin_transfer", () => {
       it("should initiate admin transfer", async () => {
-        const actionId = getCurrentTimestamp();
-
         await program.methods
           .initiateAdminTransfer(
-            { newAdmin: newAdmin.publicKey, reason: "Transferring to new admin" },
-            new anchor.BN(actionId)
+            { newAdmin: newAdmin.publicKey, reason: "Transferring to new admin" }
           )
           .accountsPartial({
             admin: admin.publicKey,
@@ -1114,14 +1249,12 @@ describe("01 - Platform Configuration", () => {
       });
 
       it("should fail to initiate transfer when one is already pending", async () => {
-        const actionId = getCurrentTimestamp();
         const anotherAdmin = Keypair.generate();
 
         await expectError(
           program.methods
             .initiateAdminTransfer(
-              { newAdmin: anotherAdmin.publicKey, reason: "Another transfer" },
-              new anchor.BN(actionId)
+              { newAdmin: anotherAdmin.publicKey, reason: "Another transfer" }
             )
             .accountsPartial({
               admin: admin.publicKey,
@@ -1135,13 +1268,11 @@ describe("01 - Platform Configuration", () => {
       it("should fail when non-admin tries to initiate transfer", async () => {
         const nonAdmin = Keypair.generate();
         await airdropSOL(provider.connection, nonAdmin.publicKey);
-        const actionId = getCurrentTimestamp();
 
         await expectError(
           program.methods
             .initiateAdminTransfer(
-              { newAdmin: newAdmin.publicKey, reason: "Unauthorized" },
-              new anchor.BN(actionId)
+              { newAdmin: newAdmin.publicKey, reason: "Unauthorized" }
             )
             .accountsPartial({
               admin: nonAdmin.publicKey,
@@ -1156,12 +1287,9 @@ describe("01 - Platform Configuration", () => {
 
     describe("cancel_admin_transfer", () => {
       it("should cancel pending admin transfer", async () => {
-        const actionId = getCurrentTimestamp();
-
         await program.methods
           .cancelAdminTransfer(
-            { reason: "Changed mind" },
-            new anchor.BN(actionId)
+            { reason: "Changed mind" }
           )
           .accountsPartial({
             admin: admin.publicKey,
@@ -1175,13 +1303,10 @@ describe("01 - Platform Configuration", () => {
       });
 
       it("should fail to cancel when no transfer is pending", async () => {
-        const actionId = getCurrentTimestamp();
-
         await expectError(
           program.methods
             .cancelAdminTransfer(
-              { reason: "No transfer" },
-              new anchor.BN(actionId)
+              { reason: "No transfer" }
             )
             .accountsPartial({
               admin: admin.publicKey,
@@ -1196,11 +1321,9 @@ describe("01 - Platform Configuration", () => {
     describe("accept_admin_transfer", () => {
       before(async () => {
         // Initiate a new transfer for accept tests
-        const actionId = getCurrentTimestamp();
         await program.methods
           .initiateAdminTransfer(
-            { newAdmin: newAdmin.publicKey, reason: "For accept test" },
-            new anchor.BN(actionId)
+            { newAdmin: newAdmin.publicKey, reason: "For accept test" }
           )
           .accountsPartial({
             admin: admin.publicKey,
@@ -1212,13 +1335,11 @@ describe("01 - Platform Configuration", () => {
       it("should fail when wrong person tries to accept", async () => {
         const wrongPerson = Keypair.generate();
         await airdropSOL(provider.connection, wrongPerson.publicKey);
-        const actionId = getCurrentTimestamp();
 
         await expectError(
           program.methods
             .acceptAdminTransfer(
-              { reason: "Wrong person" },
-              new anchor.BN(actionId)
+              { reason: "Wrong person" }
             )
             .accountsPartial({
               newAdmin: wrongPerson.publicKey,
@@ -1231,12 +1352,9 @@ describe("01 - Platform Configuration", () => {
       });
 
       it("should accept admin transfer", async () => {
-        const actionId = getCurrentTimestamp();
-
         await program.methods
           .acceptAdminTransfer(
-            { reason: "Accepting transfer" },
-            new anchor.BN(actionId)
+            { reason: "Accepting transfer" }
           )
           .accountsPartial({
             newAdmin: newAdmin.publicKey,
@@ -1252,13 +1370,10 @@ describe("01 - Platform Configuration", () => {
       });
 
       it("should fail to accept when no transfer is pending", async () => {
-        const actionId = getCurrentTimestamp();
-
         await expectError(
           program.methods
             .acceptAdminTransfer(
-              { reason: "No transfer" },
-              new anchor.BN(actionId)
+              { reason: "No transfer" }
             )
             .accountsPartial({
               newAdmin: newAdmin.publicKey,
@@ -1274,11 +1389,9 @@ describe("01 - Platform Configuration", () => {
     describe("transfer_back_to_original", () => {
       it("should transfer admin back to original", async () => {
         // newAdmin is now the admin, transfer back
-        const actionId1 = getCurrentTimestamp();
         await program.methods
           .initiateAdminTransfer(
-            { newAdmin: admin.publicKey, reason: "Transfer back" },
-            new anchor.BN(actionId1)
+            { newAdmin: admin.publicKey, reason: "Transfer back" }
           )
           .accountsPartial({
             admin: newAdmin.publicKey,
@@ -1287,11 +1400,9 @@ describe("01 - Platform Configuration", () => {
           .signers([newAdmin])
           .rpc();
 
-        const actionId2 = getCurrentTimestamp();
         await program.methods
           .acceptAdminTransfer(
-            { reason: "Accepting back" },
-            new anchor.BN(actionId2)
+            { reason: "Accepting back" }
           )
           .accountsPartial({
             newAdmin: admin.publicKey,
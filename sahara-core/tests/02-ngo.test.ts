@@ -8,7 +8,6 @@ import {
   deriveNGOPDA,
   deriveFieldWorkerPDA,
   airdropSOL,
-  getCurrentTimestamp,
 } from "./helpers/test-utils";
 import { createMockNGOParams, createMockFieldWorkerParams } from "./helpers/mock-data";
 import { expectError } from "./helpers/assertions";
@@ -109,8 +108,6 @@ describe("02 - NGO Management", () => {
 
   describe("update_ngo", () => {
     it("should update NGO profile", async () => {
-      const timestamp = getCurrentTimestamp();
-
       await program.methods
         .updateNgo(
           {
@@ -127,8 +124,7 @@ describe("02 - NGO Management", () => {
             contactPersonRole: null,
             bankAccountInfo: null,
             taxId: null,
-          },
-          new anchor.BN(timestamp)
+          }
         )
         .accountsPartial({
           authority: ngoAuthority.publicKey,
@@ -146,7 +142,6 @@ describe("02 - NGO Management", () => {
     it("should fail when non-authority tries to update", async () => {
       const nonAuthority = Keypair.generate();
       await airdropSOL(provider.connection, nonAuthority.publicKey);
-      const timestamp = getCurrentTimestamp();
 
       await expectError(
         program.methods
@@ -165,8 +160,7 @@ describe("02 - NGO Management", () => {
               contactPersonRole: null,
               bankAccountInfo: null,
               taxId: null,
-            },
-            new anchor.BN(timestamp)
+            }
           )
           .accountsPartial({
             authority: nonAuthority.publicKey,
@@ -180,8 +174,6 @@ describe("02 - NGO Management", () => {
     });
 
     it("should update multiple fields at once", async () => {
-      const timestamp = getCurrentTimestamp();
-
       await program.methods
         .updateNgo(
           {
@@ -198,8 +190,7 @@ describe("02 - NGO Management", () => {
             contactPersonRole: "CEO",
             bankAccountInfo: null,
             taxId: null,
-          },
-          new anchor.BN(timestamp)
+          }
         )
         .accountsPartial({
           authority: ngoAuthority.publicKey,
@@ -220,10 +211,8 @@ describe("02 - NGO Management", () => {
 
   describe("admin_verify_ngo", () => {
     it("should verify NGO as admin", async () => {
-      const actionId = getCurrentTimestamp();
-
       await program.methods
-        .verifyNgo(ngoAuthority.publicKey, { reason: "Documents verified" }, new anchor.BN(actionId))
+        .verifyNgo(ngoAuthority.publicKey, { reason: "Documents verified" })
         .accountsPartial({
           admin: admin.publicKey,
           ngo: ngoPDA,
@@ -253,11 +242,10 @@ describe("02 - NGO Management", () => {
         .rpc();
 
       const [newNgoPDA] = deriveNGOPDA(newNgoAuthority.publicKey, program.programId);
-      const actionId = getCurrentTimestamp();
 
       await expectError(
         program.methods
-          .verifyNgo(newNgoAuthority.publicKey, { reason: "Unauthorized" }, new anchor.BN(actionId))
+          .verifyNgo(newNgoAuthority.publicKey, { reason: "Unauthorized" })
           .accountsPartial({
             admin: nonAdmin.publicKey,
             ngo: newNgoPDA,
@@ -272,10 +260,8 @@ describe("02 - NGO Management", () => {
 
   describe("admin_revoke_verification", () => {
     it("should revoke NGO verification", async () => {
-      const actionId = getCurrentTimestamp();
-
       await program.methods
-        .revokeNgoVerification(ngoAuthority.publicKey, { reason: "Re-verification needed" }, new anchor.BN(actionId))
+        .revokeNgoVerification(ngoAuthority.publicKey, { reason: "Re-verification needed" })
         .accountsPartial({
           admin: admin.publicKey,
           ngo: ngoPDA,
@@ -290,10 +276,8 @@ describe("02 - NGO Management", () => {
     });
 
     it("should re-verify NGO after revocation", async () => {
-      const actionId = getCurrentTimestamp();
-
       await program.methods
-        .verifyNgo(ngoAuthority.publicKey, { reason: "Re-verified" }, new anchor.BN(actionId))
+        .verifyNgo(ngoAuthority.publicKey, { reason: "Re-verified" })
         .accountsPartial({
           admin: admin.publicKey,
           ngo: ngoPDA,
@@ -308,13 +292,10 @@ describe("02 - NGO Management", () => {
 
   describe("admin_update_ngo_status", () => {
     it("should deactivate NGO", async () => {
-      const actionId = getCurrentTimestamp();
-
       await program.methods
         .updateNgoStatus(
           ngoAuthority.publicKey,
-          { isActive: false, reason: "Temporary suspension" },
-          new anchor.BN(actionId)
+          { isActive: false, reason: "Temporary suspension" }
         )
         .accountsPartial({
           admin: admin.publicKey,
@@ -328,13 +309,10 @@ describe("02 - NGO Management", () => {
     });
 
     it("should reactivate NGO", async () => {
-      const actionId = getCurrentTimestamp();
-
       await program.methods
         .updateNgoStatus(
           ngoAuthority.publicKey,
-          { isActive: true, reason: "Suspension lifted" },
-          new anchor.BN(actionId)
+          { isActive: true, reason: "Suspension lifted" }
         )
         .accountsPartial({
           admin: admin.publicKey,
@@ -368,13 +346,10 @@ describe("02 - NGO Management", () => {
     });
 
     it("should blacklist NGO", async () => {
-      const actionId = getCurrentTimestamp();
-
       await program.methods
         .blacklistNgo(
           blacklistNgoAuthority.publicKey,
-          { reason: "Fraudulent activity detected" },
-          new anchor.BN(actionId)
+          { reason: "Fraudulent activity detected" }
         )
         .accountsPartial({
           admin: admin.publicKey,
@@ -392,11 +367,9 @@ describe("02 - NGO Management", () => {
     });
 
     it("should fail to verify blacklisted NGO", async () => {
-      const actionId = getCurrentTimestamp();
-
       await expectError(
         program.methods
-          .verifyNgo(blacklistNgoAuthority.publicKey, { reason: "Try verify" }, new anchor.BN(actionId))
+          .verifyNgo(blacklistNgoAuthority.publicKey, { reason: "Try verify" })
           .accountsPartial({
             admin: admin.publicKey,
             ngo: blacklistNgoPDA,
@@ -408,13 +381,10 @@ describe("02 - NGO Management", () => {
     });
 
     it("should remove blacklist", async () => {
-      const actionId = getCurrentTimestamp();
-
       await program.methods
         .removeBlacklist(
           blacklistNgoAuthority.publicKey,
-          { reason: "Investigation cleared" },
-          new anchor.BN(actionId)
+          { reason: "Investigation cleared" }
         )
         .accountsPartial({
           admin: admin.publicKey,
@@ -481,12 +451,10 @@ describe("02 - NGO Management", () => {
 
     it("should fail to register field worker for inactive NGO", async () => {
       // First deactivate the NGO
-      const actionId1 = getCurrentTimestamp();
       await program.methods
         .updateNgoStatus(
           ngoAuthority.publicKey,
-          { isActive: false, reason: "Testing inactive" },
-          new anchor.BN(actionId1)
+          { isActive: false, reason: "Testing inactive" }
         )
         .accountsPartial({
           admin: admin.publicKey,
@@ -515,12 +483,10 @@ describe("02 - NGO Management", () => {
       );
 
       // Reactivate NGO
-      const actionId2 = getCurrentTimestamp();
       await program.methods
         .updateNgoStatus(
           ngoAuthority.publicKey,
-          { isActive: true, reason: "Reactivating" },
-          new anchor.BN(actionId2)
+          { isActive: true, reason: "Reactivating" }
         )
         .accountsPartial({
           admin: admin.publicKey,
@@ -664,8 +630,6 @@ describe("02 - NGO Management", () => {
     });
 
     it("should update field worker profile", async () => {
-      const timestamp = getCurrentTimestamp();
-
       await program.methods
         .updateFieldWorker(
           {
@@ -674,8 +638,7 @@ describe("02 - NGO Management", () => {
             phoneNumber: null,
             organization: null,
             notes: "Profile updated",
-          },
-          new anchor.BN(timestamp)
+          }
         )
         .accountsPartial({
           fieldWorker: fieldWorkerPDA,
@@ -7,8 +7,8 @@ import {
   derivePlatformConfigPDA,
   deriveDisasterPDA,
   deriveNGOPDA,
+  deriveActivityLogPDA,
   airdropSOL,
-  getCurrentTimestamp,
 } from "./helpers/test-utils";
 import { createMockDisasterParams, createMockNGOParams } from "./helpers/mock-data";
 import { expectError } from "./helpers/assertions";
@@ -41,9 +41,8 @@ describe("03 - Disaster Management", () => {
       .signers([verifiedNgoAuthority])
       .rpc();
 
-    const actionId = getCurrentTimestamp();
     await program.methods
-      .verifyNgo(verifiedNgoAuthority.publicKey, { reason: "Verified for testing" }, new anchor.BN(actionId))
+      .verifyNgo(verifiedNgoAuthority.publicKey, { reason: "Verified for testing" })
       .accountsPartial({
         admin: admin.publicKey,
         ngo: verifiedNgoPDA,
@@ -55,11 +54,10 @@ describe("03 - Disaster Management", () => {
   describe("initialize_disaster", () => {
     it("should initialize disaster as admin", async () => {
       const params = createMockDisasterParams({ eventId: "ADMIN-DISASTER-001" });
-      const timestamp = getCurrentTimestamp();
       const [disasterPDA] = deriveDisasterPDA(params.eventId, program.programId);
 
       await program.methods
-        .initializeDisaster(params, new anchor.BN(timestamp))
+        .initializeDisaster(params)
         .accountsPartial({
           authority: admin.publicKey,
           config: platformConfigPDA,
@@ -89,11 +87,10 @@ describe("03 - Disaster Management", () => {
 
     it("should initialize disaster as verified NGO", async () => {
       const params = createMockDisasterParams({ eventId: "NGO-DISASTER-001" });
-      const timestamp = getCurrentTimestamp();
       const [disasterPDA] = deriveDisasterPDA(params.eventId, program.programId);
 
       await program.methods
-        .initializeDisaster(params, new anchor.BN(timestamp))
+        .initializeDisaster(params)
         .accountsPartial({
           authority: verifiedNgoAuthority.publicKey,
           config: platformConfigPDA,
@@ -128,11 +125,10 @@ describe("03 - Disaster Management", () => {
         .rpc();
 
       const params = createMockDisasterParams({ eventId: "UNVERIFIED-DISASTER-001" });
-      const timestamp = getCurrentTimestamp();
 
       await expectError(
         program.methods
-          .initializeDisaster(params, new anchor.BN(timestamp))
+          .initializeDisaster(params)
           .accountsPartial({
             authority: unverifiedNgoAuthority.publicKey,
             config: platformConfigPDA,
@@ -155,11 +151,10 @@ describe("03 - Disaster Management", () => {
       await airdropSOL(provider.connection, randomUser.publicKey);
 
       const params = createMockDisasterParams({ eventId: "RANDOM-DISASTER-001" });
-      const timestamp = getCurrentTimestamp();
 
       await expectError(
         program.methods
-          .initializeDisaster(params, new anchor.BN(timestamp))
+          .initializeDisaster(params)
           .accountsPartial({
             authority: randomUser.publicKey,
             config: platformConfigPDA,
@@ -172,11 +167,10 @@ describe("03 - Disaster Management", () => {
 
     it("should fail with duplicate event_id", async () => {
       const params = createMockDisasterParams({ eventId: "ADMIN-DISASTER-001" });
-      const timestamp = getCurrentTimestamp();
 
       await expectError(
         program.methods
-          .initializeDisaster(params, new anchor.BN(timestamp))
+          .initializeDisaster(params)
           .accountsPartial({
             authority: admin.publicKey,
             config: platformConfigPDA,
@@ -188,11 +182,10 @@ describe("03 - Disaster Management", () => {
 
     it("should fail with invalid severity (0)", async () => {
       const params = createMockDisasterParams({ eventId: "INVALID-SEV-0", severity: 0 });
-      const timestamp = getCurrentTimestamp();
 
       await expectError(
         program.methods
-          .initializeDisaster(params, new anchor.BN(timestamp))
+          .initializeDisaster(params)
           .accountsPartial({
             authority: admin.publicKey,
             config: platformConfigPDA,
@@ -204,11 +197,10 @@ describe("03 - Disaster Management", () => {
 
     it("should fail with invalid severity (11)", async () => {
       const params = createMockDisasterParams({ eventId: "INVALID-SEV-11", severity: 11 });
-      const timestamp = getCurrentTimestamp();
 
       await expectError(
         program.methods
-          .initializeDisaster(params, new anchor.BN(timestamp))
+          .initializeDisaster(params)
           .accountsPartial({
             authority: admin.publicKey,
             config: platformConfigPDA,
@@ -220,11 +212,10 @@ describe("03 - Disaster Management", () => {
 
     it("should allow severity 1 (minimum)", async () => {
       const params = createMockDisasterParams({ eventId: "SEV-MIN-1", severity: 1 });
-      const timestamp = getCurrentTimestamp();
       const [disasterPDA] = deriveDisasterPDA(params.eventId, program.programId);
 
       await program.methods
-        .initializeDisaster(params, new anchor.BN(timestamp))
+        .initializeDisaster(params)
         .accountsPartial({
           authority: admin.publicKey,
           config: platformConfigPDA,
@@ -237,11 +228,10 @@ describe("03 - Disaster Management", () => {
 
     it("should allow severity 10 (maximum)", async () => {
       const params = createMockDisasterParams({ eventId: "SEV-MAX-10", severity: 10 });
-      const timestamp = getCurrentTimestamp();
       const [disasterPDA] = deriveDisasterPDA(params.eventId, program.programId);
 
       await program.methods
-        .initializeDisaster(params, new anchor.BN(timestamp))
+        .initializeDisaster(params)
         .accountsPartial({
           authority: admin.publicKey,
           config: platformConfigPDA,
@@ -257,11 +247,10 @@ describe("03 - Disaster Management", () => {
         eventId: "INVALID-LAT",
         location: { country: "US", region: "Test", city: "Test", area: "", latitude: -91, longitude: 0 },
       });
-      const timestamp = getCurrentTimestamp();
 
       await expectError(
         program.methods
-          .initializeDisaster(params, new anchor.BN(timestamp))
+          .initializeDisaster(params)
           .accountsPartial({
             authority: admin.publicKey,
             config: platformConfigPDA,
@@ -276,11 +265,10 @@ describe("03 - Disaster Management", () => {
         eventId: "INVALID-LON",
         location: { country: "US", region: "Test", city: "Test", area: "", latitude: 0, longitude: 181 },
       });
-      const timestamp = getCurrentTimestamp();
 
       await expectError(
         program.methods
-          .initializeDisaster(params, new anchor.BN(timestamp))
+          .initializeDisaster(params)
           .accountsPartial({
             authority: admin.publicKey,
             config: platformConfigPDA,
@@ -295,11 +283,10 @@ describe("03 - Disaster Management", () => {
         eventId: "EMPTY-COUNTRY",
         location: { country: "", region: "Test", city: "Test", area: "", latitude: 0, longitude: 0 },
       });
-      const timestamp = getCurrentTimestamp();
 
       await expectError(
         program.methods
-          .initializeDisaster(params, new anchor.BN(timestamp))
+          .initializeDisaster(params)
           .accountsPartial({
             authority: admin.publicKey,
             config: platformConfigPDA,
@@ -314,11 +301,10 @@ describe("03 - Disaster Management", () => {
         eventId: "EMPTY-REGION",
         location: { country: "US", region: "", city: "Test", area: "", latitude: 0, longitude: 0 },
       });
-      const timestamp = getCurrentTimestamp();
 
       await expectError(
         program.methods
-          .initializeDisaster(params, new anchor.BN(timestamp))
+          .initializeDisaster(params)
           .accountsPartial({
             authority: admin.publicKey,
             config: platformConfigPDA,
@@ -333,11 +319,10 @@ describe("03 - Disaster Management", () => {
         eventId: "EMPTY-CITY",
         location: { country: "US", region: "Test", city: "", area: "", latitude: 0, longitude: 0 },
       });
-      const timestamp = getCurrentTimestamp();
 
       await expectError(
         program.methods
-          .initializeDisaster(params, new anchor.BN(timestamp))
+          .initializeDisaster(params)
           .accountsPartial({
             authority: admin.publicKey,
             config: platformConfigPDA,
@@ -352,10 +337,9 @@ describe("03 - Disaster Management", () => {
       const totalBefore = configBefore.totalDisasters;
 
       const params = createMockDisasterParams({ eventId: `COUNTER-TEST-${Date.now()}` });
-      const timestamp = getCurrentTimestamp();
 
       await program.methods
-        .initializeDisaster(params, new anchor.BN(timestamp))
+        .initializeDisaster(params)
         .accountsPartial({
           authority: admin.publicKey,
           config: platformConfigPDA,
@@ -419,11 +403,10 @@ describe("03 - Disaster Management", () => {
           eventId: `TYPE-TEST-${i}-${Date.now()}`,
           eventType: testTypes[i],
         });
-        const timestamp = getCurrentTimestamp();
         const [disasterPDA] = deriveDisasterPDA(params.eventId, program.programId);
 
         await program.methods
-          .initializeDisaster(params, new anchor.BN(timestamp))
+          .initializeDisaster(params)
           .accountsPartial({
             authority: admin.publicKey,
             config: platformConfigPDA,
@@ -442,10 +425,9 @@ describe("03 - Disaster Management", () => {
     before(async () => {
       testDisasterEventId = `UPDATE-TEST-${Date.now()}`;
       const params = createMockDisasterParams({ eventId: testDisasterEventId });
-      const timestamp = getCurrentTimestamp();
 
       await program.methods
-        .initializeDisaster(params, new anchor.BN(timestamp))
+        .initializeDisaster(params)
         .accountsPartial({
           authority: admin.publicKey,
           config: platformConfigPDA,
@@ -454,17 +436,17 @@ describe("03 - Disaster Management", () => {
     });
 
     it("should update disaster name", async () => {
-      const timestamp = getCurrentTimestamp();
       const [disasterPDA] = deriveDisasterPDA(testDisasterEventId, program.programId);
 
       await program.methods
-        .updateDisaster(testDisasterEventId, new anchor.BN(timestamp), {
+        .updateDisaster(testDisasterEventId, {
           name: "Updated Disaster Name",
           severity: null,
           isActive: null,
           affectedAreas: null,
           description: null,
           estimatedAffectedPopulation: null,
+          verificationWindowSeconds: null,
         })
         .accountsPartial({
           authority: admin.publicKey,
@@ -476,17 +458,17 @@ describe("03 - Disaster Management", () => {
     });
 
     it("should update disaster severity", async () => {
-      const timestamp = getCurrentTimestamp();
       const [disasterPDA] = deriveDisasterPDA(testDisasterEventId, program.programId);
 
       await program.methods
-        .updateDisaster(testDisasterEventId, new anchor.BN(timestamp), {
+        .updateDisaster(testDisasterEventId, {
           name: null,
           severity: 9,
           isActive: null,
           affectedAreas: null,
           description: null,
           estimatedAffectedPopulation: null,
+          verificationWindowSeconds: null,
         })
         .accountsPartial({
           authority: admin.publicKey,
@@ -498,18 +480,18 @@ describe("03 - Disaster Management", () => {
     });
 
     it("should update affected areas", async () => {
-      const timestamp = getCurrentTimestamp();
       const [disasterPDA] = deriveDisasterPDA(testDisasterEventId, program.programId);
       const newAreas = ["Kathmandu", "Lalitpur", "Bhaktapur"];
 
       await program.methods
-        .updateDisaster(testDisasterEventId, new anchor.BN(timestamp), {
+        .updateDisaster(testDisasterEventId, {
           name: null,
           severity: null,
           isActive: null,
           affectedAreas: newAreas,
           description: null,
           estimatedAffectedPopulation: null,
+          verificationWindowSeconds: null,
         })
         .accountsPartial({
           authority: admin.publicKey,
@@ -521,17 +503,17 @@ describe("03 - Disaster Management", () => {
     });
 
     it("should update description", async () => {
-      const timestamp = getCurrentTimestamp();
       const [disasterPDA] = deriveDisasterPDA(testDisasterEventId, program.programId);
 
       await program.methods
-        .updateDisaster(testDisasterEventId, new anchor.BN(timestamp), {
+        .updateDisaster(testDisasterEventId, {
           name: null,
           severity: null,
           isActive: null,
           affectedAreas: null,
           description: "Updated description with more details",
           estimatedAffectedPopulation: null,
+          verificationWindowSeconds: null,
         })
         .accountsPartial({
           authority: admin.publicKey,
@@ -543,17 +525,17 @@ describe("03 - Disaster Management", () => {
     });
 
     it("should update estimated affected population", async () => {
-      const timestamp = getCurrentTimestamp();
       const [disasterPDA] = deriveDisasterPDA(testDisasterEventId, program.programId);
 
       await program.methods
-        .updateDisaster(testDisasterEventId, new anchor.BN(timestamp), {
+        .updateDisaster(testDisasterEventId, {
           name: null,
           severity: null,
           isActive: null,
           affectedAreas: null,
           description: null,
           estimatedAffectedPopulation: 50000,
+          verificationWindowSeconds: null,
         })
         .accountsPartial({
           authority: admin.publicKey,
@@ -565,17 +547,17 @@ describe("03 - Disaster Management", () => {
     });
 
     it("should update multiple fields at once", async () => {
-      const timestamp = getCurrentTimestamp();
       const [disasterPDA] = deriveDisasterPDA(testDisasterEventId, program.programId);
 
       await program.methods
-        .updateDisaster(testDisasterEventId, new anchor.BN(timestamp), {
+        .updateDisaster(testDisasterEventId, {
           name: "Multi-Update Test",
           severity: 8,
           isActive: null,
           affectedAreas: ["Area1", "Area2"],
           description: "Multi-field update",
           estimatedAffectedPopulation: 75000,
+          verificationWindowSeconds: null,
         })
         .accountsPartial({
           authority: admin.publicKey,
@@ -590,20 +572,55 @@ describe("03 - Disaster Management", () => {
       expect(disaster.estimatedAffectedPopulation).to.equal(75000);
     });
 
+    it("should sequence activity logs per disaster", async () => {
+      const [disasterPDA] = deriveDisasterPDA(testDisasterEventId, program.programId);
+      const before = await program.account.disasterEvent.fetch(disasterPDA);
+      const updateParams = {
+        name: null,
+        severity: null,
+        isActive: null,
+        affectedAreas: null,
+        description: "Sequenced update",
+        estimatedAffectedPopulation: null,
+        verificationWindowSeconds: null,
+      };
+
+      for (let i = 0; i < 2; i++) {
+        await program.methods
+          .updateDisaster(testDisasterEventId, updateParams)
+          .accountsPartial({
+            authority: admin.publicKey,
+          })
+          .rpc();
+      }
+
+      const disaster = await program.account.disasterEvent.fetch(disasterPDA);
+      expect(disaster.activityCount.toNumber()).to.equal(before.activityCount.toNumber() + 2);
+
+      for (let i = 0; i < 2; i++) {
+        const sequence = before.activityCount.addn(i);
+        const [activityLogPDA] = deriveActivityLogPDA(disasterPDA, sequence, program.programId);
+        const activityLog = await program.account.activityLog.fetch(activityLogPDA);
+        expect(activityLog.stream.toBase58()).to.equal(disasterPDA.toBase58());
+        expect(activityLog.sequence.toNumber()).to.equal(sequence.toNumber());
+        expect(activityLog.actionType).to.deep.equal({ disasterUpdated: {} });
+      }
+    });
+
     it("should fail when non-authority tries to update", async () => {
       const nonAuthority = Keypair.generate();
       await airdropSOL(provider.connection, nonAuthority.publicKey);
-      const timestamp = getCurrentTimestamp();
 
       await expectError(
         program.methods
-          .updateDisaster(testDisasterEventId, new anchor.BN(timestamp), {
+          .updateDisaster(testDisasterEventId, {
             name: "Unauthorized Update",
             severity: null,
             isActive: null,
             affectedAreas: null,
             description: null,
             estimatedAffectedPopulation: null,
+            verificationWindowSeconds: null,
           })
           .accountsPartial({
             authority: nonAuthority.publicKey,
@@ -615,17 +632,16 @@ describe("03 - Disaster Management", () => {
     });
 
     it("should fail with invalid severity in update", async () => {
-      const timestamp = getCurrentTimestamp();
-
       await expectError(
         program.methods
-          .updateDisaster(testDisasterEventId, new anchor.BN(timestamp), {
+          .updateDisaster(testDisasterEventId, {
             name: null,
             severity: 0,
             isActive: null,
             affectedAreas: null,
             description: null,
             estimatedAffectedPopulation: null,
+            verificationWindowSeconds: null,
           })
           .accountsPartial({
             authority: admin.publicKey,
@@ -642,10 +658,9 @@ describe("03 - Disaster Management", () => {
     before(async () => {
       closeTestEventId = `CLOSE-TEST-${Date.now()}`;
       const params = createMockDisasterParams({ eventId: closeTestEventId });
-      const timestamp = getCurrentTimestamp();
 
       await program.methods
-        .initializeDisaster(params, new anchor.BN(timestamp))
+        .initializeDisaster(params)
         .accountsPartial({
           authority: admin.publicKey,
           config: platformConfigPDA,
@@ -654,11 +669,10 @@ describe("03 - Disaster Management", () => {
     });
 
     it("should close disaster", async () => {
-      const timestamp = getCurrentTimestamp();
       const [disasterPDA] = deriveDisasterPDA(closeTestEventId, program.programId);
 
       await program.methods
-        .closeDisaster(closeTestEventId, new anchor.BN(timestamp))
+        .closeDisaster(closeTestEventId)
         .accountsPartial({
           authority: admin.publicKey,
         })
@@ -669,11 +683,9 @@ describe("03 - Disaster Management", () => {
     });
 
     it("should fail to close already closed disaster", async () => {
-      const timestamp = getCurrentTimestamp();
-
       await expectError(
         program.methods
-          .closeDisaster(closeTestEventId, new anchor.BN(timestamp))
+          .closeDisaster(closeTestEventId)
           .accountsPartial({
             authority: admin.publicKey,
           })
@@ -685,10 +697,9 @@ describe("03 - Disaster Management", () => {
     it("should fail when non-authority tries to close", async () => {
       const newEventId = `CLOSE-AUTH-TEST-${Date.now()}`;
       const params = createMockDisasterParams({ eventId: newEventId });
-      const timestamp1 = getCurrentTimestamp();
 
       await program.methods
-        .initializeDisaster(params, new anchor.BN(timestamp1))
+        .initializeDisaster(params)
         .accountsPartial({
           authority: admin.publicKey,
           config: platformConfigPDA,
@@ -697,11 +708,10 @@ describe("03 - Disaster Management", () => {
 
       const nonAuthority = Keypair.generate();
       await airdropSOL(provider.connection, nonAuthority.publicKey);
-      const timestamp2 = getCurrentTimestamp();
 
       await expectError(
         program.methods
-          .closeDisaster(newEventId, new anchor.BN(timestamp2))
+          .closeDisaster(newEventId)
           .accountsPartial({
             authority: nonAuthority.publicKey,
           })
@@ -2,6 +2,7 @@ import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { expect } from "chai";
 import { Keypair, PublicKey } from "@solana/web3.js";
+import { TOKEN_2022_PROGRAM_ID, getAccount, getAssociatedTokenAddressSync } from "@solana/spl-token";
 import { SaharasolCore } from "../target/types/saharasol_core";
 import {
   derivePlatformConfigPDA,
@@ -11,14 +12,20 @@ import {
   deriveBeneficiaryPDA,
   derivePhoneRegistryPDA,
   deriveNationalIdRegistryPDA,
+  deriveHouseholdRegistryPDA,
+  deriveLocationRegistryPDA,
+  deriveFieldWorkerAssignmentPDA,
+  deriveAidCardMintPDA,
+  deriveAdminActionPDA,
+  encodeGeohash,
   airdropSOL,
-  getCurrentTimestamp,
 } from "./helpers/test-utils";
 import {
   createMockDisasterParams,
   createMockNGOParams,
   createMockFieldWorkerParams,
   createMockBeneficiaryParams,
+  createMockUpdateConfigParams,
 } from "./helpers/mock-data";
 import { expectError } from "./helpers/assertions";
 
@@ -41,6 +48,20 @@ describe("04 - Beneficiary Management", () => {
   let disasterEventId: string;
   let disasterPDA: PublicKey;
 
+  const updateConfig = async (overrides: Partial<any>, reason: string) => {
+    await program.methods
+      .updatePlatformConfig({
+        configParams: createMockUpdateConfigParams(overrides),
+        reason,
+        metadata: "{}",
+      })
+      .accountsPartial({
+        admin: admin.publicKey,
+        config: platformConfigPDA,
+      })
+      .rpc();
+  };
+
   before(async () => {
     [platformConfigPDA] = derivePlatformConfigPDA(program.programId);
 
@@ -58,9 +79,8 @@ describe("04 - Beneficiary Management", () => {
       .signers([ngoAuthority])
       .rpc();
 
-    const actionId = getCurrentTimestamp();
     await program.methods
-      .verifyNgo(ngoAuthority.publicKey, { reason: "Verified for testing" }, new anchor.BN(actionId))
+      .verifyNgo(ngoAuthority.publicKey, { reason: "Verified for testing" })
       .accountsPartial({
         admin: admin.publicKey,
         ngo: ngoPDA,
@@ -125,10 +145,9 @@ describe("04 - Beneficiary Management", () => {
     [disasterPDA] = deriveDisasterPDA(disasterEventId, program.programId);
 
     const disasterParams = createMockDisasterParams({ eventId: disasterEventId });
-    const timestamp = getCurrentTimestamp();
 
     await program.methods
-      .initializeDisaster(disasterParams, new anchor.BN(timestamp))
+      .initializeDisaster(disasterParams)
       .accountsPartial({
         authority: admin.publicKey,
         config: platformConfigPDA,
@@ -144,7 +163,6 @@ describe("04 - Beneficiary Management", () => {
         phoneNumber: "+977-9800000001",
         nationalId: "BEN-001",
       });
-      const timestamp = getCurrentTimestamp();
 
       const [beneficiaryPDA] = deriveBeneficiaryPDA(
         beneficiaryAuthority.publicKey,
@@ -153,7 +171,7 @@ describe("04 - Beneficiary Management", () => {
       );
 
       await program.methods
-        .registerBeneficiary(params, new anchor.BN(timestamp))
+        .registerBeneficiary(params)
         .accountsPartial({
           authority: beneficiaryAuthority.publicKey,
           fieldWorkerAuthority: fieldWorkerAuthority.publicKey,
@@ -195,10 +213,9 @@ describe("04 - Beneficiary Management", () => {
         phoneNumber: phoneNumber,
         nationalId: "BEN-002",
       });
-      const timestamp = getCurrentTimestamp();
 
       await program.methods
-        .registerBeneficiary(params, new anchor.BN(timestamp))
+        .registerBeneficiary(params)
         .accountsPartial({
           authority: beneficiaryAuthority.publicKey,
           fieldWorkerAuthority: fieldWorkerAuthority.publicKey,
@@ -231,10 +248,9 @@ describe("04 - Beneficiary Management", () => {
         phoneNumber: "+977-9800000003",
         nationalId: nationalId,
       });
-      const timestamp = getCurrentTimestamp();
 
       await program.methods
-        .registerBeneficiary(params, new anchor.BN(timestamp))
+        .registerBeneficiary(params)
         .accountsPartial({
           authority: beneficiaryAuthority.publicKey,
           fieldWorkerAuthority: fieldWorkerAuthority.publicKey,
@@ -270,11 +286,10 @@ describe("04 - Beneficiary Management", () => {
         phoneNumber: "+977-9800000001", // Duplicate
         nationalId: "BEN-DUP-PHONE",
       });
-      const timestamp = getCurrentTimestamp();
 
       await expectError(
         program.methods
-          .registerBeneficiary(params, new anchor.BN(timestamp))
+          .registerBeneficiary(params)
           .accountsPartial({
             authority: beneficiaryAuthority.publicKey,
             fieldWorkerAuthority: fieldWorkerAuthority.publicKey,
@@ -301,11 +316,10 @@ describe("04 - Beneficiary Management", () => {
         phoneNumber: "+977-9800000099",
         nationalId: "BEN-001", // Duplicate
       });
-      const timestamp = getCurrentTimestamp();
 
       await expectError(
         program.methods
-          .registerBeneficiary(params, new anchor.BN(timestamp))
+          .registerBeneficiary(params)
           .accountsPartial({
             authority: beneficiaryAuthority.publicKey,
             fieldWorkerAuthority: fieldWorkerAuthority.publicKey,
@@ -333,11 +347,10 @@ describe("04 - Beneficiary Management", () => {
         nationalId: "BEN-FAM-0",
         familySize: 0,
       });
-      const timestamp = getCurrentTimestamp();
 
       await expectError(
         program.methods
-          .registerBeneficiary(params, new anchor.BN(timestamp))
+          .registerBeneficiary(params)
           .accountsPartial({
             authority: beneficiaryAuthority.publicKey,
             fieldWorkerAuthority: fieldWorkerAuthority.publicKey,
@@ -365,11 +378,10 @@ describe("04 - Beneficiary Management", () => {
         nationalId: "BEN-FAM-51",
         familySize: 51,
       });
-      const timestamp = getCurrentTimestamp();
 
       await expectError(
         program.methods
-          .registerBeneficiary(params, new anchor.BN(timestamp))
+          .registerBeneficiary(params)
           .accountsPartial({
             authority: beneficiaryAuthority.publicKey,
             fieldWorkerAuthority: fieldWorkerAuthority.publicKey,
@@ -397,11 +409,10 @@ describe("04 - Beneficiary Management", () => {
         nationalId: "BEN-DAM-0",
         damageSeverity: 0,
       });
-      const timestamp = getCurrentTimestamp();
 
       await expectError(
         program.methods
-          .registerBeneficiary(params, new anchor.BN(timestamp))
+          .registerBeneficiary(params)
           .accountsPartial({
             authority: beneficiaryAuthority.publicKey,
             fieldWorkerAuthority: fieldWorkerAuthority.publicKey,
@@ -429,11 +440,10 @@ describe("04 - Beneficiary Management", () => {
         nationalId: "BEN-DAM-11",
         damageSeverity: 11,
       });
-      const timestamp = getCurrentTimestamp();
 
       await expectError(
         program.methods
-          .registerBeneficiary(params, new anchor.BN(timestamp))
+          .registerBeneficiary(params)
           .accountsPartial({
             authority: beneficiaryAuthority.publicKey,
             fieldWorkerAuthority: fieldWorkerAuthority.publicKey,
@@ -461,11 +471,10 @@ describe("04 - Beneficiary Management", () => {
         nationalId: "BEN-AGE-151",
         age: 151,
       });
-      const timestamp = getCurrentTimestamp();
 
       await expectError(
         program.methods
-          .registerBeneficiary(params, new anchor.BN(timestamp))
+          .registerBeneficiary(params)
           .accountsPartial({
             authority: beneficiaryAuthority.publicKey,
             fieldWorkerAuthority: fieldWorkerAuthority.publicKey,
@@ -485,6 +494,190 @@ describe("04 - Beneficiary Management", () => {
       );
     });
 
+    it("should track household members in a shared household registry", async () => {
+      const householdId = `HH-${Date.now()}`;
+      const [householdRegistryPDA] = deriveHouseholdRegistryPDA(
+        disasterEventId,
+        householdId,
+        program.programId
+      );
+      const members = [Keypair.generate(), Keypair.generate()];
+
+      for (let i = 0; i < members.length; i++) {
+        const params = createMockBeneficiaryParams({
+          disasterId: disasterEventId,
+          phoneNumber: `+977-980000015${i}`,
+          nationalId: `BEN-HOUSEHOLD-${i}`,
+          householdId,
+        });
+
+        await program.methods
+          .registerBeneficiary(params)
+          .accountsPartial({
+            authority: members[i].publicKey,
+            fieldWorkerAuthority: fieldWorkerAuthority.publicKey,
+            payer: fieldWorkerAuthority.publicKey,
+            householdRegistry: householdRegistryPDA,
+            config: platformConfigPDA,
+          })
+          .remainingAccounts([
+            {
+              pubkey: ngoPDA,
+              isWritable: false,
+              isSigner: false,
+            },
+          ])
+          .signers([fieldWorkerAuthority])
+          .rpc();
+      }
+
+      const registry = await program.account.householdRegistry.fetch(householdRegistryPDA);
+      expect(registry.householdId).to.equal(householdId);
+      expect(registry.members.length).to.equal(2);
+      expect(registry.members[0].toString()).to.equal(members[0].publicKey.toString());
+      expect(registry.members[1].toString()).to.equal(members[1].publicKey.toString());
+    });
+
+    it("should fail with household ID but no household registry", async () => {
+      const beneficiaryAuthority = Keypair.generate();
+      const params = createMockBeneficiaryParams({
+        disasterId: disasterEventId,
+        phoneNumber: "+977-9800000160",
+        nationalId: "BEN-HOUSEHOLD-MISSING",
+        householdId: `HH-MISSING-${Date.now()}`,
+      });
+
+      await expectError(
+        program.methods
+          .registerBeneficiary(params)
+          .accountsPartial({
+            authority: beneficiaryAuthority.publicKey,
+            fieldWorkerAuthority: fieldWorkerAuthority.publicKey,
+            payer: fieldWorkerAuthority.publicKey,
+            householdRegistry: null,
+            config: platformConfigPDA,
+          })
+          .remainingAccounts([
+            {
+              pubkey: ngoPDA,
+              isWritable: false,
+              isSigner: false,
+            },
+          ])
+          .signers([fieldWorkerAuthority])
+          .rpc(),
+        "ResourceNotFound"
+      );
+    });
+
+    describe("location density", () => {
+      const location = {
+        country: "NP",
+        region: "Bagmati Province",
+        city: "Kathmandu",
+        area: "Ward 12",
+        latitude: 27.6915,
+        longitude: 85.342,
+      };
+      let locationRegistryPDA: PublicKey;
+
+      const setDensityThreshold = async (threshold: number) => {
+        await updateConfig({ locationDensityThreshold: threshold }, "Adjusting location density threshold");
+      };
+
+      const registerAtLocation = async (authority: Keypair, index: number) => {
+        const params = createMockBeneficiaryParams({
+          disasterId: disasterEventId,
+          phoneNumber: `+977-980000018${index}`,
+          nationalId: `BEN-DENSITY-${index}`,
+          location,
+        });
+
+        await program.methods
+          .registerBeneficiary(params)
+          .accountsPartial({
+            authority: authority.publicKey,
+            fieldWorkerAuthority: fieldWorkerAuthority.publicKey,
+            payer: fieldWorkerAuthority.publicKey,
+            locationRegistry: locationRegistryPDA,
+            config: platformConfigPDA,
+          })
+          .remainingAccounts([
+            {
+              pubkey: ngoPDA,
+              isWritable: false,
+              isSigner: false,
+            },
+          ])
+          .signers([fieldWorkerAuthority])
+          .rpc();
+      };
+
+      before(async () => {
+        [locationRegistryPDA] = deriveLocationRegistryPDA(
+          disasterEventId,
+          encodeGeohash(location.latitude, location.longitude),
+          program.programId
+        );
+        await setDensityThreshold(1);
+      });
+
+      after(async () => {
+        await setDensityThreshold(0);
+      });
+
+      it("should flag registrations once a location cell exceeds the density threshold", async () => {
+        const first = Keypair.generate();
+        const second = Keypair.generate();
+
+        await registerAtLocation(first, 0);
+        await registerAtLocation(second, 1);
+
+        const registry = await program.account.locationRegistry.fetch(locationRegistryPDA);
+        expect(registry.geohash).to.equal(encodeGeohash(location.latitude, location.longitude));
+        expect(registry.registrationCount).to.equal(2);
+
+        const [firstPDA] = deriveBeneficiaryPDA(first.publicKey, disasterEventId, program.programId);
+        const [secondPDA] = deriveBeneficiaryPDA(second.publicKey, disasterEventId, program.programId);
+        const firstBeneficiary = await program.account.beneficiary.fetch(firstPDA);
+        const secondBeneficiary = await program.account.beneficiary.fetch(secondPDA);
+        expect(firstBeneficiary.verificationStatus).to.deep.equal({ pending: {} });
+        expect(secondBeneficiary.verificationStatus).to.deep.equal({ flagged: {} });
+        expect(secondBeneficiary.flaggedReason).to.include("Too many registrations");
+      });
+
+      it("should require the location registry while density checks are enabled", async () => {
+        const params = createMockBeneficiaryParams({
+          disasterId: disasterEventId,
+          phoneNumber: "+977-9800000189",
+          nationalId: "BEN-DENSITY-MISSING",
+          location,
+        });
+
+        await expectError(
+          program.methods
+            .registerBeneficiary(params)
+            .accountsPartial({
+              authority: Keypair.generate().publicKey,
+              fieldWorkerAuthority: fieldWorkerAuthority.publicKey,
+              payer: fieldWorkerAuthority.publicKey,
+              locationRegistry: null,
+              config: platformConfigPDA,
+            })
+            .remainingAccounts([
+              {
+                pubkey: ngoPDA,
+                isWritable: false,
+                isSigner: false,
+              },
+            ])
+            .signers([fieldWorkerAuthority])
+            .rpc(),
+          "ResourceNotFound"
+        );
+      });
+    });
+
     it("should increment disaster total_beneficiaries counter", async () => {
       const disasterBefore = await program.account.disasterEvent.fetch(disasterPDA);
       const totalBefore = disasterBefore.totalBeneficiaries;
@@ -495,10 +688,9 @@ describe("04 - Beneficiary Management", () => {
         phoneNumber: "+977-9800000105",
         nationalId: "BEN-COUNTER",
       });
-      const timestamp = getCurrentTimestamp();
 
       await program.methods
-        .registerBeneficiary(params, new anchor.BN(timestamp))
+        .registerBeneficiary(params)
         .accountsPartial({
           authority: beneficiaryAuthority.publicKey,
           fieldWorkerAuthority: fieldWorkerAuthority.publicKey,
@@ -547,10 +739,9 @@ describe("04 - Beneficiary Management", () => {
         phoneNumber: "+977-9800000106",
         nationalId: "BEN-FW-COUNT",
       });
-      const timestamp = getCurrentTimestamp();
 
       await program.methods
-        .registerBeneficiary(params, new anchor.BN(timestamp))
+        .registerBeneficiary(params)
         .accountsPartial({
           authority: beneficiaryAuthority.publicKey,
           fieldWorkerAuthority: newFieldWorkerAuthority.publicKey,
@@ -572,6 +763,104 @@ describe("04 - Beneficiary Management", () => {
     });
   });
 
+  describe("field worker coverage", () => {
+    let remoteWorkerAuthority: Keypair;
+    let assignmentPDA: PublicKey;
+
+    const registerWithRemoteWorker = (index: number, assignment: PublicKey | null) =>
+      program.methods
+        .registerBeneficiary(
+          createMockBeneficiaryParams({
+            disasterId: disasterEventId,
+            phoneNumber: `+977-980000019${index}`,
+            nationalId: `BEN-COVERAGE-${index}`,
+          })
+        )
+        .accountsPartial({
+          authority: Keypair.generate().publicKey,
+          fieldWorkerAuthority: remoteWorkerAuthority.publicKey,
+          payer: remoteWorkerAuthority.publicKey,
+          fieldWorkerAssignment: assignment,
+          config: platformConfigPDA,
+        })
+        .remainingAccounts([
+          {
+            pubkey: ngoPDA,
+            isWritable: false,
+            isSigner: false,
+          },
+        ])
+        .signers([remoteWorkerAuthority])
+        .rpc();
+
+    before(async () => {
+      remoteWorkerAuthority = Keypair.generate();
+      await airdropSOL(provider.connection, remoteWorkerAuthority.publicKey);
+      const [remoteWorkerPDA] = deriveFieldWorkerPDA(remoteWorkerAuthority.publicKey, program.programId);
+
+      await program.methods
+        .registerFieldWorker(
+          createMockFieldWorkerParams({ name: "Remote Field Worker", assignedDistricts: ["Pokhara"] })
+        )
+        .accountsPartial({
+          fieldWorker: remoteWorkerPDA,
+          ngo: ngoPDA,
+          config: platformConfigPDA,
+          authority: remoteWorkerAuthority.publicKey,
+          ngoAuthority: ngoAuthority.publicKey,
+          payer: ngoAuthority.publicKey,
+        })
+        .signers([ngoAuthority])
+        .rpc();
+
+      [assignmentPDA] = deriveFieldWorkerAssignmentPDA(
+        remoteWorkerAuthority.publicKey,
+        disasterEventId,
+        program.programId
+      );
+    });
+
+    it("should fail to register outside the field worker's districts", async () => {
+      await expectError(registerWithRemoteWorker(0, null), "FieldWorkerNotAssignedToDistrict");
+    });
+
+    it("should register in the disaster's affected areas once assigned by the NGO", async () => {
+      await program.methods
+        .assignFieldWorkerToDisaster(disasterEventId)
+        .accountsPartial({
+          assignment: assignmentPDA,
+          fieldWorker: deriveFieldWorkerPDA(remoteWorkerAuthority.publicKey, program.programId)[0],
+          ngoAuthority: ngoAuthority.publicKey,
+          config: platformConfigPDA,
+        })
+        .signers([ngoAuthority])
+        .rpc();
+
+      const assignment = await program.account.fieldWorkerAssignment.fetch(assignmentPDA);
+      expect(assignment.disasterId).to.equal(disasterEventId);
+      expect(assignment.ngo.toString()).to.equal(ngoPDA.toString());
+
+      await registerWithRemoteWorker(1, assignmentPDA);
+    });
+
+    it("should block registrations again after the assignment is revoked", async () => {
+      await program.methods
+        .revokeFieldWorkerAssignment(disasterEventId)
+        .accountsPartial({
+          assignment: assignmentPDA,
+          fieldWorker: deriveFieldWorkerPDA(remoteWorkerAuthority.publicKey, program.programId)[0],
+          ngoAuthority: ngoAuthority.publicKey,
+        })
+        .signers([ngoAuthority])
+        .rpc();
+
+      const assignmentInfo = await provider.connection.getAccountInfo(assignmentPDA);
+      expect(assignmentInfo).to.be.null;
+
+      await expectError(registerWithRemoteWorker(2, null), "FieldWorkerNotAssignedToDistrict");
+    });
+  });
+
   describe("update_beneficiary", () => {
     let testBeneficiaryAuthority: Keypair;
     let testBeneficiaryPDA: PublicKey;
@@ -589,10 +878,9 @@ describe("04 - Beneficiary Management", () => {
         phoneNumber: "+977-9800000200",
         nationalId: "BEN-UPDATE-TEST",
       });
-      const timestamp = getCurrentTimestamp();
 
       await program.methods
-        .registerBeneficiary(params, new anchor.BN(timestamp))
+        .registerBeneficiary(params)
         .accountsPartial({
           authority: testBeneficiaryAuthority.publicKey,
           fieldWorkerAuthority: fieldWorkerAuthority.publicKey,
@@ -614,7 +902,6 @@ describe("04 - Beneficiary Management", () => {
       await program.methods
         .updateBeneficiary(testBeneficiaryAuthority.publicKey, disasterEventId, {
           name: "Updated Name",
-          phoneNumber: null,
           location: null,
           familySize: null,
           damageSeverity: null,
@@ -639,7 +926,6 @@ describe("04 - Beneficiary Management", () => {
       await program.methods
         .updateBeneficiary(testBeneficiaryAuthority.publicKey, disasterEventId, {
           name: null,
-          phoneNumber: null,
           location: null,
           familySize: 6,
           damageSeverity: null,
@@ -664,7 +950,6 @@ describe("04 - Beneficiary Management", () => {
       await program.methods
         .updateBeneficiary(testBeneficiaryAuthority.publicKey, disasterEventId, {
           name: "Multi Update",
-          phoneNumber: null,
           location: null,
           familySize: 8,
           damageSeverity: 9,
@@ -694,7 +979,6 @@ describe("04 - Beneficiary Management", () => {
         program.methods
           .updateBeneficiary(testBeneficiaryAuthority.publicKey, disasterEventId, {
             name: "Unauthorized Update",
-            phoneNumber: null,
             location: null,
             familySize: null,
             damageSeverity: null,
@@ -719,7 +1003,6 @@ describe("04 - Beneficiary Management", () => {
         program.methods
           .updateBeneficiary(testBeneficiaryAuthority.publicKey, disasterEventId, {
             name: null,
-            phoneNumber: null,
             location: null,
             familySize: 0,
             damageSeverity: null,
@@ -740,6 +1023,125 @@ describe("04 - Beneficiary Management", () => {
     });
   });
 
+  describe("change_beneficiary_phone & correct_beneficiary_national_id", () => {
+    let changeBeneficiaryAuthority: Keypair;
+    let changeBeneficiaryPDA: PublicKey;
+    const originalPhone = "+977-9800000170";
+    const originalNationalId = "BEN-CHANGE-TEST";
+
+    before(async () => {
+      changeBeneficiaryAuthority = Keypair.generate();
+      [changeBeneficiaryPDA] = deriveBeneficiaryPDA(
+        changeBeneficiaryAuthority.publicKey,
+        disasterEventId,
+        program.programId
+      );
+
+      const params = createMockBeneficiaryParams({
+        disasterId: disasterEventId,
+        phoneNumber: originalPhone,
+        nationalId: originalNationalId,
+      });
+
+      await program.methods
+        .registerBeneficiary(params)
+        .accountsPartial({
+          authority: changeBeneficiaryAuthority.publicKey,
+          fieldWorkerAuthority: fieldWorkerAuthority.publicKey,
+          payer: fieldWorkerAuthority.publicKey,
+          config: platformConfigPDA,
+        })
+        .remainingAccounts([
+          {
+            pubkey: ngoPDA,
+            isWritable: false,
+            isSigner: false,
+          },
+        ])
+        .signers([fieldWorkerAuthority])
+        .rpc();
+    });
+
+    it("should move phone registry to the new number", async () => {
+      const newPhone = "+977-9800000171";
+      const [oldPhoneRegistryPDA] = derivePhoneRegistryPDA(disasterEventId, originalPhone, program.programId);
+      const [newPhoneRegistryPDA] = derivePhoneRegistryPDA(disasterEventId, newPhone, program.programId);
+
+      await program.methods
+        .changeBeneficiaryPhone(
+          changeBeneficiaryAuthority.publicKey,
+          disasterEventId,
+          newPhone
+        )
+        .accountsPartial({
+          oldPhoneRegistry: oldPhoneRegistryPDA,
+          newPhoneRegistry: newPhoneRegistryPDA,
+          rentPayer: fieldWorkerAuthority.publicKey,
+          fieldWorkerAuthority: fieldWorkerAuthority.publicKey,
+        })
+        .signers([fieldWorkerAuthority])
+        .rpc();
+
+      const beneficiary = await program.account.beneficiary.fetch(changeBeneficiaryPDA);
+      expect(beneficiary.phoneNumber).to.equal(newPhone);
+
+      const oldRegistry = await provider.connection.getAccountInfo(oldPhoneRegistryPDA);
+      expect(oldRegistry).to.be.null;
+
+      const newRegistry = await program.account.phoneRegistry.fetch(newPhoneRegistryPDA);
+      expect(newRegistry.beneficiary.toString()).to.equal(changeBeneficiaryAuthority.publicKey.toString());
+    });
+
+    it("should fail when non-admin corrects national ID", async () => {
+      const nonAdmin = Keypair.generate();
+      await airdropSOL(provider.connection, nonAdmin.publicKey);
+      const [oldRegistryPDA] = deriveNationalIdRegistryPDA(disasterEventId, originalNationalId, program.programId);
+
+      await expectError(
+        program.methods
+          .correctBeneficiaryNationalId(
+            changeBeneficiaryAuthority.publicKey,
+            disasterEventId,
+            { newNationalId: "BEN-CHANGE-FIXED", reason: "Typo at registration" }
+          )
+          .accountsPartial({
+            oldNationalIdRegistry: oldRegistryPDA,
+            rentPayer: fieldWorkerAuthority.publicKey,
+            admin: nonAdmin.publicKey,
+          })
+          .signers([nonAdmin])
+          .rpc(),
+        "UnauthorizedAdminOrManager"
+      );
+    });
+
+    it("should correct national ID with admin approval", async () => {
+      const newNationalId = "BEN-CHANGE-FIXED";
+      const [oldRegistryPDA] = deriveNationalIdRegistryPDA(disasterEventId, originalNationalId, program.programId);
+      const [newRegistryPDA] = deriveNationalIdRegistryPDA(disasterEventId, newNationalId, program.programId);
+
+      await program.methods
+        .correctBeneficiaryNationalId(
+          changeBeneficiaryAuthority.publicKey,
+          disasterEventId,
+          { newNationalId, reason: "Typo at registration" }
+        )
+        .accountsPartial({
+          oldNationalIdRegistry: oldRegistryPDA,
+          newNationalIdRegistry: newRegistryPDA,
+          rentPayer: fieldWorkerAuthority.publicKey,
+          admin: admin.publicKey,
+        })
+        .rpc();
+
+      const beneficiary = await program.account.beneficiary.fetch(changeBeneficiaryPDA);
+      expect(beneficiary.nationalId).to.equal(newNationalId);
+
+      const oldRegistry = await provider.connection.getAccountInfo(oldRegistryPDA);
+      expect(oldRegistry).to.be.null;
+    });
+  });
+
   describe("verify_beneficiary", () => {
     let verifyBeneficiaryAuthority: Keypair;
     let verifyBeneficiaryPDA: PublicKey;
@@ -757,10 +1159,9 @@ describe("04 - Beneficiary Management", () => {
         phoneNumber: "+977-9800000300",
         nationalId: "BEN-VERIFY-TEST",
       });
-      const timestamp = getCurrentTimestamp();
 
       await program.methods
-        .registerBeneficiary(params, new anchor.BN(timestamp))
+        .registerBeneficiary(params)
         .accountsPartial({
           authority: verifyBeneficiaryAuthority.publicKey,
           fieldWorkerAuthority: fieldWorkerAuthority.publicKey,
@@ -779,10 +1180,8 @@ describe("04 - Beneficiary Management", () => {
     });
 
     it("should add first verification approval", async () => {
-      const timestamp = getCurrentTimestamp();
-
       await program.methods
-        .verifyBeneficiary(verifyBeneficiaryAuthority.publicKey, disasterEventId, new anchor.BN(timestamp))
+        .verifyBeneficiary(verifyBeneficiaryAuthority.publicKey, disasterEventId)
         .accountsPartial({
           fieldWorkerAuthority: fieldWorkerAuthority.publicKey,
         })
@@ -795,10 +1194,8 @@ describe("04 - Beneficiary Management", () => {
     });
 
     it("should add second verification approval", async () => {
-      const timestamp = getCurrentTimestamp();
-
       await program.methods
-        .verifyBeneficiary(verifyBeneficiaryAuthority.publicKey, disasterEventId, new anchor.BN(timestamp))
+        .verifyBeneficiary(verifyBeneficiaryAuthority.publicKey, disasterEventId)
         .accountsPartial({
           fieldWorkerAuthority: fieldWorker2Authority.publicKey,
         })
@@ -810,12 +1207,21 @@ describe("04 - Beneficiary Management", () => {
     });
 
     it("should verify beneficiary after reaching threshold", async () => {
-      const timestamp = getCurrentTimestamp();
+      const [aidCardMint] = deriveAidCardMintPDA(verifyBeneficiaryPDA, program.programId);
+      const aidCardTokenAccount = getAssociatedTokenAddressSync(
+        aidCardMint,
+        verifyBeneficiaryAuthority.publicKey,
+        false,
+        TOKEN_2022_PROGRAM_ID
+      );
 
       await program.methods
-        .verifyBeneficiary(verifyBeneficiaryAuthority.publicKey, disasterEventId, new anchor.BN(timestamp))
+        .verifyBeneficiary(verifyBeneficiaryAuthority.publicKey, disasterEventId)
         .accountsPartial({
           fieldWorkerAuthority: fieldWorker3Authority.publicKey,
+          aidCardMint,
+          aidCardTokenAccount,
+          beneficiaryWallet: verifyBeneficiaryAuthority.publicKey,
         })
         .signers([fieldWorker3Authority])
         .rpc();
@@ -823,6 +1229,147 @@ describe("04 - Beneficiary Management", () => {
       const beneficiary = await program.account.beneficiary.fetch(verifyBeneficiaryPDA);
       expect(beneficiary.verifierApprovals).to.have.lengthOf(3);
       expect(beneficiary.verifiedAt).to.not.be.null;
+      expect(beneficiary.nftMint.toString()).to.equal(aidCardMint.toString());
+
+      const card = await getAccount(provider.connection, aidCardTokenAccount, undefined, TOKEN_2022_PROGRAM_ID);
+      expect(Number(card.amount)).to.equal(1);
+      expect(card.owner.toString()).to.equal(verifyBeneficiaryAuthority.publicKey.toString());
+    });
+
+    describe("independent verification rules", () => {
+      let independentBeneficiaryAuthority: Keypair;
+      let independentBeneficiaryPDA: PublicKey;
+      let originalThreshold: number;
+
+      before(async () => {
+        independentBeneficiaryAuthority = Keypair.generate();
+        [independentBeneficiaryPDA] = deriveBeneficiaryPDA(
+          independentBeneficiaryAuthority.publicKey,
+          disasterEventId,
+          program.programId
+        );
+
+        await program.methods
+          .registerBeneficiary(
+            createMockBeneficiaryParams({
+              disasterId: disasterEventId,
+              phoneNumber: "+977-9800000310",
+              nationalId: "BEN-INDEPENDENT-VERIFY",
+            })
+          )
+          .accountsPartial({
+            authority: independentBeneficiaryAuthority.publicKey,
+            fieldWorkerAuthority: fieldWorkerAuthority.publicKey,
+            payer: fieldWorkerAuthority.publicKey,
+            config: platformConfigPDA,
+          })
+          .remainingAccounts([
+            {
+              pubkey: ngoPDA,
+              isWritable: false,
+              isSigner: false,
+            },
+          ])
+          .signers([fieldWorkerAuthority])
+          .rpc();
+
+        const config = await program.account.platformConfig.fetch(platformConfigPDA);
+        originalThreshold = config.verificationThreshold;
+
+        await updateConfig(
+          { verificationThreshold: 2, excludeRegisteringVerifier: true, minVerifierNgos: 2 },
+          "Require independent verifiers"
+        );
+      });
+
+      after(async () => {
+        await updateConfig(
+          { verificationThreshold: originalThreshold, excludeRegisteringVerifier: false, minVerifierNgos: 0 },
+          "Restore default verification rules"
+        );
+      });
+
+      it("should fail when the registering field worker verifies", async () => {
+        await expectError(
+          program.methods
+            .verifyBeneficiary(
+              independentBeneficiaryAuthority.publicKey,
+              disasterEventId
+            )
+            .accountsPartial({
+              fieldWorkerAuthority: fieldWorkerAuthority.publicKey,
+            })
+            .signers([fieldWorkerAuthority])
+            .rpc(),
+          "SelfVerificationNotAllowed"
+        );
+      });
+
+      it("should stay pending when all approvals come from one NGO", async () => {
+        for (const verifier of [fieldWorker2Authority, fieldWorker3Authority]) {
+          await program.methods
+            .verifyBeneficiary(
+              independentBeneficiaryAuthority.publicKey,
+              disasterEventId
+            )
+            .accountsPartial({
+              fieldWorkerAuthority: verifier.publicKey,
+            })
+            .signers([verifier])
+            .rpc();
+        }
+
+        const beneficiary = await program.account.beneficiary.fetch(independentBeneficiaryPDA);
+        expect(beneficiary.verifierApprovals).to.have.lengthOf(2);
+        expect(beneficiary.verifierNgos).to.have.lengthOf(1);
+        expect(beneficiary.verifierNgos[0].toString()).to.equal(ngoPDA.toString());
+        expect(beneficiary.verificationStatus).to.deep.equal({ pending: {} });
+      });
+
+      it("should reserve the last approval slots for verifiers from other NGOs", async () => {
+        const config = await program.account.platformConfig.fetch(platformConfigPDA);
+        const originalMaxVerifiers = config.maxVerifiers;
+
+        const sameNgoVerifier = Keypair.generate();
+        await airdropSOL(provider.connection, sameNgoVerifier.publicKey);
+        const [sameNgoVerifierPDA] = deriveFieldWorkerPDA(sameNgoVerifier.publicKey, program.programId);
+
+        await program.methods
+          .registerFieldWorker(createMockFieldWorkerParams({ name: "Field Worker 4" }))
+          .accountsPartial({
+            fieldWorker: sameNgoVerifierPDA,
+            ngo: ngoPDA,
+            config: platformConfigPDA,
+            authority: sameNgoVerifier.publicKey,
+            ngoAuthority: ngoAuthority.publicKey,
+            payer: ngoAuthority.publicKey,
+          })
+          .signers([ngoAuthority])
+          .rpc();
+
+        await updateConfig({ maxVerifiers: 3 }, "Limit verifier slots");
+
+        try {
+          await expectError(
+            program.methods
+              .verifyBeneficiary(
+                independentBeneficiaryAuthority.publicKey,
+                disasterEventId
+              )
+              .accountsPartial({
+                fieldWorkerAuthority: sameNgoVerifier.publicKey,
+              })
+              .signers([sameNgoVerifier])
+              .rpc(),
+            "IndependentVerifierRequired"
+          );
+        } finally {
+          await updateConfig({ maxVerifiers: originalMaxVerifiers }, "Restore verifier slots");
+        }
+
+        const beneficiary = await program.account.beneficiary.fetch(independentBeneficiaryPDA);
+        expect(beneficiary.verifierApprovals).to.have.lengthOf(2);
+      });
     });
 
     it("should fail when same field worker tries to verify twice", async () => {
@@ -832,10 +1379,9 @@ describe("04 - Beneficiary Management", () => {
         phoneNumber: "+977-9800000301",
         nationalId: "BEN-DUP-VERIFY",
       });
-      const timestamp1 = getCurrentTimestamp();
 
       await program.methods
-        .registerBeneficiary(params, new anchor.BN(timestamp1))
+        .registerBeneficiary(params)
         .accountsPartial({
           authority: newBeneficiaryAuthority.publicKey,
           fieldWorkerAuthority: fieldWorkerAuthority.publicKey,
@@ -852,19 +1398,17 @@ describe("04 - Beneficiary Management", () => {
         .signers([fieldWorkerAuthority])
         .rpc();
 
-      const timestamp2 = getCurrentTimestamp();
       await program.methods
-        .verifyBeneficiary(newBeneficiaryAuthority.publicKey, disasterEventId, new anchor.BN(timestamp2))
+        .verifyBeneficiary(newBeneficiaryAuthority.publicKey, disasterEventId)
         .accountsPartial({
           fieldWorkerAuthority: fieldWorkerAuthority.publicKey,
         })
         .signers([fieldWorkerAuthority])
         .rpc();
 
-      const timestamp3 = getCurrentTimestamp();
       await expectError(
         program.methods
-          .verifyBeneficiary(newBeneficiaryAuthority.publicKey, disasterEventId, new anchor.BN(timestamp3))
+          .verifyBeneficiary(newBeneficiaryAuthority.publicKey, disasterEventId)
           .accountsPartial({
             fieldWorkerAuthority: fieldWorkerAuthority.publicKey,
           })
@@ -875,11 +1419,9 @@ describe("04 - Beneficiary Management", () => {
     });
 
     it("should fail to verify already verified beneficiary", async () => {
-      const timestamp = getCurrentTimestamp();
-
       await expectError(
         program.methods
-          .verifyBeneficiary(verifyBeneficiaryAuthority.publicKey, disasterEventId, new anchor.BN(timestamp))
+          .verifyBeneficiary(verifyBeneficiaryAuthority.publicKey, disasterEventId)
           .accountsPartial({
             fieldWorkerAuthority: fieldWorkerAuthority.publicKey,
           })
@@ -899,10 +1441,9 @@ describe("04 - Beneficiary Management", () => {
         phoneNumber: "+977-9800000302",
         nationalId: "BEN-VERIFY-COUNTER",
       });
-      const timestamp1 = getCurrentTimestamp();
 
       await program.methods
-        .registerBeneficiary(params, new anchor.BN(timestamp1))
+        .registerBeneficiary(params)
         .accountsPartial({
           authority: newBeneficiaryAuthority.publicKey,
           fieldWorkerAuthority: fieldWorkerAuthority.publicKey,
@@ -921,10 +1462,9 @@ describe("04 - Beneficiary Management", () => {
 
       // Add 3 verifications to reach threshold
       for (let i = 0; i < 3; i++) {
-        const timestamp = getCurrentTimestamp();
         const fw = i === 0 ? fieldWorkerAuthority : i === 1 ? fieldWorker2Authority : fieldWorker3Authority;
         await program.methods
-          .verifyBeneficiary(newBeneficiaryAuthority.publicKey, disasterEventId, new anchor.BN(timestamp))
+          .verifyBeneficiary(newBeneficiaryAuthority.publicKey, disasterEventId)
           .accountsPartial({
             fieldWorkerAuthority: fw.publicKey,
           })
@@ -936,6 +1476,79 @@ describe("04 - Beneficiary Management", () => {
       expect(disasterAfter.verifiedBeneficiaries).to.be.at.least(verifiedBefore + 1);
     });
 
+    it("should issue an aid card to a beneficiary verified without one", async () => {
+      const cardBeneficiaryAuthority = Keypair.generate();
+      const [cardBeneficiaryPDA] = deriveBeneficiaryPDA(
+        cardBeneficiaryAuthority.publicKey,
+        disasterEventId,
+        program.programId
+      );
+      const params = createMockBeneficiaryParams({
+        disasterId: disasterEventId,
+        phoneNumber: "+977-9800000304",
+        nationalId: "BEN-AID-CARD-ISSUE",
+      });
+
+      await program.methods
+        .registerBeneficiary(params)
+        .accountsPartial({
+          authority: cardBeneficiaryAuthority.publicKey,
+          fieldWorkerAuthority: fieldWorkerAuthority.publicKey,
+          payer: fieldWorkerAuthority.publicKey,
+          config: platformConfigPDA,
+        })
+        .remainingAccounts([
+          {
+            pubkey: ngoPDA,
+            isWritable: false,
+            isSigner: false,
+          },
+        ])
+        .signers([fieldWorkerAuthority])
+        .rpc();
+
+      for (const verifier of [fieldWorkerAuthority, fieldWorker2Authority, fieldWorker3Authority]) {
+        await program.methods
+          .verifyBeneficiary(cardBeneficiaryAuthority.publicKey, disasterEventId)
+          .accountsPartial({
+            fieldWorkerAuthority: verifier.publicKey,
+          })
+          .signers([verifier])
+          .rpc();
+      }
+
+      const beneficiaryBefore = await program.account.beneficiary.fetch(cardBeneficiaryPDA);
+      expect(beneficiaryBefore.nftMint).to.be.null;
+
+      const [aidCardMint] = deriveAidCardMintPDA(cardBeneficiaryPDA, program.programId);
+      const aidCardTokenAccount = getAssociatedTokenAddressSync(
+        aidCardMint,
+        cardBeneficiaryAuthority.publicKey,
+        false,
+        TOKEN_2022_PROGRAM_ID
+      );
+
+      const issueAidCard = () =>
+        program.methods
+          .issueAidCard(cardBeneficiaryAuthority.publicKey, disasterEventId)
+          .accountsPartial({
+            aidCardTokenAccount,
+            beneficiaryWallet: cardBeneficiaryAuthority.publicKey,
+            payer: admin.publicKey,
+          })
+          .rpc();
+
+      await issueAidCard();
+
+      const beneficiaryAfter = await program.account.beneficiary.fetch(cardBeneficiaryPDA);
+      expect(beneficiaryAfter.nftMint.toString()).to.equal(aidCardMint.toString());
+
+      const card = await getAccount(provider.connection, aidCardTokenAccount, undefined, TOKEN_2022_PROGRAM_ID);
+      expect(Number(card.amount)).to.equal(1);
+
+      await expectError(issueAidCard(), "AidCardAlreadyIssued");
+    });
+
     it("should increment field worker verifications_count", async () => {
       const fieldWorkerBefore = await program.account.fieldWorker.fetch(fieldWorkerPDA);
       const countBefore = fieldWorkerBefore.verificationsCount;
@@ -946,10 +1559,9 @@ describe("04 - Beneficiary Management", () => {
         phoneNumber: "+977-9800000303",
         nationalId: "BEN-FW-VERIFY-COUNT",
       });
-      const timestamp1 = getCurrentTimestamp();
 
       await program.methods
-        .registerBeneficiary(params, new anchor.BN(timestamp1))
+        .registerBeneficiary(params)
         .accountsPartial({
           authority: newBeneficiaryAuthority.publicKey,
           fieldWorkerAuthority: fieldWorkerAuthority.publicKey,
@@ -966,9 +1578,8 @@ describe("04 - Beneficiary Management", () => {
         .signers([fieldWorkerAuthority])
         .rpc();
 
-      const timestamp2 = getCurrentTimestamp();
       await program.methods
-        .verifyBeneficiary(newBeneficiaryAuthority.publicKey, disasterEventId, new anchor.BN(timestamp2))
+        .verifyBeneficiary(newBeneficiaryAuthority.publicKey, disasterEventId)
         .accountsPartial({
           fieldWorkerAuthority: fieldWorkerAuthority.publicKey,
         })
@@ -980,6 +1591,294 @@ describe("04 - Beneficiary Management", () => {
     });
   });
 
+  describe("revoke_beneficiary_verification", () => {
+    let revokeBeneficiaryAuthority: Keypair;
+    let revokeBeneficiaryPDA: PublicKey;
+    let aidCardMint: PublicKey;
+    let aidCardTokenAccount: PublicKey;
+
+    before(async () => {
+      revokeBeneficiaryAuthority = Keypair.generate();
+      [revokeBeneficiaryPDA] = deriveBeneficiaryPDA(
+        revokeBeneficiaryAuthority.publicKey,
+        disasterEventId,
+        program.programId
+      );
+
+      await program.methods
+        .registerBeneficiary(
+          createMockBeneficiaryParams({
+            disasterId: disasterEventId,
+            phoneNumber: "+977-9800000330",
+            nationalId: "BEN-REVOKE-TEST",
+          })
+        )
+        .accountsPartial({
+          authority: revokeBeneficiaryAuthority.publicKey,
+          fieldWorkerAuthority: fieldWorkerAuthority.publicKey,
+          payer: fieldWorkerAuthority.publicKey,
+          config: platformConfigPDA,
+        })
+        .remainingAccounts([
+          {
+            pubkey: ngoPDA,
+            isWritable: false,
+            isSigner: false,
+          },
+        ])
+        .signers([fieldWorkerAuthority])
+        .rpc();
+
+      [aidCardMint] = deriveAidCardMintPDA(revokeBeneficiaryPDA, program.programId);
+      aidCardTokenAccount = getAssociatedTokenAddressSync(
+        aidCardMint,
+        revokeBeneficiaryAuthority.publicKey,
+        false,
+        TOKEN_2022_PROGRAM_ID
+      );
+
+      for (const verifier of [fieldWorkerAuthority, fieldWorker2Authority, fieldWorker3Authority]) {
+        await program.methods
+          .verifyBeneficiary(
+            revokeBeneficiaryAuthority.publicKey,
+            disasterEventId
+          )
+          .accountsPartial({
+            fieldWorkerAuthority: verifier.publicKey,
+            aidCardTokenAccount,
+            beneficiaryWallet: revokeBeneficiaryAuthority.publicKey,
+          })
+          .signers([verifier])
+          .rpc();
+      }
+    });
+
+    it("should require aid card accounts to revoke a card holder", async () => {
+      await expectError(
+        program.methods
+          .revokeBeneficiaryVerification(
+            revokeBeneficiaryAuthority.publicKey,
+            disasterEventId,
+            { reason: "Missing card accounts" }
+          )
+          .accountsPartial({
+            admin: admin.publicKey,
+            aidCardTokenAccount: null,
+          })
+          .rpc(),
+        "AidCardAccountsRequired"
+      );
+    });
+
+    it("should fail when a field worker tries to revoke", async () => {
+      await expectError(
+        program.methods
+          .revokeBeneficiaryVerification(
+            revokeBeneficiaryAuthority.publicKey,
+            disasterEventId,
+            { reason: "Suspected fraud" }
+          )
+          .accountsPartial({
+            admin: fieldWorkerAuthority.publicKey,
+          })
+          .signers([fieldWorkerAuthority])
+          .rpc(),
+        "UnauthorizedAdminOrManager"
+      );
+    });
+
+    it("should revoke a verified beneficiary and unwind counters", async () => {
+      const disasterBefore = await program.account.disasterEvent.fetch(disasterPDA);
+      const configBefore = await program.account.platformConfig.fetch(platformConfigPDA);
+
+      await program.methods
+        .revokeBeneficiaryVerification(
+          revokeBeneficiaryAuthority.publicKey,
+          disasterEventId,
+          { reason: "Fraudulent documents discovered" }
+        )
+        .accountsPartial({
+          admin: admin.publicKey,
+          aidCardTokenAccount,
+        })
+        .rpc();
+
+      const beneficiary = await program.account.beneficiary.fetch(revokeBeneficiaryPDA);
+      expect(beneficiary.verificationStatus).to.deep.equal({ rejected: {} });
+      expect(beneficiary.verifiedAt).to.be.null;
+      expect(beneficiary.verifierApprovals).to.have.lengthOf(0);
+      expect(beneficiary.adminNotes).to.equal("Fraudulent documents discovered");
+
+      const disasterAfter = await program.account.disasterEvent.fetch(disasterPDA);
+      const configAfter = await program.account.platformConfig.fetch(platformConfigPDA);
+      expect(disasterAfter.verifiedBeneficiaries).to.equal(disasterBefore.verifiedBeneficiaries - 1);
+      expect(configAfter.totalVerifiedBeneficiaries).to.equal(configBefore.totalVerifiedBeneficiaries - 1);
+
+      const card = await getAccount(provider.connection, aidCardTokenAccount, undefined, TOKEN_2022_PROGRAM_ID);
+      expect(card.isFrozen).to.be.true;
+    });
+
+    it("should fail to revoke a beneficiary that is not verified", async () => {
+      await expectError(
+        program.methods
+          .revokeBeneficiaryVerification(
+            revokeBeneficiaryAuthority.publicKey,
+            disasterEventId,
+            { reason: "Duplicate revoke" }
+          )
+          .accountsPartial({
+            admin: admin.publicKey,
+          })
+          .rpc(),
+        "BeneficiaryNotVerified"
+      );
+    });
+  });
+
+  describe("expire_beneficiary_verification", () => {
+    let expiringDisasterId: string;
+    let expiringBeneficiaryAuthority: Keypair;
+    let expiringBeneficiaryPDA: PublicKey;
+
+    before(async () => {
+      expiringDisasterId = `BEN-EXPIRY-${Date.now()}`;
+
+      await program.methods
+        .initializeDisaster(
+          createMockDisasterParams({ eventId: expiringDisasterId })
+        )
+        .accountsPartial({
+          authority: admin.publicKey,
+          config: platformConfigPDA,
+        })
+        .rpc();
+
+      await program.methods
+        .updateDisaster(expiringDisasterId, {
+          name: null,
+          severity: null,
+          isActive: null,
+          affectedAreas: null,
+          description: null,
+          estimatedAffectedPopulation: null,
+          verificationWindowSeconds: new anchor.BN(1),
+        })
+        .accountsPartial({
+          authority: admin.publicKey,
+        })
+        .rpc();
+
+      expiringBeneficiaryAuthority = Keypair.generate();
+      [expiringBeneficiaryPDA] = deriveBeneficiaryPDA(
+        expiringBeneficiaryAuthority.publicKey,
+        expiringDisasterId,
+        program.programId
+      );
+
+      await program.methods
+        .registerBeneficiary(
+          createMockBeneficiaryParams({
+            disasterId: expiringDisasterId,
+            phoneNumber: "+977-9800000320",
+            nationalId: "BEN-EXPIRY-TEST",
+          })
+        )
+        .accountsPartial({
+          authority: expiringBeneficiaryAuthority.publicKey,
+          fieldWorkerAuthority: fieldWorkerAuthority.publicKey,
+          payer: fieldWorkerAuthority.publicKey,
+          config: platformConfigPDA,
+        })
+        .remainingAccounts([
+          {
+            pubkey: ngoPDA,
+            isWritable: false,
+            isSigner: false,
+          },
+        ])
+        .signers([fieldWorkerAuthority])
+        .rpc();
+
+      await new Promise((resolve) => setTimeout(resolve, 3000));
+    });
+
+    it("should fail to approve after the verification window", async () => {
+      await expectError(
+        program.methods
+          .verifyBeneficiary(
+            expiringBeneficiaryAuthority.publicKey,
+            expiringDisasterId
+          )
+          .accountsPartial({
+            fieldWorkerAuthority: fieldWorker2Authority.publicKey,
+          })
+          .signers([fieldWorker2Authority])
+          .rpc(),
+        "VerificationExpired"
+      );
+    });
+
+    it("should fail to expire a beneficiary still inside the window", async () => {
+      const pendingAuthority = Keypair.generate();
+
+      await program.methods
+        .registerBeneficiary(
+          createMockBeneficiaryParams({
+            disasterId: disasterEventId,
+            phoneNumber: "+977-9800000321",
+            nationalId: "BEN-EXPIRY-PENDING",
+          })
+        )
+        .accountsPartial({
+          authority: pendingAuthority.publicKey,
+          fieldWorkerAuthority: fieldWorkerAuthority.publicKey,
+          payer: fieldWorkerAuthority.publicKey,
+          config: platformConfigPDA,
+        })
+        .remainingAccounts([
+          {
+            pubkey: ngoPDA,
+            isWritable: false,
+            isSigner: false,
+          },
+        ])
+        .signers([fieldWorkerAuthority])
+        .rpc();
+
+      await expectError(
+        program.methods
+          .expireBeneficiaryVerification(
+            pendingAuthority.publicKey,
+            disasterEventId
+          )
+          .accountsPartial({
+            caller: admin.publicKey,
+          })
+          .rpc(),
+        "VerificationNotExpired"
+      );
+    });
+
+    it("should let anyone expire a stale pending beneficiary", async () => {
+      const cranker = Keypair.generate();
+      await airdropSOL(provider.connection, cranker.publicKey);
+
+      await program.methods
+        .expireBeneficiaryVerification(
+          expiringBeneficiaryAuthority.publicKey,
+          expiringDisasterId
+        )
+        .accountsPartial({
+          caller: cranker.publicKey,
+        })
+        .signers([cranker])
+        .rpc();
+
+      const beneficiary = await program.account.beneficiary.fetch(expiringBeneficiaryPDA);
+      expect(beneficiary.verificationStatus).to.deep.equal({ expired: {} });
+    });
+  });
+
   describe("flag_beneficiary", () => {
     let flagBeneficiaryAuthority: Keypair;
     let flagBeneficiaryPDA: PublicKey;
@@ -997,10 +1896,9 @@ describe("04 - Beneficiary Management", () => {
         phoneNumber: "+977-9800000400",
         nationalId: "BEN-FLAG-TEST",
       });
-      const timestamp = getCurrentTimestamp();
 
       await program.methods
-        .registerBeneficiary(params, new anchor.BN(timestamp))
+        .registerBeneficiary(params)
         .accountsPartial({
           authority: flagBeneficiaryAuthority.publicKey,
           fieldWorkerAuthority: fieldWorkerAuthority.publicKey,
@@ -1042,10 +1940,9 @@ describe("04 - Beneficiary Management", () => {
         phoneNumber: "+977-9800000401",
         nationalId: "BEN-FLAG-EMPTY",
       });
-      const timestamp = getCurrentTimestamp();
 
       await program.methods
-        .registerBeneficiary(params, new anchor.BN(timestamp))
+        .registerBeneficiary(params)
         .accountsPartial({
           authority: newBeneficiaryAuthority.publicKey,
           fieldWorkerAuthority: fieldWorkerAuthority.publicKey,
@@ -1083,10 +1980,9 @@ describe("04 - Beneficiary Management", () => {
         phoneNumber: "+977-9800000402",
         nationalId: "BEN-FLAG-VERIFIED",
       });
-      const timestamp1 = getCurrentTimestamp();
 
       await program.methods
-        .registerBeneficiary(params, new anchor.BN(timestamp1))
+        .registerBeneficiary(params)
         .accountsPartial({
           authority: newBeneficiaryAuthority.publicKey,
           fieldWorkerAuthority: fieldWorkerAuthority.publicKey,
@@ -1105,10 +2001,9 @@ describe("04 - Beneficiary Management", () => {
 
       // Verify the beneficiary
       for (let i = 0; i < 3; i++) {
-        const timestamp = getCurrentTimestamp();
         const fw = i === 0 ? fieldWorkerAuthority : i === 1 ? fieldWorker2Authority : fieldWorker3Authority;
         await program.methods
-          .verifyBeneficiary(newBeneficiaryAuthority.publicKey, disasterEventId, new anchor.BN(timestamp))
+          .verifyBeneficiary(newBeneficiaryAuthority.publicKey, disasterEventId)
           .accountsPartial({
             fieldWorkerAuthority: fw.publicKey,
           })
@@ -1140,10 +2035,9 @@ describe("04 - Beneficiary Management", () => {
         phoneNumber: "+977-9800000403",
         nationalId: "BEN-FLAG-COUNT",
       });
-      const timestamp = getCurrentTimestamp();
 
       await program.methods
-        .registerBeneficiary(params, new anchor.BN(timestamp))
+        .registerBeneficiary(params)
         .accountsPartial({
           authority: newBeneficiaryAuthority.publicKey,
           fieldWorkerAuthority: fieldWorkerAuthority.publicKey,
@@ -1192,10 +2086,9 @@ describe("04 - Beneficiary Management", () => {
         phoneNumber: "+977-9800000500",
         nationalId: "BEN-REVIEW-TEST",
       });
-      const timestamp = getCurrentTimestamp();
 
       await program.methods
-        .registerBeneficiary(params, new anchor.BN(timestamp))
+        .registerBeneficiary(params)
         .accountsPartial({
           authority: reviewBeneficiaryAuthority.publicKey,
           fieldWorkerAuthority: fieldWorkerAuthority.publicKey,
@@ -1254,10 +2147,9 @@ describe("04 - Beneficiary Management", () => {
         phoneNumber: "+977-9800000501",
         nationalId: "BEN-REJECT-TEST",
       });
-      const timestamp = getCurrentTimestamp();
 
       await program.methods
-        .registerBeneficiary(params, new anchor.BN(timestamp))
+        .registerBeneficiary(params)
         .accountsPartial({
           authority: rejectBeneficiaryAuthority.publicKey,
           fieldWorkerAuthority: fieldWorkerAuthority.publicKey,
@@ -1305,10 +2197,9 @@ describe("04 - Beneficiary Management", () => {
         phoneNumber: "+977-9800000502",
         nationalId: "BEN-REVIEW-UNAUTH",
       });
-      const timestamp = getCurrentTimestamp();
 
       await program.methods
-        .registerBeneficiary(params, new anchor.BN(timestamp))
+        .registerBeneficiary(params)
         .accountsPartial({
           authority: newBeneficiaryAuthority.publicKey,
           fieldWorkerAuthority: fieldWorkerAuthority.publicKey,
@@ -1349,10 +2240,116 @@ describe("04 - Beneficiary Management", () => {
           })
           .signers([nonAdmin])
           .rpc(),
-        "UnauthorizedAdmin"
+        "UnauthorizedAdminOrManager"
       );
     });
 
+    it("should let the declaring NGO review once delegated", async () => {
+      const ngoDisasterId = `NGO-REVIEW-${Date.now()}`;
+
+      await program.methods
+        .initializeDisaster(
+          createMockDisasterParams({ eventId: ngoDisasterId })
+        )
+        .accountsPartial({
+          authority: ngoAuthority.publicKey,
+          config: platformConfigPDA,
+        })
+        .remainingAccounts([
+          {
+            pubkey: ngoPDA,
+            isWritable: false,
+            isSigner: false,
+          },
+        ])
+        .signers([ngoAuthority])
+        .rpc();
+
+      const flaggedAuthority = Keypair.generate();
+      await program.methods
+        .registerBeneficiary(
+          createMockBeneficiaryParams({
+            disasterId: ngoDisasterId,
+            phoneNumber: "+977-9800000510",
+            nationalId: "BEN-REVIEW-DELEGATED",
+          })
+        )
+        .accountsPartial({
+          authority: flaggedAuthority.publicKey,
+          fieldWorkerAuthority: fieldWorkerAuthority.publicKey,
+          payer: fieldWorkerAuthority.publicKey,
+          config: platformConfigPDA,
+        })
+        .remainingAccounts([
+          {
+            pubkey: ngoPDA,
+            isWritable: false,
+            isSigner: false,
+          },
+        ])
+        .signers([fieldWorkerAuthority])
+        .rpc();
+
+      await program.methods
+        .flagBeneficiary(flaggedAuthority.publicKey, ngoDisasterId, {
+          reason: "Documents unclear",
+        })
+        .accountsPartial({
+          fieldWorkerAuthority: fieldWorkerAuthority.publicKey,
+        })
+        .signers([fieldWorkerAuthority])
+        .rpc();
+
+      await expectError(
+        program.methods
+          .reviewFlaggedBeneficiary(
+            flaggedAuthority.publicKey,
+            ngoDisasterId,
+            { approve: true, notes: "Not yet delegated" }
+          )
+          .accountsPartial({
+            ngo: ngoPDA,
+            admin: ngoAuthority.publicKey,
+          })
+          .signers([ngoAuthority])
+          .rpc(),
+        "UnauthorizedAdminOrManager"
+      );
+
+      await program.methods
+        .delegateDisasterReview(
+          ngoDisasterId,
+          { enabled: true, reason: "Declaring NGO handles local review" }
+        )
+        .accountsPartial({
+          admin: admin.publicKey,
+        })
+        .rpc();
+
+      const { adminActionCount } = await program.account.platformConfig.fetch(platformConfigPDA);
+      await program.methods
+        .reviewFlaggedBeneficiary(
+          flaggedAuthority.publicKey,
+          ngoDisasterId,
+          { approve: true, notes: "Documents confirmed on site" }
+        )
+        .accountsPartial({
+          ngo: ngoPDA,
+          admin: ngoAuthority.publicKey,
+        })
+        .signers([ngoAuthority])
+        .rpc();
+
+      const [flaggedPDA] = deriveBeneficiaryPDA(flaggedAuthority.publicKey, ngoDisasterId, program.programId);
+      const beneficiary = await program.account.beneficiary.fetch(flaggedPDA);
+      expect(beneficiary.verificationStatus).to.deep.equal({ pending: {} });
+
+      const [adminActionPDA] = deriveAdminActionPDA(ngoAuthority.publicKey, adminActionCount, program.programId);
+      const adminAction = await program.account.adminAction.fetch(adminActionPDA);
+      expect(adminAction.actionType).to.deep.equal({ verifyBeneficiary: {} });
+      expect(adminAction.reason).to.equal("Documents confirmed on site");
+    });
+
     it("should fail to review non-flagged beneficiary", async () => {
       const newBeneficiaryAuthority = Keypair.generate();
       const params = createMockBeneficiaryParams({
@@ -1360,10 +2357,9 @@ describe("04 - Beneficiary Management", () => {
         phoneNumber: "+977-9800000503",
         nationalId: "BEN-REVIEW-NOT-FLAGGED",
       });
-      const timestamp = getCurrentTimestamp();
 
       await program.methods
-        .registerBeneficiary(params, new anchor.BN(timestamp))
+        .registerBeneficiary(params)
         .accountsPartial({
           authority: newBeneficiaryAuthority.publicKey,
           fieldWorkerAuthority: fieldWorkerAuthority.publicKey,
@@ -1,8 +1,18 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { expect } from "chai";
-import { Keypair, PublicKey } from "@solana/web3.js";
-import { getAssociatedTokenAddressSync, createAssociatedTokenAccount, mintTo } from "@solana/spl-token";
+import { Keypair, PublicKey, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import { randomBytes } from "crypto";
+import {
+  TOKEN_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
+  NATIVE_MINT,
+  getAccount,
+  getOrCreateAssociatedTokenAccount,
+  getAssociatedTokenAddressSync,
+  createAssociatedTokenAccount,
+  mintTo,
+} from "@solana/spl-token";
 import { SaharasolCore } from "../target/types/saharasol_core";
 import {
   derivePlatformConfigPDA,
@@ -13,9 +23,15 @@ import {
   deriveFundPoolPDA,
   derivePoolTokenAccountPDA,
   derivePoolRegistrationPDA,
+  deriveDonationRecordPDA,
+  derivePriceFeedPDA,
+  deriveStandInOraclePDA,
+  createDonorCommitment,
   airdropSOL,
   getCurrentTimestamp,
   createTokenMint,
+  createTransferFeeMint,
+  createPermanentDelegateMint,
 } from "./helpers/test-utils";
 import {
   createMockDisasterParams,
@@ -23,6 +39,7 @@ import {
   createMockFieldWorkerParams,
   createMockBeneficiaryParams,
   createMockFundPoolParams,
+  createMockUpdateConfigParams,
 } from "./helpers/mock-data";
 import { expectError } from "./helpers/assertions";
 
@@ -57,9 +74,8 @@ describe("05 - Fund Pool Management", () => {
 
     // Ensure USDC is in allowed tokens (it should be from platform init, but let's verify)
     if (!config.allowedTokens.some((t: PublicKey) => t.toString() === usdcMint.toString())) {
-      const timestamp = getCurrentTimestamp();
       await program.methods
-        .addAllowedToken(new anchor.BN(timestamp), usdcMint, "Adding USDC for pool tests")
+        .addAllowedToken(usdcMint, "Adding USDC for pool tests")
         .accountsPartial({
           admin: admin.publicKey,
           config: platformConfigPDA,
@@ -98,9 +114,8 @@ describe("05 - Fund Pool Management", () => {
       .signers([ngoAuthority])
       .rpc();
 
-    const actionId = getCurrentTimestamp();
     await program.methods
-      .verifyNgo(ngoAuthority.publicKey, { reason: "Verified for testing" }, new anchor.BN(actionId))
+      .verifyNgo(ngoAuthority.publicKey, { reason: "Verified for testing" })
       .accountsPartial({
         admin: admin.publicKey,
         ngo: ngoPDA,
@@ -165,10 +180,9 @@ describe("05 - Fund Pool Management", () => {
     [disasterPDA] = deriveDisasterPDA(disasterEventId, program.programId);
 
     const disasterParams = createMockDisasterParams({ eventId: disasterEventId });
-    const timestamp = getCurrentTimestamp();
 
     await program.methods
-      .initializeDisaster(disasterParams, new anchor.BN(timestamp))
+      .initializeDisaster(disasterParams)
       .accountsPartial({
         authority: admin.publicKey,
         config: platformConfigPDA,
@@ -180,16 +194,16 @@ describe("05 - Fund Pool Management", () => {
     it("should create a fund pool", async () => {
       const poolId = "POOL-001";
       const params = createMockFundPoolParams({ name: "Emergency Relief Pool" });
-      const timestamp = getCurrentTimestamp();
 
       const [poolPDA] = deriveFundPoolPDA(disasterEventId, poolId, program.programId);
 
       await program.methods
-        .createFundPool(disasterEventId, poolId, new anchor.BN(timestamp), params)
+        .createFundPool(disasterEventId, poolId, params)
         .accountsPartial({
           ngoAuthority: ngoAuthority.publicKey,
           payer: ngoAuthority.publicKey,
           tokenMint: usdcMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
         .signers([ngoAuthority])
         .rpc();
@@ -210,6 +224,131 @@ describe("05 - Fund Pool Management", () => {
       expect(pool.beneficiaryCount).to.equal(0);
       expect(pool.donorCount).to.equal(0);
       expect(pool.registrationLocked).to.be.false;
+      expect(pool.claimWindowSeconds.toNumber()).to.equal(90 * 24 * 60 * 60);
+    });
+
+    it("should fail with claim window below platform minimum", async () => {
+      const poolId = "POOL-SHORT-WINDOW";
+      const params = createMockFundPoolParams({
+        claimWindowSeconds: new anchor.BN(24 * 60 * 60),
+      });
+
+      await expectError(
+        program.methods
+          .createFundPool(disasterEventId, poolId, params)
+          .accountsPartial({
+            ngoAuthority: ngoAuthority.publicKey,
+            payer: ngoAuthority.publicKey,
+            tokenMint: usdcMint,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([ngoAuthority])
+          .rpc(),
+        "InvalidClaimWindow"
+      );
+    });
+
+    it("should fail with a claim window shorter than the time lock", async () => {
+      const poolId = "POOL-WINDOW-LOCK";
+      const params = createMockFundPoolParams({
+        distributionPercentageImmediate: 0,
+        distributionPercentageLocked: 100,
+        timeLockDuration: new anchor.BN(30 * 24 * 60 * 60),
+        claimWindowSeconds: new anchor.BN(14 * 24 * 60 * 60),
+      });
+
+      await expectError(
+        program.methods
+          .createFundPool(disasterEventId, poolId, params)
+          .accountsPartial({
+            ngoAuthority: ngoAuthority.publicKey,
+            payer: ngoAuthority.publicKey,
+            tokenMint: usdcMint,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([ngoAuthority])
+          .rpc(),
+        "InvalidClaimWindow"
+      );
+    });
+
+    it("should fail with vesting tranches that do not sum to the locked percentage", async () => {
+      const poolId = "POOL-BAD-VESTING-SUM";
+      const params = createMockFundPoolParams({
+        distributionPercentageImmediate: 40,
+        distributionPercentageLocked: 60,
+        vestingSchedule: [
+          { percentage: 20, unlockOffset: new anchor.BN(30 * 24 * 60 * 60) },
+          { percentage: 20, unlockOffset: new anchor.BN(60 * 24 * 60 * 60) },
+        ],
+      });
+
+      await expectError(
+        program.methods
+          .createFundPool(disasterEventId, poolId, params)
+          .accountsPartial({
+            ngoAuthority: ngoAuthority.publicKey,
+            payer: ngoAuthority.publicKey,
+            tokenMint: usdcMint,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([ngoAuthority])
+          .rpc(),
+        "InvalidVestingSchedule"
+      );
+    });
+
+    it("should fail with vesting tranches out of unlock order", async () => {
+      const poolId = "POOL-BAD-VESTING-ORDER";
+      const params = createMockFundPoolParams({
+        distributionPercentageImmediate: 40,
+        distributionPercentageLocked: 60,
+        vestingSchedule: [
+          { percentage: 30, unlockOffset: new anchor.BN(60 * 24 * 60 * 60) },
+          { percentage: 30, unlockOffset: new anchor.BN(30 * 24 * 60 * 60) },
+        ],
+      });
+
+      await expectError(
+        program.methods
+          .createFundPool(disasterEventId, poolId, params)
+          .accountsPartial({
+            ngoAuthority: ngoAuthority.publicKey,
+            payer: ngoAuthority.publicKey,
+            tokenMint: usdcMint,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([ngoAuthority])
+          .rpc(),
+        "InvalidVestingSchedule"
+      );
+    });
+
+    it("should fail with a vesting tranche beyond the maximum claim window", async () => {
+      const poolId = "POOL-BAD-VESTING-OFFSET";
+      const config = await program.account.platformConfig.fetch(platformConfigPDA);
+      const params = createMockFundPoolParams({
+        distributionPercentageImmediate: 40,
+        distributionPercentageLocked: 60,
+        vestingSchedule: [
+          { percentage: 30, unlockOffset: new anchor.BN(30 * 24 * 60 * 60) },
+          { percentage: 30, unlockOffset: config.maxClaimWindowSeconds.addn(1) },
+        ],
+      });
+
+      await expectError(
+        program.methods
+          .createFundPool(disasterEventId, poolId, params)
+          .accountsPartial({
+            ngoAuthority: ngoAuthority.publicKey,
+            payer: ngoAuthority.publicKey,
+            tokenMint: usdcMint,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([ngoAuthority])
+          .rpc(),
+        "InvalidVestingSchedule"
+      );
     });
 
     it("should fail with invalid distribution percentages (not 100)", async () => {
@@ -218,15 +357,15 @@ describe("05 - Fund Pool Management", () => {
         distributionPercentageImmediate: 60,
         distributionPercentageLocked: 30, // 60 + 30 = 90, not 100
       });
-      const timestamp = getCurrentTimestamp();
 
       await expectError(
         program.methods
-          .createFundPool(disasterEventId, poolId, new anchor.BN(timestamp), params)
+          .createFundPool(disasterEventId, poolId, params)
           .accountsPartial({
             ngoAuthority: ngoAuthority.publicKey,
             payer: ngoAuthority.publicKey,
             tokenMint: usdcMint,
+            tokenProgram: TOKEN_PROGRAM_ID,
           })
           .signers([ngoAuthority])
           .rpc(),
@@ -247,16 +386,16 @@ describe("05 - Fund Pool Management", () => {
           name: `Pool Type ${i}`,
           distributionType: types[i],
         });
-        const timestamp = getCurrentTimestamp();
 
         const [poolPDA] = deriveFundPoolPDA(disasterEventId, poolId, program.programId);
 
         await program.methods
-          .createFundPool(disasterEventId, poolId, new anchor.BN(timestamp), params)
+          .createFundPool(disasterEventId, poolId, params)
           .accountsPartial({
             ngoAuthority: ngoAuthority.publicKey,
             payer: ngoAuthority.publicKey,
             tokenMint: usdcMint,
+            tokenProgram: TOKEN_PROGRAM_ID,
           })
           .signers([ngoAuthority])
           .rpc();
@@ -272,14 +411,14 @@ describe("05 - Fund Pool Management", () => {
 
       const poolId = `POOL-COUNTER-${Date.now()}`;
       const params = createMockFundPoolParams();
-      const timestamp = getCurrentTimestamp();
 
       await program.methods
-        .createFundPool(disasterEventId, poolId, new anchor.BN(timestamp), params)
+        .createFundPool(disasterEventId, poolId, params)
         .accountsPartial({
           ngoAuthority: ngoAuthority.publicKey,
           payer: ngoAuthority.publicKey,
           tokenMint: usdcMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
         .signers([ngoAuthority])
         .rpc();
@@ -294,14 +433,14 @@ describe("05 - Fund Pool Management", () => {
 
       const poolId = `POOL-PLATFORM-${Date.now()}`;
       const params = createMockFundPoolParams();
-      const timestamp = getCurrentTimestamp();
 
       await program.methods
-        .createFundPool(disasterEventId, poolId, new anchor.BN(timestamp), params)
+        .createFundPool(disasterEventId, poolId, params)
         .accountsPartial({
           ngoAuthority: ngoAuthority.publicKey,
           payer: ngoAuthority.publicKey,
           tokenMint: usdcMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
         .signers([ngoAuthority])
         .rpc();
@@ -316,19 +455,17 @@ describe("05 - Fund Pool Management", () => {
       const [closedDisasterPDA] = deriveDisasterPDA(closedDisasterId, program.programId);
 
       const disasterParams = createMockDisasterParams({ eventId: closedDisasterId });
-      const timestamp1 = getCurrentTimestamp();
 
       await program.methods
-        .initializeDisaster(disasterParams, new anchor.BN(timestamp1))
+        .initializeDisaster(disasterParams)
         .accountsPartial({
           authority: admin.publicKey,
           config: platformConfigPDA,
         })
         .rpc();
 
-      const timestamp2 = getCurrentTimestamp();
       await program.methods
-        .closeDisaster(closedDisasterId, new anchor.BN(timestamp2))
+        .closeDisaster(closedDisasterId)
         .accountsPartial({
           authority: admin.publicKey,
         })
@@ -336,15 +473,15 @@ describe("05 - Fund Pool Management", () => {
 
       const poolId = "POOL-CLOSED-DISASTER";
       const params = createMockFundPoolParams();
-      const timestamp3 = getCurrentTimestamp();
 
       await expectError(
         program.methods
-          .createFundPool(closedDisasterId, poolId, new anchor.BN(timestamp3), params)
+          .createFundPool(closedDisasterId, poolId, params)
           .accountsPartial({
             ngoAuthority: ngoAuthority.publicKey,
             payer: ngoAuthority.publicKey,
             tokenMint: usdcMint,
+            tokenProgram: TOKEN_PROGRAM_ID,
           })
           .signers([ngoAuthority])
           .rpc(),
@@ -364,14 +501,14 @@ describe("05 - Fund Pool Management", () => {
       [testPoolPDA] = deriveFundPoolPDA(disasterEventId, testPoolId, program.programId);
 
       const params = createMockFundPoolParams({ name: "Donation Test Pool" });
-      const timestamp = getCurrentTimestamp();
 
       await program.methods
-        .createFundPool(disasterEventId, testPoolId, new anchor.BN(timestamp), params)
+        .createFundPool(disasterEventId, testPoolId, params)
         .accountsPartial({
           ngoAuthority: ngoAuthority.publicKey,
           payer: ngoAuthority.publicKey,
           tokenMint: usdcMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
         .signers([ngoAuthority])
         .rpc();
@@ -402,10 +539,12 @@ describe("05 - Fund Pool Management", () => {
           new anchor.BN(timestamp)
         )
         .accountsPartial({
+          donationRecord: deriveDonationRecordPDA(donorKeypair.publicKey, testPoolPDA, timestamp, program.programId)[0],
           donor: donorKeypair.publicKey,
           donorTokenAccount: donorUsdcAccount,
           poolTokenAccount: poolTokenAccount,
           platformFeeRecipient: platformFeeRecipient,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
         .remainingAccounts([
           {
@@ -442,10 +581,12 @@ describe("05 - Fund Pool Management", () => {
             new anchor.BN(timestamp)
           )
           .accountsPartial({
+            donationRecord: deriveDonationRecordPDA(donorKeypair.publicKey, testPoolPDA, timestamp, program.programId)[0],
             donor: donorKeypair.publicKey,
             donorTokenAccount: donorUsdcAccount,
             poolTokenAccount: poolTokenAccount,
             platformFeeRecipient: platformFeeRecipient,
+            tokenProgram: TOKEN_PROGRAM_ID,
           })
           .remainingAccounts([
             {
@@ -460,30 +601,478 @@ describe("05 - Fund Pool Management", () => {
       );
     });
 
-    it("should increment pool donor_count", async () => {
-      const poolBefore = await program.account.fundPool.fetch(testPoolPDA);
-      const countBefore = poolBefore.donorCount;
+    it("should increment pool donor_count", async () => {
+      const poolBefore = await program.account.fundPool.fetch(testPoolPDA);
+      const countBefore = poolBefore.donorCount;
+
+      const amount = new anchor.BN(5000000);
+      const timestamp = getCurrentTimestamp();
+      const [poolTokenAccount] = derivePoolTokenAccountPDA(disasterEventId, testPoolId, program.programId);
+
+      await program.methods
+        .donateToPool(
+          disasterEventId,
+          testPoolId,
+          {
+            amount: amount,
+            message: "Another donation",
+            isAnonymous: false,
+          },
+          new anchor.BN(timestamp)
+        )
+        .accountsPartial({
+          donationRecord: deriveDonationRecordPDA(donorKeypair.publicKey, testPoolPDA, timestamp, program.programId)[0],
+          donor: donorKeypair.publicKey,
+          donorTokenAccount: donorUsdcAccount,
+          poolTokenAccount: poolTokenAccount,
+          platformFeeRecipient: platformFeeRecipient,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .remainingAccounts([
+          {
+            pubkey: ngoPDA,
+            isWritable: false,
+            isSigner: false,
+          },
+        ])
+        .signers([donorKeypair])
+        .rpc();
+
+      const poolAfter = await program.account.fundPool.fetch(testPoolPDA);
+      expect(poolAfter.donorCount).to.equal(countBefore + 1);
+    });
+
+    it("should store a donor commitment instead of the donor key for anonymous donations", async () => {
+      const timestamp = getCurrentTimestamp();
+      const salt = randomBytes(32);
+      const commitment = createDonorCommitment(donorKeypair.publicKey, salt);
+      const [donationRecordPDA] = deriveDonationRecordPDA(commitment, testPoolPDA, timestamp, program.programId);
+      const [poolTokenAccount] = derivePoolTokenAccountPDA(disasterEventId, testPoolId, program.programId);
+
+      await program.methods
+        .donateToPool(
+          disasterEventId,
+          testPoolId,
+          {
+            amount: new anchor.BN(5000000),
+            message: "Anonymous donation",
+            isAnonymous: true,
+            donorCommitment: Array.from(commitment),
+          },
+          new anchor.BN(timestamp)
+        )
+        .accountsPartial({
+          donationRecord: donationRecordPDA,
+          donor: donorKeypair.publicKey,
+          donorTokenAccount: donorUsdcAccount,
+          poolTokenAccount: poolTokenAccount,
+          platformFeeRecipient: platformFeeRecipient,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .remainingAccounts([
+          {
+            pubkey: ngoPDA,
+            isWritable: false,
+            isSigner: false,
+          },
+        ])
+        .signers([donorKeypair])
+        .rpc();
+
+      const record = await program.account.donationRecord.fetch(donationRecordPDA);
+      expect(record.isAnonymous).to.be.true;
+      expect(record.donor.toString()).to.equal(PublicKey.default.toString());
+      expect(Buffer.from(record.donorCommitment).equals(commitment)).to.be.true;
+    });
+
+    it("should fail anonymous donation without a donor commitment", async () => {
+      const timestamp = getCurrentTimestamp();
+      const [poolTokenAccount] = derivePoolTokenAccountPDA(disasterEventId, testPoolId, program.programId);
+
+      await expectError(
+        program.methods
+          .donateToPool(
+            disasterEventId,
+            testPoolId,
+            {
+              amount: new anchor.BN(5000000),
+              message: "Not really anonymous",
+              isAnonymous: true,
+              donorCommitment: null,
+            },
+            new anchor.BN(timestamp)
+          )
+          .accountsPartial({
+            donationRecord: deriveDonationRecordPDA(donorKeypair.publicKey, testPoolPDA, timestamp, program.programId)[0],
+            donor: donorKeypair.publicKey,
+            donorTokenAccount: donorUsdcAccount,
+            poolTokenAccount: poolTokenAccount,
+            platformFeeRecipient: platformFeeRecipient,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .remainingAccounts([
+            {
+              pubkey: ngoPDA,
+              isWritable: false,
+              isSigner: false,
+            },
+          ])
+          .signers([donorKeypair])
+          .rpc(),
+        "DonorCommitmentMismatch"
+      );
+    });
+  });
+
+  describe("token-2022 pools", () => {
+    let feeMint: PublicKey;
+    let feePoolId: string;
+    let feePoolPDA: PublicKey;
+    let feeDonor: Keypair;
+    let feeDonorTokenAccount: PublicKey;
+    let feeRecipientTokenAccount: PublicKey;
+    let delegateMint: PublicKey;
+
+    const allowToken = async (mint: PublicKey) => {
+      await program.methods
+        .addAllowedToken(mint, "Adding Token-2022 mint for pool tests")
+        .accountsPartial({
+          admin: admin.publicKey,
+          config: platformConfigPDA,
+        })
+        .rpc();
+    };
+
+    before(async () => {
+      // 1% transfer fee withheld by the mint, capped well above the test amounts
+      feeMint = await createTransferFeeMint(provider.connection, admin.payer, 100, BigInt(1000000000));
+      await allowToken(feeMint);
+
+      feePoolId = `FEE-POOL-${Date.now()}`;
+      [feePoolPDA] = deriveFundPoolPDA(disasterEventId, feePoolId, program.programId);
+
+      await program.methods
+        .createFundPool(disasterEventId, feePoolId, createMockFundPoolParams({ name: "Transfer Fee Pool" }))
+        .accountsPartial({
+          ngoAuthority: ngoAuthority.publicKey,
+          payer: ngoAuthority.publicKey,
+          tokenMint: feeMint,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .signers([ngoAuthority])
+        .rpc();
+
+      const config = await program.account.platformConfig.fetch(platformConfigPDA);
+      feeRecipientTokenAccount = await createAssociatedTokenAccount(
+        provider.connection,
+        admin.payer,
+        feeMint,
+        config.platformFeeRecipient,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+
+      feeDonor = Keypair.generate();
+      await airdropSOL(provider.connection, feeDonor.publicKey);
+      feeDonorTokenAccount = await createAssociatedTokenAccount(
+        provider.connection,
+        admin.payer,
+        feeMint,
+        feeDonor.publicKey,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+      await mintTo(
+        provider.connection,
+        admin.payer,
+        feeMint,
+        feeDonorTokenAccount,
+        admin.payer,
+        100000000,
+        [],
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+    });
+
+    after(async () => {
+      for (const mint of [feeMint, delegateMint]) {
+        if (!mint) continue;
+        await program.methods
+          .removeAllowedToken(mint, "Removing Token-2022 test mint")
+          .accountsPartial({
+            admin: admin.publicKey,
+            config: platformConfigPDA,
+          })
+          .rpc();
+      }
+    });
+
+    it("should create a pool token account owned by Token-2022", async () => {
+      const pool = await program.account.fundPool.fetch(feePoolPDA);
+      const poolTokenAccount = await getAccount(
+        provider.connection,
+        pool.tokenAccount,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+
+      expect(poolTokenAccount.mint.toString()).to.equal(feeMint.toString());
+      expect(poolTokenAccount.owner.toString()).to.equal(feePoolPDA.toString());
+    });
+
+    it("should credit the pool with the amount that arrived after the mint's transfer fee", async () => {
+      const amount = new anchor.BN(10000000);
+      const timestamp = getCurrentTimestamp();
+      const [poolTokenAccount] = derivePoolTokenAccountPDA(disasterEventId, feePoolId, program.programId);
+      const [recordPDA] = deriveDonationRecordPDA(feeDonor.publicKey, feePoolPDA, timestamp, program.programId);
+
+      await program.methods
+        .donateToPool(
+          disasterEventId,
+          feePoolId,
+          {
+            amount: amount,
+            message: "Token-2022 donation",
+            isAnonymous: false,
+            donorCommitment: null,
+          },
+          new anchor.BN(timestamp)
+        )
+        .accountsPartial({
+          donationRecord: recordPDA,
+          donor: feeDonor.publicKey,
+          donorTokenAccount: feeDonorTokenAccount,
+          poolTokenAccount: poolTokenAccount,
+          platformFeeRecipient: feeRecipientTokenAccount,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .remainingAccounts([{ pubkey: ngoPDA, isWritable: false, isSigner: false }])
+        .signers([feeDonor])
+        .rpc();
+
+      const pool = await program.account.fundPool.fetch(feePoolPDA);
+      const record = await program.account.donationRecord.fetch(recordPDA);
+      const poolBalance = await getAccount(provider.connection, poolTokenAccount, undefined, TOKEN_2022_PROGRAM_ID);
+
+      expect(pool.totalDeposited.toString()).to.equal(poolBalance.amount.toString());
+      expect(record.netAmount.toString()).to.equal(poolBalance.amount.toString());
+      expect(record.amount.toNumber()).to.equal(amount.toNumber());
+      expect(record.netAmount.add(record.platformFee).toNumber()).to.be.lessThan(amount.toNumber());
+    });
+
+    it("should reject a mint with a permanent delegate", async () => {
+      delegateMint = await createPermanentDelegateMint(provider.connection, admin.payer);
+      await allowToken(delegateMint);
+
+      const poolId = `DELEGATE-POOL-${Date.now()}`;
+
+      await expectError(
+        program.methods
+          .createFundPool(disasterEventId, poolId, createMockFundPoolParams({ name: "Permanent Delegate Pool" }))
+          .accountsPartial({
+            ngoAuthority: ngoAuthority.publicKey,
+            payer: ngoAuthority.publicKey,
+            tokenMint: delegateMint,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+          })
+          .signers([ngoAuthority])
+          .rpc(),
+        "UnsupportedMintExtension"
+      );
+    });
+  });
+
+  describe("native SOL donations", () => {
+    let solPoolId: string;
+    let solPoolPDA: PublicKey;
+    let solPoolTokenAccount: PublicKey;
+    let solFeeRecipient: PublicKey;
+    let solDonor: Keypair;
+    const [solPriceFeedPDA] = derivePriceFeedPDA(NATIVE_MINT, program.programId);
+    const [solPriceOraclePDA] = deriveStandInOraclePDA(NATIVE_MINT, program.programId);
+
+    const setSolUsdOracle = (oracle: PublicKey) =>
+      program.methods
+        .updatePlatformConfig({
+          configParams: createMockUpdateConfigParams({ solUsdOracle: oracle }),
+          reason: "Set SOL/USD oracle",
+          metadata: "{}",
+        })
+        .accountsPartial({
+          admin: admin.publicKey,
+          config: platformConfigPDA,
+        })
+        .rpc();
+
+    const donateLamports = (poolId: string, poolPDA: PublicKey, poolTokenAccount: PublicKey, feeRecipient: PublicKey, timestamp: number) =>
+      program.methods
+        .donateToPool(
+          disasterEventId,
+          poolId,
+          {
+            amount: new anchor.BN(LAMPORTS_PER_SOL / 10),
+            message: "Donated from SOL",
+            isAnonymous: false,
+            donorCommitment: null,
+          },
+          new anchor.BN(timestamp)
+        )
+        .accountsPartial({
+          donationRecord: deriveDonationRecordPDA(solDonor.publicKey, poolPDA, timestamp, program.programId)[0],
+          donor: solDonor.publicKey,
+          donorTokenAccount: null,
+          poolTokenAccount: poolTokenAccount,
+          platformFeeRecipient: feeRecipient,
+          priceFeed: solPriceFeedPDA,
+          priceOracle: solPriceOraclePDA,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .remainingAccounts([{ pubkey: ngoPDA, isWritable: false, isSigner: false }])
+        .signers([solDonor])
+        .rpc();
+
+    before(async () => {
+      const config = await program.account.platformConfig.fetch(platformConfigPDA);
+      if (!config.allowedTokens.some((t: PublicKey) => t.toString() === NATIVE_MINT.toString())) {
+        await program.methods
+          .addAllowedToken(NATIVE_MINT, "Adding wrapped SOL for pool tests")
+          .accountsPartial({
+            admin: admin.publicKey,
+            config: platformConfigPDA,
+          })
+          .rpc();
+      }
+
+      await program.methods
+        .initializeStandInOracle({ price: new anchor.BN(150_000_000), exponent: -6, publishTime: null })
+        .accountsPartial({
+          tokenMint: NATIVE_MINT,
+          admin: admin.publicKey,
+        })
+        .rpc();
+
+      await program.methods
+        .registerPriceFeed(
+          { oracle: solPriceOraclePDA, maxStalenessSeconds: new anchor.BN(300), reason: "Price wrapped SOL" }
+        )
+        .accountsPartial({
+          tokenMint: NATIVE_MINT,
+          admin: admin.publicKey,
+        })
+        .rpc();
+
+      solPoolId = `SOL-POOL-${Date.now()}`;
+      [solPoolPDA] = deriveFundPoolPDA(disasterEventId, solPoolId, program.programId);
+      [solPoolTokenAccount] = derivePoolTokenAccountPDA(disasterEventId, solPoolId, program.programId);
+
+      await program.methods
+        .createFundPool(disasterEventId, solPoolId, createMockFundPoolParams({ name: "SOL Relief Pool" }))
+        .accountsPartial({
+          ngoAuthority: ngoAuthority.publicKey,
+          payer: ngoAuthority.publicKey,
+          tokenMint: NATIVE_MINT,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([ngoAuthority])
+        .rpc();
+
+      solFeeRecipient = (
+        await getOrCreateAssociatedTokenAccount(provider.connection, admin.payer, NATIVE_MINT, config.platformFeeRecipient)
+      ).address;
+
+      solDonor = Keypair.generate();
+      await airdropSOL(provider.connection, solDonor.publicKey);
+    });
+
+    it("should wrap donated lamports into the pool's wSOL account", async () => {
+      const feeBefore = (await getAccount(provider.connection, solFeeRecipient)).amount;
+      const timestamp = getCurrentTimestamp();
+
+      await donateLamports(solPoolId, solPoolPDA, solPoolTokenAccount, solFeeRecipient, timestamp);
+
+      const pool = await program.account.fundPool.fetch(solPoolPDA);
+      const vault = await getAccount(provider.connection, solPoolTokenAccount);
+      const feeAfter = (await getAccount(provider.connection, solFeeRecipient)).amount;
+      const [recordPDA] = deriveDonationRecordPDA(solDonor.publicKey, solPoolPDA, timestamp, program.programId);
+      const record = await program.account.donationRecord.fetch(recordPDA);
+
+      expect(vault.isNative).to.be.true;
+      expect(vault.amount.toString()).to.equal(pool.totalDeposited.toString());
+      expect(record.tokenMint.toString()).to.equal(NATIVE_MINT.toString());
+      expect(record.netAmount.add(record.platformFee).toNumber()).to.equal(LAMPORTS_PER_SOL / 10);
+      expect((feeAfter - feeBefore).toString()).to.equal(record.platformFee.toString());
+    });
+
+    it("should value wrapped SOL with the platform SOL/USD oracle", async () => {
+      await setSolUsdOracle(Keypair.generate().publicKey);
+
+      await expectError(
+        donateLamports(solPoolId, solPoolPDA, solPoolTokenAccount, solFeeRecipient, getCurrentTimestamp()),
+        "OracleAccountMismatch"
+      );
+
+      await setSolUsdOracle(solPriceOraclePDA);
+
+      const poolBefore = await program.account.fundPool.fetch(solPoolPDA);
+      await donateLamports(solPoolId, solPoolPDA, solPoolTokenAccount, solFeeRecipient, getCurrentTimestamp());
+      const poolAfter = await program.account.fundPool.fetch(solPoolPDA);
+
+      expect(poolAfter.totalDepositedUsd.gt(poolBefore.totalDepositedUsd)).to.be.true;
+    });
+
+    it("should fail to donate lamports to a pool that does not hold wrapped SOL", async () => {
+      const [usdcPoolPDA] = deriveFundPoolPDA(disasterEventId, "POOL-001", program.programId);
+      const [usdcPoolTokenAccount] = derivePoolTokenAccountPDA(disasterEventId, "POOL-001", program.programId);
+
+      await expectError(
+        donateLamports("POOL-001", usdcPoolPDA, usdcPoolTokenAccount, platformFeeRecipient, getCurrentTimestamp()),
+        "NativeDonationNotSupported"
+      );
+    });
+  });
+
+  describe("issue_receipt", () => {
+    let receiptPoolId: string;
+    let receiptPoolPDA: PublicKey;
+    let receiptDonor: Keypair;
+    let receiptDonorUsdcAccount: PublicKey;
+    let donationRecordPDA: PublicKey;
+    let anonymousDonationRecordPDA: PublicKey;
+    const anonymousSalt = randomBytes(32);
+
+    const fiscalYear = new Date().getUTCFullYear();
 
-      const amount = new anchor.BN(5000000);
+    async function donate(isAnonymous: boolean): Promise<PublicKey> {
       const timestamp = getCurrentTimestamp();
-      const [poolTokenAccount] = derivePoolTokenAccountPDA(disasterEventId, testPoolId, program.programId);
+      const [poolTokenAccount] = derivePoolTokenAccountPDA(disasterEventId, receiptPoolId, program.programId);
+      const donorCommitment = isAnonymous ? createDonorCommitment(receiptDonor.publicKey, anonymousSalt) : null;
+      const [recordPDA] = deriveDonationRecordPDA(
+        donorCommitment ?? receiptDonor.publicKey,
+        receiptPoolPDA,
+        timestamp,
+        program.programId
+      );
 
       await program.methods
         .donateToPool(
           disasterEventId,
-          testPoolId,
+          receiptPoolId,
           {
-            amount: amount,
-            message: "Another donation",
-            isAnonymous: false,
+            amount: new anchor.BN(10000000),
+            message: "Receipt please",
+            isAnonymous,
+            donorCommitment: donorCommitment && Array.from(donorCommitment),
           },
           new anchor.BN(timestamp)
         )
         .accountsPartial({
-          donor: donorKeypair.publicKey,
-          donorTokenAccount: donorUsdcAccount,
+          donationRecord: recordPDA,
+          donor: receiptDonor.publicKey,
+          donorTokenAccount: receiptDonorUsdcAccount,
           poolTokenAccount: poolTokenAccount,
           platformFeeRecipient: platformFeeRecipient,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
         .remainingAccounts([
           {
@@ -492,11 +1081,178 @@ describe("05 - Fund Pool Management", () => {
             isSigner: false,
           },
         ])
-        .signers([donorKeypair])
+        .signers([receiptDonor])
         .rpc();
 
-      const poolAfter = await program.account.fundPool.fetch(testPoolPDA);
-      expect(poolAfter.donorCount).to.equal(countBefore + 1);
+      return recordPDA;
+    }
+
+    function issueReceipt(
+      recordPDA: PublicKey,
+      params: {
+        receiptNumber: string;
+        fiscalYear: number;
+        donorName: string | null;
+        donorEmail: string | null;
+        donorProof?: { donor: PublicKey; salt: number[] } | null;
+      },
+      authority: Keypair = ngoAuthority
+    ) {
+      return program.methods
+        .issueReceipt(disasterEventId, receiptPoolId, { donorProof: null, ...params })
+        .accountsPartial({
+          donationRecord: recordPDA,
+          ngoAuthority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+    }
+
+    before(async () => {
+      receiptPoolId = `RECEIPT-POOL-${Date.now()}`;
+      [receiptPoolPDA] = deriveFundPoolPDA(disasterEventId, receiptPoolId, program.programId);
+
+      await program.methods
+        .createFundPool(disasterEventId, receiptPoolId, createMockFundPoolParams({ name: "Receipt Test Pool" }))
+        .accountsPartial({
+          ngoAuthority: ngoAuthority.publicKey,
+          payer: ngoAuthority.publicKey,
+          tokenMint: usdcMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([ngoAuthority])
+        .rpc();
+
+      receiptDonor = Keypair.generate();
+      await airdropSOL(provider.connection, receiptDonor.publicKey);
+      receiptDonorUsdcAccount = getAssociatedTokenAddressSync(usdcMint, receiptDonor.publicKey);
+      await createAssociatedTokenAccount(provider.connection, admin.payer, usdcMint, receiptDonor.publicKey);
+      await mintTo(provider.connection, admin.payer, usdcMint, receiptDonorUsdcAccount, admin.payer, 100000000000);
+
+      donationRecordPDA = await donate(false);
+      anonymousDonationRecordPDA = await donate(true);
+    });
+
+    it("should fail when a non-authority tries to issue a receipt", async () => {
+      const stranger = Keypair.generate();
+      await airdropSOL(provider.connection, stranger.publicKey);
+
+      await expectError(
+        issueReceipt(
+          donationRecordPDA,
+          { receiptNumber: "RCPT-0000", fiscalYear, donorName: null, donorEmail: null },
+          stranger
+        ),
+        "UnauthorizedModification"
+      );
+    });
+
+    it("should fail with a fiscal year that does not match the donation", async () => {
+      await expectError(
+        issueReceipt(donationRecordPDA, {
+          receiptNumber: "RCPT-0001",
+          fiscalYear: fiscalYear - 2,
+          donorName: null,
+          donorEmail: null,
+        }),
+        "InvalidFiscalYear"
+      );
+    });
+
+    it("should fail to issue a receipt while the NGO is inactive", async () => {
+      const setNgoActive = (isActive: boolean, reason: string) =>
+        program.methods
+          .updateNgoStatus(ngoAuthority.publicKey, { isActive, reason })
+          .accountsPartial({
+            admin: admin.publicKey,
+            ngo: ngoPDA,
+            config: platformConfigPDA,
+          })
+          .rpc();
+
+      await setNgoActive(false, "Suspended pending audit");
+      try {
+        await expectError(
+          issueReceipt(donationRecordPDA, {
+            receiptNumber: "RCPT-0002",
+            fiscalYear,
+            donorName: null,
+            donorEmail: null,
+          }),
+          "NGONotActive"
+        );
+      } finally {
+        await setNgoActive(true, "Audit cleared");
+      }
+    });
+
+    it("should issue a receipt with the NGO tax ID", async () => {
+      await issueReceipt(donationRecordPDA, {
+        receiptNumber: "RCPT-0001",
+        fiscalYear,
+        donorName: "Jane Donor",
+        donorEmail: "jane@donor.org",
+      });
+
+      const ngo = await program.account.ngo.fetch(ngoPDA);
+      const record = await program.account.donationRecord.fetch(donationRecordPDA);
+      expect(record.receiptSent).to.be.true;
+      expect(record.receiptNumber).to.equal("RCPT-0001");
+      expect(record.receiptTaxId).to.equal(ngo.taxId);
+      expect(record.receiptFiscalYear).to.equal(fiscalYear);
+      expect(record.receiptIssuedAt).to.not.be.null;
+      expect(record.donorName).to.equal("Jane Donor");
+      expect(record.donorEmail).to.equal("jane@donor.org");
+    });
+
+    it("should fail to issue a second receipt for the same donation", async () => {
+      await expectError(
+        issueReceipt(donationRecordPDA, {
+          receiptNumber: "RCPT-0002",
+          fiscalYear,
+          donorName: null,
+          donorEmail: null,
+        }),
+        "ReceiptAlreadyIssued"
+      );
+    });
+
+    it("should not record donor details on an anonymous donation without a donor proof", async () => {
+      await expectError(
+        issueReceipt(anonymousDonationRecordPDA, {
+          receiptNumber: "RCPT-0003",
+          fiscalYear,
+          donorName: "Not Anonymous",
+          donorEmail: null,
+        }),
+        "AnonymousDonorDetails"
+      );
+
+      await expectError(
+        issueReceipt(anonymousDonationRecordPDA, {
+          receiptNumber: "RCPT-0003",
+          fiscalYear,
+          donorName: "Not Anonymous",
+          donorEmail: null,
+          donorProof: { donor: receiptDonor.publicKey, salt: Array.from(randomBytes(32)) },
+        }),
+        "InvalidDonorProof"
+      );
+    });
+
+    it("should record donor details on an anonymous donation once the donor reveals the salt", async () => {
+      await issueReceipt(anonymousDonationRecordPDA, {
+        receiptNumber: "RCPT-0003",
+        fiscalYear,
+        donorName: "Revealed Donor",
+        donorEmail: null,
+        donorProof: { donor: receiptDonor.publicKey, salt: Array.from(anonymousSalt) },
+      });
+
+      const record = await program.account.donationRecord.fetch(anonymousDonationRecordPDA);
+      expect(record.receiptSent).to.be.true;
+      expect(record.donorName).to.equal("Revealed Donor");
+      expect(record.donor.toString()).to.equal(PublicKey.default.toString());
     });
   });
 
@@ -509,14 +1265,14 @@ describe("05 - Fund Pool Management", () => {
       [updatePoolPDA] = deriveFundPoolPDA(disasterEventId, updatePoolId, program.programId);
 
       const params = createMockFundPoolParams({ name: "Update Test Pool" });
-      const timestamp = getCurrentTimestamp();
 
       await program.methods
-        .createFundPool(disasterEventId, updatePoolId, new anchor.BN(timestamp), params)
+        .createFundPool(disasterEventId, updatePoolId, params)
         .accountsPartial({
           ngoAuthority: ngoAuthority.publicKey,
           payer: ngoAuthority.publicKey,
           tokenMint: usdcMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
         .signers([ngoAuthority])
         .rpc();
@@ -529,6 +1285,7 @@ describe("05 - Fund Pool Management", () => {
           eligibilityCriteria: "Updated criteria: Family size >= 5",
           targetAmount: null,
           description: null,
+          claimWindowSeconds: null,
         })
         .accountsPartial({
           authority: ngoAuthority.publicKey,
@@ -547,6 +1304,7 @@ describe("05 - Fund Pool Management", () => {
           eligibilityCriteria: null,
           targetAmount: new anchor.BN(50000000000),
           description: null,
+          claimWindowSeconds: null,
         })
         .accountsPartial({
           authority: ngoAuthority.publicKey,
@@ -565,6 +1323,7 @@ describe("05 - Fund Pool Management", () => {
           eligibilityCriteria: null,
           targetAmount: null,
           description: null,
+          claimWindowSeconds: null,
         })
         .accountsPartial({
           authority: ngoAuthority.publicKey,
@@ -583,6 +1342,7 @@ describe("05 - Fund Pool Management", () => {
           eligibilityCriteria: null,
           targetAmount: null,
           description: null,
+          claimWindowSeconds: null,
         })
         .accountsPartial({
           authority: ngoAuthority.publicKey,
@@ -594,6 +1354,46 @@ describe("05 - Fund Pool Management", () => {
       expect(pool.isActive).to.be.true;
     });
 
+    it("should update pool claim window", async () => {
+      const claimWindow = 14 * 24 * 60 * 60;
+
+      await program.methods
+        .updatePoolConfig(disasterEventId, updatePoolId, {
+          isActive: null,
+          eligibilityCriteria: null,
+          targetAmount: null,
+          description: null,
+          claimWindowSeconds: new anchor.BN(claimWindow),
+        })
+        .accountsPartial({
+          authority: ngoAuthority.publicKey,
+        })
+        .signers([ngoAuthority])
+        .rpc();
+
+      const pool = await program.account.fundPool.fetch(updatePoolPDA);
+      expect(pool.claimWindowSeconds.toNumber()).to.equal(claimWindow);
+    });
+
+    it("should fail to update claim window above platform maximum", async () => {
+      await expectError(
+        program.methods
+          .updatePoolConfig(disasterEventId, updatePoolId, {
+            isActive: null,
+            eligibilityCriteria: null,
+            targetAmount: null,
+            description: null,
+            claimWindowSeconds: new anchor.BN(2 * 365 * 24 * 60 * 60),
+          })
+          .accountsPartial({
+            authority: ngoAuthority.publicKey,
+          })
+          .signers([ngoAuthority])
+          .rpc(),
+        "InvalidClaimWindow"
+      );
+    });
+
     it("should fail when non-authority tries to update", async () => {
       const nonAuthority = Keypair.generate();
       await airdropSOL(provider.connection, nonAuthority.publicKey);
@@ -605,6 +1405,7 @@ describe("05 - Fund Pool Management", () => {
             eligibilityCriteria: null,
             targetAmount: null,
             description: null,
+            claimWindowSeconds: null,
           })
           .accountsPartial({
             authority: nonAuthority.publicKey,
@@ -619,30 +1420,126 @@ describe("05 - Fund Pool Management", () => {
   describe("close_pool", () => {
     let closePoolId: string;
     let closePoolPDA: PublicKey;
+    let closeDonor: Keypair;
+    let closeDonorUsdcAccount: PublicKey;
+    let closeDonationRecordPDA: PublicKey;
+    let closeAnonymousRecordPDA: PublicKey;
+    const closeDonorSalt = randomBytes(32);
 
     before(async () => {
       closePoolId = `CLOSE-POOL-${Date.now()}`;
       [closePoolPDA] = deriveFundPoolPDA(disasterEventId, closePoolId, program.programId);
 
       const params = createMockFundPoolParams({ name: "Close Test Pool" });
-      const timestamp = getCurrentTimestamp();
 
       await program.methods
-        .createFundPool(disasterEventId, closePoolId, new anchor.BN(timestamp), params)
+        .createFundPool(disasterEventId, closePoolId, params)
         .accountsPartial({
           ngoAuthority: ngoAuthority.publicKey,
           payer: ngoAuthority.publicKey,
           tokenMint: usdcMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
         .signers([ngoAuthority])
         .rpc();
+
+      // Donate so there is an undistributed balance to refund on close
+      closeDonor = Keypair.generate();
+      await airdropSOL(provider.connection, closeDonor.publicKey);
+      closeDonorUsdcAccount = getAssociatedTokenAddressSync(usdcMint, closeDonor.publicKey);
+      await createAssociatedTokenAccount(provider.connection, admin.payer, usdcMint, closeDonor.publicKey);
+      await mintTo(provider.connection, admin.payer, usdcMint, closeDonorUsdcAccount, admin.payer, 100000000);
+
+      const [poolTokenAccount] = derivePoolTokenAccountPDA(disasterEventId, closePoolId, program.programId);
+      const donationTimestamp = getCurrentTimestamp();
+      [closeDonationRecordPDA] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("donation"),
+          closeDonor.publicKey.toBuffer(),
+          closePoolPDA.toBuffer(),
+          Buffer.from(new anchor.BN(donationTimestamp).toArray("le", 8)),
+        ],
+        program.programId
+      );
+
+      await program.methods
+        .donateToPool(
+          disasterEventId,
+          closePoolId,
+          {
+            amount: new anchor.BN(10000000),
+            message: "Refundable donation",
+            isAnonymous: false,
+          },
+          new anchor.BN(donationTimestamp)
+        )
+        .accountsPartial({
+          donationRecord: closeDonationRecordPDA,
+          donor: closeDonor.publicKey,
+          donorTokenAccount: closeDonorUsdcAccount,
+          poolTokenAccount: poolTokenAccount,
+          platformFeeRecipient: platformFeeRecipient,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .remainingAccounts([{ pubkey: ngoPDA, isWritable: false, isSigner: false }])
+        .signers([closeDonor])
+        .rpc();
+
+      const anonymousTimestamp = getCurrentTimestamp();
+      const commitment = createDonorCommitment(closeDonor.publicKey, closeDonorSalt);
+      [closeAnonymousRecordPDA] = deriveDonationRecordPDA(
+        commitment,
+        closePoolPDA,
+        anonymousTimestamp,
+        program.programId
+      );
+
+      await program.methods
+        .donateToPool(
+          disasterEventId,
+          closePoolId,
+          {
+            amount: new anchor.BN(10000000),
+            message: "Refundable anonymous donation",
+            isAnonymous: true,
+            donorCommitment: Array.from(commitment),
+          },
+          new anchor.BN(anonymousTimestamp)
+        )
+        .accountsPartial({
+          donationRecord: closeAnonymousRecordPDA,
+          donor: closeDonor.publicKey,
+          donorTokenAccount: closeDonorUsdcAccount,
+          poolTokenAccount: poolTokenAccount,
+          platformFeeRecipient: platformFeeRecipient,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .remainingAccounts([{ pubkey: ngoPDA, isWritable: false, isSigner: false }])
+        .signers([closeDonor])
+        .rpc();
+    });
+
+    it("should fail to close disaster while pools are active", async () => {
+      const disaster = await program.account.disasterEvent.fetch(disasterPDA);
+      expect(disaster.activePoolCount).to.be.greaterThan(0);
+
+
+      await expectError(
+        program.methods
+          .closeDisaster(disasterEventId)
+          .accountsPartial({
+            authority: admin.publicKey,
+          })
+          .rpc(),
+        "DisasterHasActivePools"
+      );
     });
 
     it("should close pool", async () => {
-      const timestamp = getCurrentTimestamp();
+      const disasterBefore = await program.account.disasterEvent.fetch(disasterPDA);
 
       await program.methods
-        .closePool(disasterEventId, closePoolId, new anchor.BN(timestamp))
+        .closePool(disasterEventId, closePoolId)
         .accountsPartial({
           authority: ngoAuthority.publicKey,
         })
@@ -652,14 +1549,109 @@ describe("05 - Fund Pool Management", () => {
       const pool = await program.account.fundPool.fetch(closePoolPDA);
       expect(pool.isActive).to.be.false;
       expect(pool.closedAt).to.not.be.null;
+      expect(pool.donorRefundTotal.toNumber()).to.equal(pool.totalDeposited.toNumber());
+
+      const disasterAfter = await program.account.disasterEvent.fetch(disasterPDA);
+      expect(disasterAfter.activePoolCount).to.equal(disasterBefore.activePoolCount - 1);
     });
 
-    it("should fail to close already closed pool", async () => {
-      const timestamp = getCurrentTimestamp();
+    it("should refund donor their share of the undistributed balance", async () => {
+      const [poolTokenAccount] = derivePoolTokenAccountPDA(disasterEventId, closePoolId, program.programId);
+      const balanceBefore = await provider.connection.getTokenAccountBalance(closeDonorUsdcAccount);
+
+      await program.methods
+        .refundDonation(disasterEventId, closePoolId, null)
+        .accountsPartial({
+          donationRecord: closeDonationRecordPDA,
+          poolTokenAccount: poolTokenAccount,
+          donorTokenAccount: closeDonorUsdcAccount,
+          ngo: ngoPDA,
+          donor: closeDonor.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([closeDonor])
+        .rpc();
+
+      const record = await program.account.donationRecord.fetch(closeDonationRecordPDA);
+      const balanceAfter = await provider.connection.getTokenAccountBalance(closeDonorUsdcAccount);
+
+      expect(record.isRefunded).to.be.true;
+      expect(record.refundedAmount.toNumber()).to.equal(record.netAmount.toNumber());
+      expect(Number(balanceAfter.value.amount) - Number(balanceBefore.value.amount)).to.equal(
+        record.netAmount.toNumber()
+      );
+    });
 
+    it("should fail to refund the same donation twice", async () => {
+      const [poolTokenAccount] = derivePoolTokenAccountPDA(disasterEventId, closePoolId, program.programId);
+
+      await expectError(
+        program.methods
+          .refundDonation(disasterEventId, closePoolId, null)
+          .accountsPartial({
+            donationRecord: closeDonationRecordPDA,
+            poolTokenAccount: poolTokenAccount,
+            donorTokenAccount: closeDonorUsdcAccount,
+            ngo: ngoPDA,
+            donor: closeDonor.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([closeDonor])
+          .rpc(),
+        "DonationAlreadyRefunded"
+      );
+    });
+
+    it("should fail to refund an anonymous donation without the donor salt", async () => {
+      const [poolTokenAccount] = derivePoolTokenAccountPDA(disasterEventId, closePoolId, program.programId);
+
+      await expectError(
+        program.methods
+          .refundDonation(disasterEventId, closePoolId, Array.from(randomBytes(32)))
+          .accountsPartial({
+            donationRecord: closeAnonymousRecordPDA,
+            poolTokenAccount: poolTokenAccount,
+            donorTokenAccount: closeDonorUsdcAccount,
+            ngo: ngoPDA,
+            donor: closeDonor.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([closeDonor])
+          .rpc(),
+        "InvalidAccountOwner"
+      );
+    });
+
+    it("should refund an anonymous donation to the donor who reveals the salt", async () => {
+      const [poolTokenAccount] = derivePoolTokenAccountPDA(disasterEventId, closePoolId, program.programId);
+      const balanceBefore = await provider.connection.getTokenAccountBalance(closeDonorUsdcAccount);
+
+      await program.methods
+        .refundDonation(disasterEventId, closePoolId, Array.from(closeDonorSalt))
+        .accountsPartial({
+          donationRecord: closeAnonymousRecordPDA,
+          poolTokenAccount: poolTokenAccount,
+          donorTokenAccount: closeDonorUsdcAccount,
+          ngo: ngoPDA,
+          donor: closeDonor.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([closeDonor])
+        .rpc();
+
+      const record = await program.account.donationRecord.fetch(closeAnonymousRecordPDA);
+      const balanceAfter = await provider.connection.getTokenAccountBalance(closeDonorUsdcAccount);
+
+      expect(record.isRefunded).to.be.true;
+      expect(Number(balanceAfter.value.amount) - Number(balanceBefore.value.amount)).to.equal(
+        record.netAmount.toNumber()
+      );
+    });
+
+    it("should fail to close already closed pool", async () => {
       await expectError(
         program.methods
-          .closePool(disasterEventId, closePoolId, new anchor.BN(timestamp))
+          .closePool(disasterEventId, closePoolId)
           .accountsPartial({
             authority: ngoAuthority.publicKey,
           })
@@ -684,9 +1676,8 @@ describe("05 - Fund Pool Management", () => {
         .signers([newNgoAuthority])
         .rpc();
 
-      const actionId = getCurrentTimestamp();
       await program.methods
-        .verifyNgo(newNgoAuthority.publicKey, { reason: "Verified" }, new anchor.BN(actionId))
+        .verifyNgo(newNgoAuthority.publicKey, { reason: "Verified" })
         .accountsPartial({
           admin: admin.publicKey,
           ngo: newNgoPDA,
@@ -696,25 +1687,24 @@ describe("05 - Fund Pool Management", () => {
 
       const newPoolId = `CLOSE-AUTH-${Date.now()}`;
       const params = createMockFundPoolParams();
-      const timestamp1 = getCurrentTimestamp();
 
       await program.methods
-        .createFundPool(disasterEventId, newPoolId, new anchor.BN(timestamp1), params)
+        .createFundPool(disasterEventId, newPoolId, params)
         .accountsPartial({
           ngoAuthority: newNgoAuthority.publicKey,
           payer: newNgoAuthority.publicKey,
           tokenMint: usdcMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
         .signers([newNgoAuthority])
         .rpc();
 
       const nonAuthority = Keypair.generate();
       await airdropSOL(provider.connection, nonAuthority.publicKey);
-      const timestamp2 = getCurrentTimestamp();
 
       await expectError(
         program.methods
-          .closePool(disasterEventId, newPoolId, new anchor.BN(timestamp2))
+          .closePool(disasterEventId, newPoolId)
           .accountsPartial({
             authority: nonAuthority.publicKey,
           })
@@ -725,6 +1715,87 @@ describe("05 - Fund Pool Management", () => {
     });
   });
 
+  describe("force_close_disaster", () => {
+    let cascadeDisasterId: string;
+    let cascadeDisasterPDA: PublicKey;
+    let cascadePoolPDA: PublicKey;
+
+    before(async () => {
+      cascadeDisasterId = `CASCADE-DISASTER-${Date.now()}`;
+      [cascadeDisasterPDA] = deriveDisasterPDA(cascadeDisasterId, program.programId);
+
+      await program.methods
+        .initializeDisaster(createMockDisasterParams({ eventId: cascadeDisasterId }))
+        .accountsPartial({
+          authority: admin.publicKey,
+          config: platformConfigPDA,
+        })
+        .rpc();
+
+      // Use a fresh NGO to stay under the pool limit
+      const cascadeNgoAuthority = Keypair.generate();
+      await airdropSOL(provider.connection, cascadeNgoAuthority.publicKey);
+
+      await program.methods
+        .registerNgo(createMockNGOParams({ name: "Cascade Test NGO" }))
+        .accountsPartial({
+          authority: cascadeNgoAuthority.publicKey,
+          config: platformConfigPDA,
+        })
+        .signers([cascadeNgoAuthority])
+        .rpc();
+
+      const cascadePoolId = `CASCADE-POOL-${Date.now()}`;
+      [cascadePoolPDA] = deriveFundPoolPDA(cascadeDisasterId, cascadePoolId, program.programId);
+
+      await program.methods
+        .createFundPool(cascadeDisasterId, cascadePoolId, createMockFundPoolParams())
+        .accountsPartial({
+          ngoAuthority: cascadeNgoAuthority.publicKey,
+          payer: cascadeNgoAuthority.publicKey,
+          tokenMint: usdcMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([cascadeNgoAuthority])
+        .rpc();
+    });
+
+    it("should fail when non-admin tries to force close", async () => {
+      const nonAdmin = Keypair.generate();
+      await airdropSOL(provider.connection, nonAdmin.publicKey);
+
+      await expectError(
+        program.methods
+          .forceCloseDisaster(cascadeDisasterId, { reason: "Emergency shutdown" })
+          .accountsPartial({
+            admin: nonAdmin.publicKey,
+          })
+          .remainingAccounts([{ pubkey: cascadePoolPDA, isWritable: true, isSigner: false }])
+          .signers([nonAdmin])
+          .rpc(),
+        "UnauthorizedAdmin"
+      );
+    });
+
+    it("should close disaster and all of its pools", async () => {
+      await program.methods
+        .forceCloseDisaster(cascadeDisasterId, { reason: "Emergency shutdown" })
+        .accountsPartial({
+          admin: admin.publicKey,
+        })
+        .remainingAccounts([{ pubkey: cascadePoolPDA, isWritable: true, isSigner: false }])
+        .rpc();
+
+      const disaster = await program.account.disasterEvent.fetch(cascadeDisasterPDA);
+      expect(disaster.isActive).to.be.false;
+      expect(disaster.activePoolCount).to.equal(0);
+
+      const pool = await program.account.fundPool.fetch(cascadePoolPDA);
+      expect(pool.isActive).to.be.false;
+      expect(pool.closedAt).to.not.be.null;
+    });
+  });
+
   describe("register_beneficiary_for_pool", () => {
     let regPoolId: string;
     let regPoolPDA: PublicKey;
@@ -750,9 +1821,8 @@ describe("05 - Fund Pool Management", () => {
         .signers([regNgoAuthority])
         .rpc();
 
-      const actionId = getCurrentTimestamp();
       await program.methods
-        .verifyNgo(regNgoAuthority.publicKey, { reason: "Verified" }, new anchor.BN(actionId))
+        .verifyNgo(regNgoAuthority.publicKey, { reason: "Verified" })
         .accountsPartial({
           admin: admin.publicKey,
           ngo: regNgoPDA,
@@ -769,14 +1839,14 @@ describe("05 - Fund Pool Management", () => {
         minimumFamilySize: 3,
         minimumDamageSeverity: 5,
       });
-      const timestamp = getCurrentTimestamp();
 
       await program.methods
-        .createFundPool(disasterEventId, regPoolId, new anchor.BN(timestamp), params)
+        .createFundPool(disasterEventId, regPoolId, params)
         .accountsPartial({
           ngoAuthority: regNgoAuthority.publicKey,
           payer: regNgoAuthority.publicKey,
           tokenMint: usdcMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
         .signers([regNgoAuthority])
         .rpc();
@@ -792,10 +1862,9 @@ describe("05 - Fund Pool Management", () => {
         familySize: 5,
         damageSeverity: 7,
       });
-      const ts1 = getCurrentTimestamp();
 
       await program.methods
-        .registerBeneficiary(ben1Params, new anchor.BN(ts1))
+        .registerBeneficiary(ben1Params)
         .accountsPartial({
           authority: beneficiary1Authority.publicKey,
           fieldWorkerAuthority: fieldWorkerAuthority.publicKey,
@@ -808,10 +1877,9 @@ describe("05 - Fund Pool Management", () => {
 
       // Verify beneficiary1
       for (let i = 0; i < 3; i++) {
-        const ts = getCurrentTimestamp();
         const fw = i === 0 ? fieldWorkerAuthority : i === 1 ? fieldWorker2Authority : fieldWorker3Authority;
         await program.methods
-          .verifyBeneficiary(beneficiary1Authority.publicKey, disasterEventId, new anchor.BN(ts))
+          .verifyBeneficiary(beneficiary1Authority.publicKey, disasterEventId)
           .accountsPartial({ fieldWorkerAuthority: fw.publicKey })
           .signers([fw])
           .rpc();
@@ -828,10 +1896,9 @@ describe("05 - Fund Pool Management", () => {
         familySize: 4,
         damageSeverity: 6,
       });
-      const ts2 = getCurrentTimestamp();
 
       await program.methods
-        .registerBeneficiary(ben2Params, new anchor.BN(ts2))
+        .registerBeneficiary(ben2Params)
         .accountsPartial({
           authority: beneficiary2Authority.publicKey,
           fieldWorkerAuthority: fieldWorkerAuthority.publicKey,
@@ -844,10 +1911,9 @@ describe("05 - Fund Pool Management", () => {
 
       // Verify beneficiary2
       for (let i = 0; i < 3; i++) {
-        const ts = getCurrentTimestamp();
         const fw = i === 0 ? fieldWorkerAuthority : i === 1 ? fieldWorker2Authority : fieldWorker3Authority;
         await program.methods
-          .verifyBeneficiary(beneficiary2Authority.publicKey, disasterEventId, new anchor.BN(ts))
+          .verifyBeneficiary(beneficiary2Authority.publicKey, disasterEventId)
           .accountsPartial({ fieldWorkerAuthority: fw.publicKey })
           .signers([fw])
           .rpc();
@@ -855,14 +1921,11 @@ describe("05 - Fund Pool Management", () => {
     });
 
     it("should register beneficiary for pool", async () => {
-      const timestamp = getCurrentTimestamp();
-
       await program.methods
         .registerBeneficiaryForPool(
           disasterEventId,
           regPoolId,
-          { beneficiaryAuthority: beneficiary1Authority.publicKey },
-          new anchor.BN(timestamp)
+          { beneficiaryAuthority: beneficiary1Authority.publicKey }
         )
         .accountsPartial({
           authority: regNgoAuthority.publicKey,
@@ -877,14 +1940,11 @@ describe("05 - Fund Pool Management", () => {
     });
 
     it("should register second beneficiary with different weight", async () => {
-      const timestamp = getCurrentTimestamp();
-
       await program.methods
         .registerBeneficiaryForPool(
           disasterEventId,
           regPoolId,
-          { beneficiaryAuthority: beneficiary2Authority.publicKey },
-          new anchor.BN(timestamp)
+          { beneficiaryAuthority: beneficiary2Authority.publicKey }
         )
         .accountsPartial({
           authority: regNgoAuthority.publicKey,
@@ -905,10 +1965,9 @@ describe("05 - Fund Pool Management", () => {
         phoneNumber: "+977-9800001003",
         nationalId: "POOL-BEN-UNVERIFIED",
       });
-      const ts1 = getCurrentTimestamp();
 
       await program.methods
-        .registerBeneficiary(benParams, new anchor.BN(ts1))
+        .registerBeneficiary(benParams)
         .accountsPartial({
           authority: unverifiedBenAuthority.publicKey,
           fieldWorkerAuthority: fieldWorkerAuthority.publicKey,
@@ -919,15 +1978,13 @@ describe("05 - Fund Pool Management", () => {
         .signers([fieldWorkerAuthority])
         .rpc();
 
-      const timestamp = getCurrentTimestamp();
 
       await expectError(
         program.methods
           .registerBeneficiaryForPool(
             disasterEventId,
             regPoolId,
-            { beneficiaryAuthority: unverifiedBenAuthority.publicKey },
-            new anchor.BN(timestamp)
+            { beneficiaryAuthority: unverifiedBenAuthority.publicKey }
           )
           .accountsPartial({
             authority: regNgoAuthority.publicKey,
@@ -949,10 +2006,9 @@ describe("05 - Fund Pool Management", () => {
         familySize: 2, // Below minimum
         damageSeverity: 7,
       });
-      const ts1 = getCurrentTimestamp();
 
       await program.methods
-        .registerBeneficiary(benParams, new anchor.BN(ts1))
+        .registerBeneficiary(benParams)
         .accountsPartial({
           authority: ineligibleBenAuthority.publicKey,
           fieldWorkerAuthority: fieldWorkerAuthority.publicKey,
@@ -965,24 +2021,21 @@ describe("05 - Fund Pool Management", () => {
 
       // Verify
       for (let i = 0; i < 3; i++) {
-        const ts = getCurrentTimestamp();
         const fw = i === 0 ? fieldWorkerAuthority : i === 1 ? fieldWorker2Authority : fieldWorker3Authority;
         await program.methods
-          .verifyBeneficiary(ineligibleBenAuthority.publicKey, disasterEventId, new anchor.BN(ts))
+          .verifyBeneficiary(ineligibleBenAuthority.publicKey, disasterEventId)
           .accountsPartial({ fieldWorkerAuthority: fw.publicKey })
           .signers([fw])
           .rpc();
       }
 
-      const timestamp = getCurrentTimestamp();
 
       await expectError(
         program.methods
           .registerBeneficiaryForPool(
             disasterEventId,
             regPoolId,
-            { beneficiaryAuthority: ineligibleBenAuthority.publicKey },
-            new anchor.BN(timestamp)
+            { beneficiaryAuthority: ineligibleBenAuthority.publicKey }
           )
           .accountsPartial({
             authority: regNgoAuthority.publicKey,
@@ -1016,9 +2069,8 @@ describe("05 - Fund Pool Management", () => {
         .signers([lockNgoAuthority])
         .rpc();
 
-      const actionId = getCurrentTimestamp();
       await program.methods
-        .verifyNgo(lockNgoAuthority.publicKey, { reason: "Verified" }, new anchor.BN(actionId))
+        .verifyNgo(lockNgoAuthority.publicKey, { reason: "Verified" })
         .accountsPartial({
           admin: admin.publicKey,
           ngo: lockNgoPDA,
@@ -1030,14 +2082,14 @@ describe("05 - Fund Pool Management", () => {
       [lockPoolPDA] = deriveFundPoolPDA(disasterEventId, lockPoolId, program.programId);
 
       const params = createMockFundPoolParams({ name: "Lock Test Pool" });
-      const timestamp = getCurrentTimestamp();
 
       await program.methods
-        .createFundPool(disasterEventId, lockPoolId, new anchor.BN(timestamp), params)
+        .createFundPool(disasterEventId, lockPoolId, params)
         .accountsPartial({
           ngoAuthority: lockNgoAuthority.publicKey,
           payer: lockNgoAuthority.publicKey,
           tokenMint: usdcMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
         .signers([lockNgoAuthority])
         .rpc();
@@ -1049,10 +2101,9 @@ describe("05 - Fund Pool Management", () => {
         phoneNumber: "+977-9800002001",
         nationalId: "LOCK-BEN-001",
       });
-      const ts1 = getCurrentTimestamp();
 
       await program.methods
-        .registerBeneficiary(benParams, new anchor.BN(ts1))
+        .registerBeneficiary(benParams)
         .accountsPartial({
           authority: benAuthority.publicKey,
           fieldWorkerAuthority: fieldWorkerAuthority.publicKey,
@@ -1065,23 +2116,20 @@ describe("05 - Fund Pool Management", () => {
 
       // Verify
       for (let i = 0; i < 3; i++) {
-        const ts = getCurrentTimestamp();
         const fw = i === 0 ? fieldWorkerAuthority : i === 1 ? fieldWorker2Authority : fieldWorker3Authority;
         await program.methods
-          .verifyBeneficiary(benAuthority.publicKey, disasterEventId, new anchor.BN(ts))
+          .verifyBeneficiary(benAuthority.publicKey, disasterEventId)
           .accountsPartial({ fieldWorkerAuthority: fw.publicKey })
           .signers([fw])
           .rpc();
       }
 
       // Register for pool
-      const ts2 = getCurrentTimestamp();
       await program.methods
         .registerBeneficiaryForPool(
           disasterEventId,
           lockPoolId,
-          { beneficiaryAuthority: benAuthority.publicKey },
-          new anchor.BN(ts2)
+          { beneficiaryAuthority: benAuthority.publicKey }
         )
         .accountsPartial({
           authority: lockNgoAuthority.publicKey,
@@ -1092,10 +2140,8 @@ describe("05 - Fund Pool Management", () => {
     });
 
     it("should lock pool registration", async () => {
-      const timestamp = getCurrentTimestamp();
-
       await program.methods
-        .lockPoolRegistration(disasterEventId, lockPoolId, new anchor.BN(timestamp))
+        .lockPoolRegistration(disasterEventId, lockPoolId)
         .accountsPartial({
           authority: lockNgoAuthority.publicKey,
         })
@@ -1113,10 +2159,9 @@ describe("05 - Fund Pool Management", () => {
         phoneNumber: "+977-9800002002",
         nationalId: "LOCK-BEN-002",
       });
-      const ts1 = getCurrentTimestamp();
 
       await program.methods
-        .registerBeneficiary(benParams, new anchor.BN(ts1))
+        .registerBeneficiary(benParams)
         .accountsPartial({
           authority: benAuthority.publicKey,
           fieldWorkerAuthority: fieldWorkerAuthority.publicKey,
@@ -1129,24 +2174,21 @@ describe("05 - Fund Pool Management", () => {
 
       // Verify
       for (let i = 0; i < 3; i++) {
-        const ts = getCurrentTimestamp();
         const fw = i === 0 ? fieldWorkerAuthority : i === 1 ? fieldWorker2Authority : fieldWorker3Authority;
         await program.methods
-          .verifyBeneficiary(benAuthority.publicKey, disasterEventId, new anchor.BN(ts))
+          .verifyBeneficiary(benAuthority.publicKey, disasterEventId)
           .accountsPartial({ fieldWorkerAuthority: fw.publicKey })
           .signers([fw])
           .rpc();
       }
 
-      const timestamp = getCurrentTimestamp();
 
       await expectError(
         program.methods
           .registerBeneficiaryForPool(
             disasterEventId,
             lockPoolId,
-            { beneficiaryAuthority: benAuthority.publicKey },
-            new anchor.BN(timestamp)
+            { beneficiaryAuthority: benAuthority.publicKey }
           )
           .accountsPartial({
             authority: lockNgoAuthority.publicKey,
@@ -1159,11 +2201,9 @@ describe("05 - Fund Pool Management", () => {
     });
 
     it("should fail to lock already locked pool", async () => {
-      const timestamp = getCurrentTimestamp();
-
       await expectError(
         program.methods
-          .lockPoolRegistration(disasterEventId, lockPoolId, new anchor.BN(timestamp))
+          .lockPoolRegistration(disasterEventId, lockPoolId)
           .accountsPartial({
             authority: lockNgoAuthority.publicKey,
           })
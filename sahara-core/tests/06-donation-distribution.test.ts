@@ -2,7 +2,7 @@ import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { expect } from "chai";
 import { Keypair, PublicKey } from "@solana/web3.js";
-import { getAssociatedTokenAddressSync, createAssociatedTokenAccount, mintTo } from "@solana/spl-token";
+import { TOKEN_PROGRAM_ID, getAssociatedTokenAddressSync, createAssociatedTokenAccount, mintTo } from "@solana/spl-token";
 import { SaharasolCore } from "../target/types/saharasol_core";
 import {
   derivePlatformConfigPDA,
@@ -13,7 +13,15 @@ import {
   deriveFundPoolPDA,
   derivePoolTokenAccountPDA,
   deriveDistributionPDA,
+  derivePoolRegistrationPDA,
+  derivePhoneRegistryPDA,
+  deriveActivityArchivePDA,
+  deriveActivityLogPDA,
+  deriveTokenStatsPDA,
+  deriveDisasterTokenStatsPDA,
+  deriveDonationRecordPDA,
   airdropSOL,
+  fetchEvents,
   getCurrentTimestamp,
 } from "./helpers/test-utils";
 import {
@@ -22,6 +30,7 @@ import {
   createMockFieldWorkerParams,
   createMockBeneficiaryParams,
   createMockFundPoolParams,
+  createMockUpdateConfigParams,
 } from "./helpers/mock-data";
 import { expectError } from "./helpers/assertions";
 
@@ -70,9 +79,8 @@ describe("06 - Donation & Distribution", () => {
       .signers([ngoAuthority])
       .rpc();
 
-    const actionId = getCurrentTimestamp();
     await program.methods
-      .verifyNgo(ngoAuthority.publicKey, { reason: "Verified" }, new anchor.BN(actionId))
+      .verifyNgo(ngoAuthority.publicKey, { reason: "Verified" })
       .accountsPartial({
         admin: admin.publicKey,
         ngo: ngoPDA,
@@ -137,10 +145,9 @@ describe("06 - Donation & Distribution", () => {
     [disasterPDA] = deriveDisasterPDA(disasterEventId, program.programId);
 
     const disasterParams = createMockDisasterParams({ eventId: disasterEventId });
-    const timestamp = getCurrentTimestamp();
 
     await program.methods
-      .initializeDisaster(disasterParams, new anchor.BN(timestamp))
+      .initializeDisaster(disasterParams)
       .accountsPartial({
         authority: admin.publicKey,
         config: platformConfigPDA,
@@ -158,10 +165,9 @@ describe("06 - Donation & Distribution", () => {
       familySize: 5,
       damageSeverity: 8,
     });
-    const ts1 = getCurrentTimestamp();
 
     await program.methods
-      .registerBeneficiary(benParams, new anchor.BN(ts1))
+      .registerBeneficiary(benParams)
       .accountsPartial({
         authority: beneficiaryAuthority.publicKey,
         fieldWorkerAuthority: fieldWorkerAuthority.publicKey,
@@ -174,10 +180,9 @@ describe("06 - Donation & Distribution", () => {
 
     // Verify beneficiary
     for (let i = 0; i < 3; i++) {
-      const ts = getCurrentTimestamp();
       const fw = i === 0 ? fieldWorkerAuthority : i === 1 ? fieldWorker2Authority : fieldWorker3Authority;
       await program.methods
-        .verifyBeneficiary(beneficiaryAuthority.publicKey, disasterEventId, new anchor.BN(ts))
+        .verifyBeneficiary(beneficiaryAuthority.publicKey, disasterEventId)
         .accountsPartial({ fieldWorkerAuthority: fw.publicKey })
         .signers([fw])
         .rpc();
@@ -224,10 +229,15 @@ describe("06 - Donation & Distribution", () => {
           new anchor.BN(timestamp)
         )
         .accountsPartial({
+          donationRecord: deriveDonationRecordPDA(donorKeypair.publicKey, beneficiaryPDA, timestamp, program.programId)[0],
           donor: donorKeypair.publicKey,
           donorTokenAccount: donorUsdcAccount,
           beneficiaryTokenAccount: beneficiaryUsdcAccount,
           platformFeeRecipient: platformFeeRecipient,
+          tokenStats: deriveTokenStatsPDA(usdcMint, program.programId)[0],
+          disasterTokenStats: deriveDisasterTokenStatsPDA(disasterEventId, usdcMint, program.programId)[0],
+          tokenMint: usdcMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
         .signers([donorKeypair])
         .rpc();
@@ -236,6 +246,46 @@ describe("06 - Donation & Distribution", () => {
       expect(benAfter.totalReceived.toNumber()).to.be.greaterThan(receivedBefore);
     });
 
+    it("should emit a DonationMade event", async () => {
+      const amount = new anchor.BN(5000000);
+
+      const timestamp = getCurrentTimestamp();
+
+      const signature = await program.methods
+        .donateDirect(
+          beneficiaryAuthority.publicKey,
+          disasterEventId,
+          {
+            amount: amount,
+            message: "Event check",
+            isAnonymous: false,
+          },
+          new anchor.BN(timestamp)
+        )
+        .accountsPartial({
+          donationRecord: deriveDonationRecordPDA(donorKeypair.publicKey, beneficiaryPDA, timestamp, program.programId)[0],
+          donor: donorKeypair.publicKey,
+          donorTokenAccount: donorUsdcAccount,
+          beneficiaryTokenAccount: beneficiaryUsdcAccount,
+          platformFeeRecipient: platformFeeRecipient,
+          tokenStats: deriveTokenStatsPDA(usdcMint, program.programId)[0],
+          disasterTokenStats: deriveDisasterTokenStatsPDA(disasterEventId, usdcMint, program.programId)[0],
+          tokenMint: usdcMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([donorKeypair])
+        .rpc();
+
+      const events = await fetchEvents(program, signature);
+      const donation = events.find((e) => e.name === "DonationMade");
+      expect(donation).to.not.be.undefined;
+      expect(donation!.data.donor.toString()).to.equal(donorKeypair.publicKey.toString());
+      expect(donation!.data.recipient.toString()).to.equal(beneficiaryPDA.toString());
+      expect(donation!.data.disasterId).to.equal(disasterEventId);
+      expect(donation!.data.amount.toNumber()).to.equal(amount.toNumber());
+      expect(donation!.data.netAmount.add(donation!.data.platformFee).toNumber()).to.equal(amount.toNumber());
+    });
+
     it("should fail with donation below minimum", async () => {
       const config = await program.account.platformConfig.fetch(platformConfigPDA);
       const amount = config.minDonationAmount.sub(new anchor.BN(1));
@@ -254,10 +304,15 @@ describe("06 - Donation & Distribution", () => {
             new anchor.BN(timestamp)
           )
           .accountsPartial({
+            donationRecord: deriveDonationRecordPDA(donorKeypair.publicKey, beneficiaryPDA, timestamp, program.programId)[0],
             donor: donorKeypair.publicKey,
             donorTokenAccount: donorUsdcAccount,
             beneficiaryTokenAccount: beneficiaryUsdcAccount,
             platformFeeRecipient: platformFeeRecipient,
+            tokenStats: deriveTokenStatsPDA(usdcMint, program.programId)[0],
+            disasterTokenStats: deriveDisasterTokenStatsPDA(disasterEventId, usdcMint, program.programId)[0],
+            tokenMint: usdcMint,
+            tokenProgram: TOKEN_PROGRAM_ID,
           })
           .signers([donorKeypair])
           .rpc(),
@@ -272,10 +327,9 @@ describe("06 - Donation & Distribution", () => {
         phoneNumber: "+977-9800003002",
         nationalId: "DONATION-UNVERIFIED",
       });
-      const ts1 = getCurrentTimestamp();
 
       await program.methods
-        .registerBeneficiary(benParams, new anchor.BN(ts1))
+        .registerBeneficiary(benParams)
         .accountsPartial({
           authority: unverifiedBenAuthority.publicKey,
           fieldWorkerAuthority: fieldWorkerAuthority.publicKey,
@@ -286,6 +340,7 @@ describe("06 - Donation & Distribution", () => {
         .signers([fieldWorkerAuthority])
         .rpc();
 
+      const [unverifiedBenPDA] = deriveBeneficiaryPDA(unverifiedBenAuthority.publicKey, disasterEventId, program.programId);
       const unverifiedUsdcAccount = getAssociatedTokenAddressSync(usdcMint, unverifiedBenAuthority.publicKey);
       await createAssociatedTokenAccount(
         provider.connection,
@@ -310,10 +365,15 @@ describe("06 - Donation & Distribution", () => {
             new anchor.BN(timestamp)
           )
           .accountsPartial({
+            donationRecord: deriveDonationRecordPDA(donorKeypair.publicKey, unverifiedBenPDA, timestamp, program.programId)[0],
             donor: donorKeypair.publicKey,
             donorTokenAccount: donorUsdcAccount,
             beneficiaryTokenAccount: unverifiedUsdcAccount,
             platformFeeRecipient: platformFeeRecipient,
+            tokenStats: deriveTokenStatsPDA(usdcMint, program.programId)[0],
+            disasterTokenStats: deriveDisasterTokenStatsPDA(disasterEventId, usdcMint, program.programId)[0],
+            tokenMint: usdcMint,
+            tokenProgram: TOKEN_PROGRAM_ID,
           })
           .signers([donorKeypair])
           .rpc(),
@@ -340,10 +400,15 @@ describe("06 - Donation & Distribution", () => {
           new anchor.BN(timestamp)
         )
         .accountsPartial({
+          donationRecord: deriveDonationRecordPDA(donorKeypair.publicKey, beneficiaryPDA, timestamp, program.programId)[0],
           donor: donorKeypair.publicKey,
           donorTokenAccount: donorUsdcAccount,
           beneficiaryTokenAccount: beneficiaryUsdcAccount,
           platformFeeRecipient: platformFeeRecipient,
+          tokenStats: deriveTokenStatsPDA(usdcMint, program.programId)[0],
+          disasterTokenStats: deriveDisasterTokenStatsPDA(disasterEventId, usdcMint, program.programId)[0],
+          tokenMint: usdcMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
         .signers([donorKeypair])
         .rpc();
@@ -351,6 +416,60 @@ describe("06 - Donation & Distribution", () => {
       const disasterAfter = await program.account.disasterEvent.fetch(disasterPDA);
       expect(disasterAfter.totalAidDistributed.toNumber()).to.be.greaterThan(aidBefore);
     });
+
+    it("should track direct donations in per-mint token stats", async () => {
+      const [tokenStatsPDA] = deriveTokenStatsPDA(usdcMint, program.programId);
+      const [disasterTokenStatsPDA] = deriveDisasterTokenStatsPDA(disasterEventId, usdcMint, program.programId);
+      const statsBefore = await program.account.tokenStats.fetch(tokenStatsPDA);
+      const disasterStatsBefore = await program.account.tokenStats.fetch(disasterTokenStatsPDA);
+
+      const amount = new anchor.BN(5000000);
+
+      const timestamp = getCurrentTimestamp();
+
+      const signature = await program.methods
+        .donateDirect(
+          beneficiaryAuthority.publicKey,
+          disasterEventId,
+          {
+            amount: amount,
+            message: "Stats donation",
+            isAnonymous: false,
+          },
+          new anchor.BN(timestamp)
+        )
+        .accountsPartial({
+          donationRecord: deriveDonationRecordPDA(donorKeypair.publicKey, beneficiaryPDA, timestamp, program.programId)[0],
+          donor: donorKeypair.publicKey,
+          donorTokenAccount: donorUsdcAccount,
+          beneficiaryTokenAccount: beneficiaryUsdcAccount,
+          platformFeeRecipient: platformFeeRecipient,
+          tokenStats: tokenStatsPDA,
+          disasterTokenStats: disasterTokenStatsPDA,
+          tokenMint: usdcMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([donorKeypair])
+        .rpc();
+
+      const events = await fetchEvents(program, signature);
+      const donation = events.find((e) => e.name === "DonationMade")!;
+      const netAmount = donation.data.netAmount.toNumber();
+      const platformFee = donation.data.platformFee.toNumber();
+
+      const statsAfter = await program.account.tokenStats.fetch(tokenStatsPDA);
+      expect(statsAfter.tokenMint.toString()).to.equal(usdcMint.toString());
+      expect(statsAfter.disasterId).to.be.null;
+      expect(statsAfter.totalDonations.sub(statsBefore.totalDonations).toNumber()).to.equal(netAmount);
+      expect(statsAfter.totalFeesCollected.sub(statsBefore.totalFeesCollected).toNumber()).to.equal(platformFee);
+      expect(statsAfter.totalAidDistributed.sub(statsBefore.totalAidDistributed).toNumber()).to.equal(netAmount);
+      expect(statsAfter.donationCount.sub(statsBefore.donationCount).toNumber()).to.equal(1);
+
+      const disasterStatsAfter = await program.account.tokenStats.fetch(disasterTokenStatsPDA);
+      expect(disasterStatsAfter.disasterId).to.equal(disasterEventId);
+      expect(disasterStatsAfter.totalDonations.sub(disasterStatsBefore.totalDonations).toNumber()).to.equal(netAmount);
+      expect(disasterStatsAfter.distributionCount.sub(disasterStatsBefore.distributionCount).toNumber()).to.equal(1);
+    });
   });
 
   describe("distribute_from_pool & claim_distribution", () => {
@@ -376,26 +495,24 @@ describe("06 - Donation & Distribution", () => {
         distributionPercentageLocked: 40,
         timeLockDuration: new anchor.BN(7 * 24 * 60 * 60), // 7 days
       });
-      const timestamp = getCurrentTimestamp();
 
       await program.methods
-        .createFundPool(disasterEventId, poolId, new anchor.BN(timestamp), params)
+        .createFundPool(disasterEventId, poolId, params)
         .accountsPartial({
           ngoAuthority: ngoAuthority.publicKey,
           payer: ngoAuthority.publicKey,
           tokenMint: usdcMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
         .signers([ngoAuthority])
         .rpc();
 
       // Register beneficiary1 for pool
-      const ts1 = getCurrentTimestamp();
       await program.methods
         .registerBeneficiaryForPool(
           disasterEventId,
           poolId,
-          { beneficiaryAuthority: beneficiaryAuthority.publicKey },
-          new anchor.BN(ts1)
+          { beneficiaryAuthority: beneficiaryAuthority.publicKey }
         )
         .accountsPartial({
           authority: ngoAuthority.publicKey,
@@ -415,10 +532,9 @@ describe("06 - Donation & Distribution", () => {
         familySize: 3,
         damageSeverity: 6,
       });
-      const ts2 = getCurrentTimestamp();
 
       await program.methods
-        .registerBeneficiary(ben2Params, new anchor.BN(ts2))
+        .registerBeneficiary(ben2Params)
         .accountsPartial({
           authority: beneficiary2Authority.publicKey,
           fieldWorkerAuthority: fieldWorkerAuthority.publicKey,
@@ -431,10 +547,9 @@ describe("06 - Donation & Distribution", () => {
 
       // Verify beneficiary2
       for (let i = 0; i < 3; i++) {
-        const ts = getCurrentTimestamp();
         const fw = i === 0 ? fieldWorkerAuthority : i === 1 ? fieldWorker2Authority : fieldWorker3Authority;
         await program.methods
-          .verifyBeneficiary(beneficiary2Authority.publicKey, disasterEventId, new anchor.BN(ts))
+          .verifyBeneficiary(beneficiary2Authority.publicKey, disasterEventId)
           .accountsPartial({ fieldWorkerAuthority: fw.publicKey })
           .signers([fw])
           .rpc();
@@ -449,13 +564,11 @@ describe("06 - Donation & Distribution", () => {
       );
 
       // Register beneficiary2 for pool
-      const ts3 = getCurrentTimestamp();
       await program.methods
         .registerBeneficiaryForPool(
           disasterEventId,
           poolId,
-          { beneficiaryAuthority: beneficiary2Authority.publicKey },
-          new anchor.BN(ts3)
+          { beneficiaryAuthority: beneficiary2Authority.publicKey }
         )
         .accountsPartial({
           authority: ngoAuthority.publicKey,
@@ -465,9 +578,8 @@ describe("06 - Donation & Distribution", () => {
         .rpc();
 
       // Lock pool registration
-      const ts4 = getCurrentTimestamp();
       await program.methods
-        .lockPoolRegistration(disasterEventId, poolId, new anchor.BN(ts4))
+        .lockPoolRegistration(disasterEventId, poolId)
         .accountsPartial({
           authority: ngoAuthority.publicKey,
         })
@@ -496,10 +608,12 @@ describe("06 - Donation & Distribution", () => {
           new anchor.BN(ts5)
         )
         .accountsPartial({
+          donationRecord: deriveDonationRecordPDA(donorKeypair.publicKey, poolPDA, ts5, program.programId)[0],
           donor: donorKeypair.publicKey,
           donorTokenAccount: donorUsdcAccount,
           poolTokenAccount: poolTokenAccount,
           platformFeeRecipient: platformFeeRecipient,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
         .remainingAccounts([{ pubkey: ngoPDA, isWritable: false, isSigner: false }])
         .signers([donorKeypair])
@@ -534,14 +648,14 @@ describe("06 - Donation & Distribution", () => {
       // Create a new pool without locking
       const newPoolId = `UNLOCKED-POOL-${Date.now()}`;
       const params = createMockFundPoolParams({ name: "Unlocked Pool" });
-      const ts1 = getCurrentTimestamp();
 
       await program.methods
-        .createFundPool(disasterEventId, newPoolId, new anchor.BN(ts1), params)
+        .createFundPool(disasterEventId, newPoolId, params)
         .accountsPartial({
           ngoAuthority: ngoAuthority.publicKey,
           payer: ngoAuthority.publicKey,
           tokenMint: usdcMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
         .signers([ngoAuthority])
         .rpc();
@@ -549,13 +663,11 @@ describe("06 - Donation & Distribution", () => {
       const [newPoolPDA] = deriveFundPoolPDA(disasterEventId, newPoolId, program.programId);
 
       // Register beneficiary
-      const ts2 = getCurrentTimestamp();
       await program.methods
         .registerBeneficiaryForPool(
           disasterEventId,
           newPoolId,
-          { beneficiaryAuthority: beneficiaryAuthority.publicKey },
-          new anchor.BN(ts2)
+          { beneficiaryAuthority: beneficiaryAuthority.publicKey }
         )
         .accountsPartial({
           authority: ngoAuthority.publicKey,
@@ -584,17 +696,20 @@ describe("06 - Donation & Distribution", () => {
       await airdropSOL(provider.connection, beneficiaryAuthority.publicKey);
 
       const [distributionPDA] = deriveDistributionPDA(beneficiaryAuthority.publicKey, poolPDA, program.programId);
-      const timestamp = getCurrentTimestamp();
 
       const distBefore = await program.account.distribution.fetch(distributionPDA);
       const immediateAmount = distBefore.amountImmediate.toNumber();
 
+      const [disasterTokenStatsPDA] = deriveDisasterTokenStatsPDA(disasterEventId, usdcMint, program.programId);
+      const statsBefore = await program.account.tokenStats.fetch(disasterTokenStatsPDA);
+
       await program.methods
-        .claimDistribution(disasterEventId, poolId, new anchor.BN(timestamp))
+        .claimDistribution(disasterEventId, poolId)
         .accountsPartial({
           beneficiaryAuthority: beneficiaryAuthority.publicKey,
           beneficiaryTokenAccount: beneficiaryUsdcAccount,
           poolTokenAccount: poolTokenAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
         .signers([beneficiaryAuthority])
         .rpc();
@@ -604,19 +719,54 @@ describe("06 - Donation & Distribution", () => {
       expect(distAfter.claimedAt).to.not.be.null;
       expect(distAfter.lockedClaimedAt).to.be.null; // Locked not claimed yet
       expect(distAfter.isFullyClaimed).to.be.false; // Still has locked portion
+
+      const statsAfter = await program.account.tokenStats.fetch(disasterTokenStatsPDA);
+      expect(statsAfter.totalAidDistributed.sub(statsBefore.totalAidDistributed).toNumber()).to.equal(immediateAmount);
+      expect(statsAfter.distributionCount.sub(statsBefore.distributionCount).toNumber()).to.equal(1);
+    });
+
+    it("should emit a FundsClaimed event when claiming", async () => {
+      const [distributionPDA] = deriveDistributionPDA(beneficiary2Authority.publicKey, poolPDA, program.programId);
+
+      await program.methods
+        .distributeFromPool(disasterEventId, poolId, {
+          beneficiaryAuthority: beneficiary2Authority.publicKey,
+        })
+        .accountsPartial({ authority: ngoAuthority.publicKey })
+        .signers([ngoAuthority])
+        .rpc();
+      await airdropSOL(provider.connection, beneficiary2Authority.publicKey);
+
+      const signature = await program.methods
+        .claimDistribution(disasterEventId, poolId)
+        .accountsPartial({
+          beneficiaryAuthority: beneficiary2Authority.publicKey,
+          beneficiaryTokenAccount: beneficiary2UsdcAccount,
+          poolTokenAccount: poolTokenAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([beneficiary2Authority])
+        .rpc();
+
+      const events = await fetchEvents(program, signature);
+      const claimed = events.filter((e) => e.name === "FundsClaimed");
+      expect(claimed).to.have.lengthOf(1);
+      expect(claimed[0].data.distribution.toString()).to.equal(distributionPDA.toString());
+      expect(claimed[0].data.beneficiary.toString()).to.equal(beneficiary2PDA.toString());
+      expect(claimed[0].data.amount.toNumber()).to.be.greaterThan(0);
     });
 
     it("should fail to claim locked distribution before unlock time", async () => {
       const [distributionPDA] = deriveDistributionPDA(beneficiaryAuthority.publicKey, poolPDA, program.programId);
-      const timestamp = getCurrentTimestamp();
 
       await expectError(
         program.methods
-          .claimDistribution(disasterEventId, poolId, new anchor.BN(timestamp))
+          .claimDistribution(disasterEventId, poolId)
           .accountsPartial({
             beneficiaryAuthority: beneficiaryAuthority.publicKey,
             beneficiaryTokenAccount: beneficiaryUsdcAccount,
             poolTokenAccount: poolTokenAccount,
+            tokenProgram: TOKEN_PROGRAM_ID,
           })
           .signers([beneficiaryAuthority])
           .rpc(),
@@ -672,10 +822,9 @@ describe("06 - Donation & Distribution", () => {
           familySize: beneficiaries[i].familySize,
           damageSeverity: beneficiaries[i].damageSeverity,
         });
-        const ts1 = getCurrentTimestamp();
 
         await program.methods
-          .registerBeneficiary(benParams, new anchor.BN(ts1))
+          .registerBeneficiary(benParams)
           .accountsPartial({
             authority: benAuthority.publicKey,
             fieldWorkerAuthority: fieldWorkerAuthority.publicKey,
@@ -688,10 +837,9 @@ describe("06 - Donation & Distribution", () => {
 
         // Verify
         for (let j = 0; j < 3; j++) {
-          const ts = getCurrentTimestamp();
           const fw = j === 0 ? fieldWorkerAuthority : j === 1 ? fieldWorker2Authority : fieldWorker3Authority;
           await program.methods
-            .verifyBeneficiary(benAuthority.publicKey, disasterEventId, new anchor.BN(ts))
+            .verifyBeneficiary(benAuthority.publicKey, disasterEventId)
             .accountsPartial({ fieldWorkerAuthority: fw.publicKey })
             .signers([fw])
             .rpc();
@@ -715,23 +863,22 @@ describe("06 - Donation & Distribution", () => {
         distributionPercentageImmediate: 100,
         distributionPercentageLocked: 0,
       });
-      const ts1 = getCurrentTimestamp();
 
       await program.methods
-        .createFundPool(disasterEventId, poolId, new anchor.BN(ts1), params)
+        .createFundPool(disasterEventId, poolId, params)
         .accountsPartial({
           ngoAuthority: ngoAuthority.publicKey,
           payer: ngoAuthority.publicKey,
           tokenMint: usdcMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
         .signers([ngoAuthority])
         .rpc();
 
       // Register all 3 beneficiaries
       for (const benAuth of [testBen1Authority, testBen2Authority, testBen3Authority]) {
-        const ts = getCurrentTimestamp();
         await program.methods
-          .registerBeneficiaryForPool(disasterEventId, poolId, { beneficiaryAuthority: benAuth.publicKey }, new anchor.BN(ts))
+          .registerBeneficiaryForPool(disasterEventId, poolId, { beneficiaryAuthority: benAuth.publicKey })
           .accountsPartial({
             authority: ngoAuthority.publicKey,
             payer: ngoAuthority.publicKey,
@@ -741,9 +888,8 @@ describe("06 - Donation & Distribution", () => {
       }
 
       // Lock and donate
-      const ts2 = getCurrentTimestamp();
       await program.methods
-        .lockPoolRegistration(disasterEventId, poolId, new anchor.BN(ts2))
+        .lockPoolRegistration(disasterEventId, poolId)
         .accountsPartial({ authority: ngoAuthority.publicKey })
         .signers([ngoAuthority])
         .rpc();
@@ -759,13 +905,15 @@ describe("06 - Donation & Distribution", () => {
       await program.methods
         .donateToPool(disasterEventId, poolId, { amount: new anchor.BN(90000000), message: "Equal test", isAnonymous: false }, new anchor.BN(ts3))
         .accountsPartial({
-          donor: donorKeypair.publicKey,
-          donorTokenAccount: donorUsdcAccount,
+          donationRecord: deriveDonationRecordPDA(expiryDonor.publicKey, poolPDA, ts3, program.programId)[0],
+          donor: expiryDonor.publicKey,
+          donorTokenAccount: expiryDonorUsdcAccount,
           poolTokenAccount: poolTokenAccount,
           platformFeeRecipient: platformFeeRecipient,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
         .remainingAccounts([{ pubkey: ngoPDA, isWritable: false, isSigner: false }])
-        .signers([donorKeypair])
+        .signers([expiryDonor])
         .rpc();
 
       // Distribute to all
@@ -806,23 +954,22 @@ describe("06 - Donation & Distribution", () => {
         distributionPercentageImmediate: 100,
         distributionPercentageLocked: 0,
       });
-      const ts1 = getCurrentTimestamp();
 
       await program.methods
-        .createFundPool(disasterEventId, poolId, new anchor.BN(ts1), params)
+        .createFundPool(disasterEventId, poolId, params)
         .accountsPartial({
           ngoAuthority: ngoAuthority.publicKey,
           payer: ngoAuthority.publicKey,
           tokenMint: usdcMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
         .signers([ngoAuthority])
         .rpc();
 
       // Register all 3 beneficiaries
       for (const benAuth of [testBen1Authority, testBen2Authority, testBen3Authority]) {
-        const ts = getCurrentTimestamp();
         await program.methods
-          .registerBeneficiaryForPool(disasterEventId, poolId, { beneficiaryAuthority: benAuth.publicKey }, new anchor.BN(ts))
+          .registerBeneficiaryForPool(disasterEventId, poolId, { beneficiaryAuthority: benAuth.publicKey })
           .accountsPartial({
             authority: ngoAuthority.publicKey,
             payer: ngoAuthority.publicKey,
@@ -832,9 +979,8 @@ describe("06 - Donation & Distribution", () => {
       }
 
       // Lock and donate
-      const ts2 = getCurrentTimestamp();
       await program.methods
-        .lockPoolRegistration(disasterEventId, poolId, new anchor.BN(ts2))
+        .lockPoolRegistration(disasterEventId, poolId)
         .accountsPartial({ authority: ngoAuthority.publicKey })
         .signers([ngoAuthority])
         .rpc();
@@ -850,10 +996,12 @@ describe("06 - Donation & Distribution", () => {
       await program.methods
         .donateToPool(disasterEventId, poolId, { amount: new anchor.BN(90000000), message: "Family test", isAnonymous: false }, new anchor.BN(ts3))
         .accountsPartial({
+          donationRecord: deriveDonationRecordPDA(donorKeypair.publicKey, poolPDA, ts3, program.programId)[0],
           donor: donorKeypair.publicKey,
           donorTokenAccount: donorUsdcAccount,
           poolTokenAccount: poolTokenAccount,
           platformFeeRecipient: platformFeeRecipient,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
         .remainingAccounts([{ pubkey: ngoPDA, isWritable: false, isSigner: false }])
         .signers([donorKeypair])
@@ -897,23 +1045,22 @@ describe("06 - Donation & Distribution", () => {
         distributionPercentageImmediate: 100,
         distributionPercentageLocked: 0,
       });
-      const ts1 = getCurrentTimestamp();
 
       await program.methods
-        .createFundPool(disasterEventId, poolId, new anchor.BN(ts1), params)
+        .createFundPool(disasterEventId, poolId, params)
         .accountsPartial({
           ngoAuthority: ngoAuthority.publicKey,
           payer: ngoAuthority.publicKey,
           tokenMint: usdcMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
         .signers([ngoAuthority])
         .rpc();
 
       // Register all 3 beneficiaries
       for (const benAuth of [testBen1Authority, testBen2Authority, testBen3Authority]) {
-        const ts = getCurrentTimestamp();
         await program.methods
-          .registerBeneficiaryForPool(disasterEventId, poolId, { beneficiaryAuthority: benAuth.publicKey }, new anchor.BN(ts))
+          .registerBeneficiaryForPool(disasterEventId, poolId, { beneficiaryAuthority: benAuth.publicKey })
           .accountsPartial({
             authority: ngoAuthority.publicKey,
             payer: ngoAuthority.publicKey,
@@ -923,9 +1070,8 @@ describe("06 - Donation & Distribution", () => {
       }
 
       // Lock and donate
-      const ts2 = getCurrentTimestamp();
       await program.methods
-        .lockPoolRegistration(disasterEventId, poolId, new anchor.BN(ts2))
+        .lockPoolRegistration(disasterEventId, poolId)
         .accountsPartial({ authority: ngoAuthority.publicKey })
         .signers([ngoAuthority])
         .rpc();
@@ -941,10 +1087,12 @@ describe("06 - Donation & Distribution", () => {
       await program.methods
         .donateToPool(disasterEventId, poolId, { amount: new anchor.BN(90000000), message: "Damage test", isAnonymous: false }, new anchor.BN(ts3))
         .accountsPartial({
+          donationRecord: deriveDonationRecordPDA(donorKeypair.publicKey, poolPDA, ts3, program.programId)[0],
           donor: donorKeypair.publicKey,
           donorTokenAccount: donorUsdcAccount,
           poolTokenAccount: poolTokenAccount,
           platformFeeRecipient: platformFeeRecipient,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
         .remainingAccounts([{ pubkey: ngoPDA, isWritable: false, isSigner: false }])
         .signers([donorKeypair])
@@ -984,6 +1132,9 @@ describe("06 - Donation & Distribution", () => {
     let expiredPoolId: string;
     let expiredPoolPDA: PublicKey;
     let expiredBenAuthority: Keypair;
+    let expiryDonor: Keypair;
+    let expiryDonorUsdcAccount: PublicKey;
+    let expiryDonationRecordPDA: PublicKey;
 
     before(async () => {
       // Create pool with short claim deadline for testing
@@ -995,14 +1146,14 @@ describe("06 - Donation & Distribution", () => {
         distributionPercentageImmediate: 100,
         distributionPercentageLocked: 0,
       });
-      const ts1 = getCurrentTimestamp();
 
       await program.methods
-        .createFundPool(disasterEventId, expiredPoolId, new anchor.BN(ts1), params)
+        .createFundPool(disasterEventId, expiredPoolId, params)
         .accountsPartial({
           ngoAuthority: ngoAuthority.publicKey,
           payer: ngoAuthority.publicKey,
           tokenMint: usdcMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
         .signers([ngoAuthority])
         .rpc();
@@ -1020,10 +1171,9 @@ describe("06 - Donation & Distribution", () => {
         phoneNumber: "+977-9800003004",
         nationalId: "EXPIRED-BEN",
       });
-      const ts2 = getCurrentTimestamp();
 
       await program.methods
-        .registerBeneficiary(benParams, new anchor.BN(ts2))
+        .registerBeneficiary(benParams)
         .accountsPartial({
           authority: expiredBenAuthority.publicKey,
           fieldWorkerAuthority: fieldWorkerAuthority.publicKey,
@@ -1036,23 +1186,20 @@ describe("06 - Donation & Distribution", () => {
 
       // Verify
       for (let i = 0; i < 3; i++) {
-        const ts = getCurrentTimestamp();
         const fw = i === 0 ? fieldWorkerAuthority : i === 1 ? fieldWorker2Authority : fieldWorker3Authority;
         await program.methods
-          .verifyBeneficiary(expiredBenAuthority.publicKey, disasterEventId, new anchor.BN(ts))
+          .verifyBeneficiary(expiredBenAuthority.publicKey, disasterEventId)
           .accountsPartial({ fieldWorkerAuthority: fw.publicKey })
           .signers([fw])
           .rpc();
       }
 
       // Register for pool
-      const ts3 = getCurrentTimestamp();
       await program.methods
         .registerBeneficiaryForPool(
           disasterEventId,
           expiredPoolId,
-          { beneficiaryAuthority: expiredBenAuthority.publicKey },
-          new anchor.BN(ts3)
+          { beneficiaryAuthority: expiredBenAuthority.publicKey }
         )
         .accountsPartial({
           authority: ngoAuthority.publicKey,
@@ -1062,9 +1209,8 @@ describe("06 - Donation & Distribution", () => {
         .rpc();
 
       // Lock registration
-      const ts4 = getCurrentTimestamp();
       await program.methods
-        .lockPoolRegistration(disasterEventId, expiredPoolId, new anchor.BN(ts4))
+        .lockPoolRegistration(disasterEventId, expiredPoolId)
         .accountsPartial({
           authority: ngoAuthority.publicKey,
         })
@@ -1072,15 +1218,24 @@ describe("06 - Donation & Distribution", () => {
         .rpc();
 
       // Donate to pool
-      const donorKeypair = Keypair.generate();
-      await airdropSOL(provider.connection, donorKeypair.publicKey);
-      const donorUsdcAccount = getAssociatedTokenAddressSync(usdcMint, donorKeypair.publicKey);
-      await createAssociatedTokenAccount(provider.connection, admin.payer, usdcMint, donorKeypair.publicKey);
-      await mintTo(provider.connection, admin.payer, usdcMint, donorUsdcAccount, admin.payer, 10000000000);
+      expiryDonor = Keypair.generate();
+      await airdropSOL(provider.connection, expiryDonor.publicKey);
+      expiryDonorUsdcAccount = getAssociatedTokenAddressSync(usdcMint, expiryDonor.publicKey);
+      await createAssociatedTokenAccount(provider.connection, admin.payer, usdcMint, expiryDonor.publicKey);
+      await mintTo(provider.connection, admin.payer, usdcMint, expiryDonorUsdcAccount, admin.payer, 10000000000);
 
       const [poolTokenAccount] = derivePoolTokenAccountPDA(disasterEventId, expiredPoolId, program.programId);
       const donateAmount = new anchor.BN(10000000);
       const ts5 = getCurrentTimestamp();
+      [expiryDonationRecordPDA] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("donation"),
+          expiryDonor.publicKey.toBuffer(),
+          expiredPoolPDA.toBuffer(),
+          Buffer.from(new anchor.BN(ts5).toArray("le", 8)),
+        ],
+        program.programId
+      );
 
       await program.methods
         .donateToPool(
@@ -1094,10 +1249,12 @@ describe("06 - Donation & Distribution", () => {
           new anchor.BN(ts5)
         )
         .accountsPartial({
+          donationRecord: deriveDonationRecordPDA(donorKeypair.publicKey, expiredPoolPDA, ts5, program.programId)[0],
           donor: donorKeypair.publicKey,
           donorTokenAccount: donorUsdcAccount,
           poolTokenAccount: poolTokenAccount,
           platformFeeRecipient: platformFeeRecipient,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
         .remainingAccounts([{ pubkey: ngoPDA, isWritable: false, isSigner: false }])
         .signers([donorKeypair])
@@ -1133,5 +1290,893 @@ describe("06 - Donation & Distribution", () => {
       // In production, this would work after the claim_deadline passes
       console.log("  → Skipping: requires time travel to test expiry");
     });
+
+    it("should let a field worker extend the claim deadline", async () => {
+      const [distributionPDA] = deriveDistributionPDA(
+        expiredBenAuthority.publicKey,
+        expiredPoolPDA,
+        program.programId
+      );
+      const before = await program.account.distribution.fetch(distributionPDA);
+      const extension = 14 * 24 * 60 * 60;
+
+      await program.methods
+        .extendClaimDeadline(disasterEventId, expiredPoolId, expiredBenAuthority.publicKey, {
+          extensionSeconds: new anchor.BN(extension),
+          reason: "Beneficiary displaced to relief camp",
+        })
+        .accountsPartial({
+          ngo: ngoPDA,
+          fieldWorkerAuthority: fieldWorkerAuthority.publicKey,
+        })
+        .signers([fieldWorkerAuthority])
+        .rpc();
+
+      const after = await program.account.distribution.fetch(distributionPDA);
+      expect(after.claimDeadline.toNumber()).to.equal(before.claimDeadline.toNumber() + extension);
+      expect(after.deadlineExtension.toNumber()).to.equal(extension);
+      expect(after.notes).to.equal(before.notes);
+    });
+
+    it("should fail to extend claim deadline beyond the maximum window", async () => {
+      await expectError(
+        program.methods
+          .extendClaimDeadline(disasterEventId, expiredPoolId, expiredBenAuthority.publicKey, {
+            extensionSeconds: new anchor.BN(365 * 24 * 60 * 60),
+            reason: "Too long",
+          })
+          .accountsPartial({
+            ngo: ngoPDA,
+            fieldWorkerAuthority: fieldWorkerAuthority.publicKey,
+          })
+          .signers([fieldWorkerAuthority])
+          .rpc(),
+        "ClaimExtensionTooLong"
+      );
+    });
+
+    it("should fail to extend claim deadline by a field worker from another NGO", async () => {
+      const otherNgoAuthority = Keypair.generate();
+      await airdropSOL(provider.connection, otherNgoAuthority.publicKey);
+      const [otherNgoPDA] = deriveNGOPDA(otherNgoAuthority.publicKey, program.programId);
+
+      await program.methods
+        .registerNgo(createMockNGOParams({ name: "Other Extension NGO" }))
+        .accountsPartial({
+          authority: otherNgoAuthority.publicKey,
+          config: platformConfigPDA,
+        })
+        .signers([otherNgoAuthority])
+        .rpc();
+
+      await program.methods
+        .verifyNgo(otherNgoAuthority.publicKey, { reason: "Verified" })
+        .accountsPartial({
+          admin: admin.publicKey,
+          ngo: otherNgoPDA,
+          config: platformConfigPDA,
+        })
+        .rpc();
+
+      const otherWorkerAuthority = Keypair.generate();
+      await airdropSOL(provider.connection, otherWorkerAuthority.publicKey);
+      const [otherWorkerPDA] = deriveFieldWorkerPDA(otherWorkerAuthority.publicKey, program.programId);
+
+      await program.methods
+        .registerFieldWorker(createMockFieldWorkerParams({ name: "Other NGO FW" }))
+        .accountsPartial({
+          fieldWorker: otherWorkerPDA,
+          ngo: otherNgoPDA,
+          config: platformConfigPDA,
+          authority: otherWorkerAuthority.publicKey,
+          ngoAuthority: otherNgoAuthority.publicKey,
+          payer: otherNgoAuthority.publicKey,
+        })
+        .signers([otherNgoAuthority])
+        .rpc();
+
+      await expectError(
+        program.methods
+          .extendClaimDeadline(disasterEventId, expiredPoolId, expiredBenAuthority.publicKey, {
+            extensionSeconds: new anchor.BN(7 * 24 * 60 * 60),
+            reason: "Outside NGO",
+          })
+          .accountsPartial({
+            ngo: ngoPDA,
+            fieldWorkerAuthority: otherWorkerAuthority.publicKey,
+          })
+          .signers([otherWorkerAuthority])
+          .rpc(),
+        "UnauthorizedFieldWorker"
+      );
+    });
+
+    it("should fail to route reclaimed funds when nothing was reclaimed", async () => {
+      const [poolTokenAccount] = derivePoolTokenAccountPDA(disasterEventId, expiredPoolId, program.programId);
+
+      await expectError(
+        program.methods
+          .routeReclaimedFunds(disasterEventId, expiredPoolId, {
+            destination: { redistributionRound: {} },
+          })
+          .accountsPartial({
+            poolTokenAccount: poolTokenAccount,
+            destinationPool: null,
+            destinationPoolTokenAccount: null,
+            authority: ngoAuthority.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([ngoAuthority])
+          .rpc(),
+        "NoReclaimedFunds"
+      );
+    });
+
+    it("should fail to claim redistribution before a round is opened", async () => {
+      await airdropSOL(provider.connection, expiredBenAuthority.publicKey);
+      const benUsdcAccount = getAssociatedTokenAddressSync(usdcMint, expiredBenAuthority.publicKey);
+      await createAssociatedTokenAccount(provider.connection, admin.payer, usdcMint, expiredBenAuthority.publicKey);
+      const [poolTokenAccount] = derivePoolTokenAccountPDA(disasterEventId, expiredPoolId, program.programId);
+
+      await expectError(
+        program.methods
+          .claimRedistribution(disasterEventId, expiredPoolId)
+          .accountsPartial({
+            poolTokenAccount: poolTokenAccount,
+            beneficiaryTokenAccount: benUsdcAccount,
+            beneficiaryAuthority: expiredBenAuthority.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([expiredBenAuthority])
+          .rpc(),
+        "NoRedistributionAvailable"
+      );
+    });
+
+    it("should fail to refund donation when no refunds were routed", async () => {
+      const [poolTokenAccount] = derivePoolTokenAccountPDA(disasterEventId, expiredPoolId, program.programId);
+
+      await expectError(
+        program.methods
+          .refundDonation(disasterEventId, expiredPoolId, null)
+          .accountsPartial({
+            donationRecord: expiryDonationRecordPDA,
+            poolTokenAccount: poolTokenAccount,
+            donorTokenAccount: expiryDonorUsdcAccount,
+            ngo: ngoPDA,
+            donor: expiryDonor.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([expiryDonor])
+          .rpc(),
+        "NoRefundAvailable"
+      );
+    });
+  });
+
+  describe("batch_claim_distributions", () => {
+    const poolIds: string[] = [];
+    let batchBenAuthority: Keypair;
+    let batchBenUsdcAccount: PublicKey;
+
+    before(async () => {
+      batchBenAuthority = Keypair.generate();
+      await airdropSOL(provider.connection, batchBenAuthority.publicKey);
+
+      const benParams = createMockBeneficiaryParams({
+        disasterId: disasterEventId,
+        phoneNumber: "+977-9800003005",
+        nationalId: "BATCH-BEN",
+      });
+
+      await program.methods
+        .registerBeneficiary(benParams)
+        .accountsPartial({
+          authority: batchBenAuthority.publicKey,
+          fieldWorkerAuthority: fieldWorkerAuthority.publicKey,
+          payer: fieldWorkerAuthority.publicKey,
+          config: platformConfigPDA,
+        })
+        .remainingAccounts([{ pubkey: ngoPDA, isWritable: false, isSigner: false }])
+        .signers([fieldWorkerAuthority])
+        .rpc();
+
+      for (let i = 0; i < 3; i++) {
+        const fw = i === 0 ? fieldWorkerAuthority : i === 1 ? fieldWorker2Authority : fieldWorker3Authority;
+        await program.methods
+          .verifyBeneficiary(batchBenAuthority.publicKey, disasterEventId)
+          .accountsPartial({ fieldWorkerAuthority: fw.publicKey })
+          .signers([fw])
+          .rpc();
+      }
+
+      batchBenUsdcAccount = getAssociatedTokenAddressSync(usdcMint, batchBenAuthority.publicKey);
+      await createAssociatedTokenAccount(provider.connection, admin.payer, usdcMint, batchBenAuthority.publicKey);
+
+      const donorKeypair = Keypair.generate();
+      await airdropSOL(provider.connection, donorKeypair.publicKey);
+      const donorUsdcAccount = getAssociatedTokenAddressSync(usdcMint, donorKeypair.publicKey);
+      await createAssociatedTokenAccount(provider.connection, admin.payer, usdcMint, donorKeypair.publicKey);
+      await mintTo(provider.connection, admin.payer, usdcMint, donorUsdcAccount, admin.payer, 10000000000);
+
+      for (let i = 0; i < 2; i++) {
+        const poolId = `BATCH-POOL-${i}-${Date.now()}`;
+        poolIds.push(poolId);
+
+        const params = createMockFundPoolParams({ name: `Batch Claim Pool ${i}` });
+        await program.methods
+          .createFundPool(disasterEventId, poolId, params)
+          .accountsPartial({
+            ngoAuthority: ngoAuthority.publicKey,
+            payer: ngoAuthority.publicKey,
+            tokenMint: usdcMint,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([ngoAuthority])
+          .rpc();
+
+        await program.methods
+          .registerBeneficiaryForPool(
+            disasterEventId,
+            poolId,
+            { beneficiaryAuthority: batchBenAuthority.publicKey }
+          )
+          .accountsPartial({
+            authority: ngoAuthority.publicKey,
+            payer: ngoAuthority.publicKey,
+          })
+          .signers([ngoAuthority])
+          .rpc();
+
+        await program.methods
+          .lockPoolRegistration(disasterEventId, poolId)
+          .accountsPartial({ authority: ngoAuthority.publicKey })
+          .signers([ngoAuthority])
+          .rpc();
+
+        const [poolPDA] = deriveFundPoolPDA(disasterEventId, poolId, program.programId);
+        const [poolTokenAccount] = derivePoolTokenAccountPDA(disasterEventId, poolId, program.programId);
+        const timestamp = getCurrentTimestamp();
+
+        await program.methods
+          .donateToPool(
+            disasterEventId,
+            poolId,
+            {
+              amount: new anchor.BN(10000000),
+              message: "For batch claim test",
+              isAnonymous: false,
+            },
+            new anchor.BN(timestamp)
+          )
+          .accountsPartial({
+            donationRecord: deriveDonationRecordPDA(donorKeypair.publicKey, poolPDA, timestamp, program.programId)[0],
+            donor: donorKeypair.publicKey,
+            donorTokenAccount: donorUsdcAccount,
+            poolTokenAccount: poolTokenAccount,
+            platformFeeRecipient: platformFeeRecipient,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .remainingAccounts([{ pubkey: ngoPDA, isWritable: false, isSigner: false }])
+          .signers([donorKeypair])
+          .rpc();
+
+        await program.methods
+          .distributeFromPool(disasterEventId, poolId, {
+            beneficiaryAuthority: batchBenAuthority.publicKey,
+          })
+          .accountsPartial({ authority: ngoAuthority.publicKey })
+          .signers([ngoAuthority])
+          .rpc();
+      }
+    });
+
+    const claimTriples = () =>
+      poolIds.flatMap((poolId) => {
+        const [poolPDA] = deriveFundPoolPDA(disasterEventId, poolId, program.programId);
+        const [poolTokenAccount] = derivePoolTokenAccountPDA(disasterEventId, poolId, program.programId);
+        const [distributionPDA] = deriveDistributionPDA(batchBenAuthority.publicKey, poolPDA, program.programId);
+        return [
+          { pubkey: distributionPDA, isWritable: true, isSigner: false },
+          { pubkey: poolPDA, isWritable: true, isSigner: false },
+          { pubkey: poolTokenAccount, isWritable: true, isSigner: false },
+        ];
+      });
+
+    it("should fail when batch exceeds maximum size", async () => {
+      const triple = claimTriples().slice(0, 3);
+      const oversized = Array.from({ length: 21 }, () => triple).flat();
+
+      await expectError(
+        program.methods
+          .batchClaimDistributions(disasterEventId)
+          .accountsPartial({
+            beneficiaryAuthority: batchBenAuthority.publicKey,
+            beneficiaryTokenAccount: batchBenUsdcAccount,
+            tokenStats: deriveTokenStatsPDA(usdcMint, program.programId)[0],
+            disasterTokenStats: deriveDisasterTokenStatsPDA(disasterEventId, usdcMint, program.programId)[0],
+            tokenMint: usdcMint,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .remainingAccounts(oversized)
+          .signers([batchBenAuthority])
+          .rpc(),
+        "BatchSizeTooLarge"
+      );
+    });
+
+    it("should claim distributions from multiple pools in one instruction", async () => {
+      const balanceBefore = (await provider.connection.getTokenAccountBalance(batchBenUsdcAccount)).value.amount;
+
+      await program.methods
+        .batchClaimDistributions(disasterEventId)
+        .accountsPartial({
+          beneficiaryAuthority: batchBenAuthority.publicKey,
+          beneficiaryTokenAccount: batchBenUsdcAccount,
+          tokenStats: deriveTokenStatsPDA(usdcMint, program.programId)[0],
+          disasterTokenStats: deriveDisasterTokenStatsPDA(disasterEventId, usdcMint, program.programId)[0],
+          tokenMint: usdcMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .remainingAccounts(claimTriples())
+        .signers([batchBenAuthority])
+        .rpc();
+
+      let expectedTotal = 0;
+      for (const poolId of poolIds) {
+        const [poolPDA] = deriveFundPoolPDA(disasterEventId, poolId, program.programId);
+        const [distributionPDA] = deriveDistributionPDA(batchBenAuthority.publicKey, poolPDA, program.programId);
+        const distribution = await program.account.distribution.fetch(distributionPDA);
+        expect(distribution.isFullyClaimed).to.be.true;
+        expect(distribution.amountClaimed.toNumber()).to.equal(distribution.amountAllocated.toNumber());
+        expectedTotal += distribution.amountClaimed.toNumber();
+      }
+
+      const balanceAfter = (await provider.connection.getTokenAccountBalance(batchBenUsdcAccount)).value.amount;
+      expect(Number(balanceAfter) - Number(balanceBefore)).to.equal(expectedTotal);
+    });
+
+    it("should fail to batch claim when nothing is claimable", async () => {
+      await expectError(
+        program.methods
+          .batchClaimDistributions(disasterEventId)
+          .accountsPartial({
+            beneficiaryAuthority: batchBenAuthority.publicKey,
+            beneficiaryTokenAccount: batchBenUsdcAccount,
+            tokenStats: deriveTokenStatsPDA(usdcMint, program.programId)[0],
+            disasterTokenStats: deriveDisasterTokenStatsPDA(disasterEventId, usdcMint, program.programId)[0],
+            tokenMint: usdcMint,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .remainingAccounts(claimTriples())
+          .signers([batchBenAuthority])
+          .rpc(),
+        "DistributionAlreadyClaimed"
+      );
+    });
+  });
+
+  describe("revoked beneficiaries", () => {
+    let revokedPoolId: string;
+    let revokedPoolPDA: PublicKey;
+    let revokedBenAuthority: Keypair;
+    let revokedBenUsdcAccount: PublicKey;
+
+    before(async () => {
+      revokedBenAuthority = Keypair.generate();
+      await airdropSOL(provider.connection, revokedBenAuthority.publicKey);
+
+      await program.methods
+        .registerBeneficiary(
+          createMockBeneficiaryParams({
+            disasterId: disasterEventId,
+            phoneNumber: "+977-9800003006",
+            nationalId: "REVOKED-BEN",
+          })
+        )
+        .accountsPartial({
+          authority: revokedBenAuthority.publicKey,
+          fieldWorkerAuthority: fieldWorkerAuthority.publicKey,
+          payer: fieldWorkerAuthority.publicKey,
+          config: platformConfigPDA,
+        })
+        .remainingAccounts([{ pubkey: ngoPDA, isWritable: false, isSigner: false }])
+        .signers([fieldWorkerAuthority])
+        .rpc();
+
+      for (const fw of [fieldWorkerAuthority, fieldWorker2Authority, fieldWorker3Authority]) {
+        await program.methods
+          .verifyBeneficiary(revokedBenAuthority.publicKey, disasterEventId)
+          .accountsPartial({ fieldWorkerAuthority: fw.publicKey })
+          .signers([fw])
+          .rpc();
+      }
+
+      revokedBenUsdcAccount = getAssociatedTokenAddressSync(usdcMint, revokedBenAuthority.publicKey);
+      await createAssociatedTokenAccount(provider.connection, admin.payer, usdcMint, revokedBenAuthority.publicKey);
+
+      revokedPoolId = `REVOKED-POOL-${Date.now()}`;
+      [revokedPoolPDA] = deriveFundPoolPDA(disasterEventId, revokedPoolId, program.programId);
+      const [poolTokenAccount] = derivePoolTokenAccountPDA(disasterEventId, revokedPoolId, program.programId);
+
+      await program.methods
+        .createFundPool(disasterEventId, revokedPoolId, createMockFundPoolParams({ name: "Revoked Beneficiary Pool" }))
+        .accountsPartial({
+          ngoAuthority: ngoAuthority.publicKey,
+          payer: ngoAuthority.publicKey,
+          tokenMint: usdcMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([ngoAuthority])
+        .rpc();
+
+      await program.methods
+        .registerBeneficiaryForPool(disasterEventId, revokedPoolId, {
+          beneficiaryAuthority: revokedBenAuthority.publicKey,
+        })
+        .accountsPartial({
+          authority: ngoAuthority.publicKey,
+          payer: ngoAuthority.publicKey,
+        })
+        .signers([ngoAuthority])
+        .rpc();
+
+      await program.methods
+        .lockPoolRegistration(disasterEventId, revokedPoolId)
+        .accountsPartial({ authority: ngoAuthority.publicKey })
+        .signers([ngoAuthority])
+        .rpc();
+
+      const donorKeypair = Keypair.generate();
+      await airdropSOL(provider.connection, donorKeypair.publicKey);
+      const donorUsdcAccount = getAssociatedTokenAddressSync(usdcMint, donorKeypair.publicKey);
+      await createAssociatedTokenAccount(provider.connection, admin.payer, usdcMint, donorKeypair.publicKey);
+      await mintTo(provider.connection, admin.payer, usdcMint, donorUsdcAccount, admin.payer, 100000000);
+
+      const timestamp = getCurrentTimestamp();
+      await program.methods
+        .donateToPool(
+          disasterEventId,
+          revokedPoolId,
+          {
+            amount: new anchor.BN(10000000),
+            message: "For revoked beneficiary test",
+            isAnonymous: false,
+          },
+          new anchor.BN(timestamp)
+        )
+        .accountsPartial({
+          donationRecord: deriveDonationRecordPDA(donorKeypair.publicKey, revokedPoolPDA, timestamp, program.programId)[0],
+          donor: donorKeypair.publicKey,
+          donorTokenAccount: donorUsdcAccount,
+          poolTokenAccount: poolTokenAccount,
+          platformFeeRecipient: platformFeeRecipient,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .remainingAccounts([{ pubkey: ngoPDA, isWritable: false, isSigner: false }])
+        .signers([donorKeypair])
+        .rpc();
+
+      await program.methods
+        .distributeFromPool(disasterEventId, revokedPoolId, {
+          beneficiaryAuthority: revokedBenAuthority.publicKey,
+        })
+        .accountsPartial({ authority: ngoAuthority.publicKey })
+        .signers([ngoAuthority])
+        .rpc();
+
+      await program.methods
+        .revokeBeneficiaryVerification(
+          revokedBenAuthority.publicKey,
+          disasterEventId,
+          { reason: "Fraud discovered after distribution" }
+        )
+        .accountsPartial({
+          admin: admin.publicKey,
+          aidCardMint: null,
+          aidCardTokenAccount: null,
+          token2022Program: null,
+        })
+        .rpc();
+    });
+
+    it("should fail to batch claim distributions for a revoked beneficiary", async () => {
+      const [poolTokenAccount] = derivePoolTokenAccountPDA(disasterEventId, revokedPoolId, program.programId);
+      const [distributionPDA] = deriveDistributionPDA(revokedBenAuthority.publicKey, revokedPoolPDA, program.programId);
+
+      await expectError(
+        program.methods
+          .batchClaimDistributions(disasterEventId)
+          .accountsPartial({
+            beneficiaryAuthority: revokedBenAuthority.publicKey,
+            beneficiaryTokenAccount: revokedBenUsdcAccount,
+            tokenStats: deriveTokenStatsPDA(usdcMint, program.programId)[0],
+            disasterTokenStats: deriveDisasterTokenStatsPDA(disasterEventId, usdcMint, program.programId)[0],
+            tokenMint: usdcMint,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .remainingAccounts([
+            { pubkey: distributionPDA, isWritable: true, isSigner: false },
+            { pubkey: revokedPoolPDA, isWritable: true, isSigner: false },
+            { pubkey: poolTokenAccount, isWritable: true, isSigner: false },
+          ])
+          .signers([revokedBenAuthority])
+          .rpc(),
+        "BeneficiaryNotVerified"
+      );
+    });
+
+    it("should reclaim a revoked beneficiary's distribution before the claim deadline", async () => {
+      const [distributionPDA] = deriveDistributionPDA(revokedBenAuthority.publicKey, revokedPoolPDA, program.programId);
+      const poolBefore = await program.account.fundPool.fetch(revokedPoolPDA);
+      const distributionBefore = await program.account.distribution.fetch(distributionPDA);
+      const [activityLogPDA] = deriveActivityLogPDA(revokedPoolPDA, poolBefore.activityCount, program.programId);
+
+      await program.methods
+        .reclaimExpiredDistribution(disasterEventId, revokedPoolId, revokedBenAuthority.publicKey)
+        .accountsPartial({ authority: ngoAuthority.publicKey })
+        .signers([ngoAuthority])
+        .rpc();
+
+      const distribution = await program.account.distribution.fetch(distributionPDA);
+      const pool = await program.account.fundPool.fetch(revokedPoolPDA);
+
+      expect(distribution.isExpired).to.be.true;
+      expect(pool.reclaimedAmount.sub(poolBefore.reclaimedAmount).toNumber()).to.equal(
+        distributionBefore.amountAllocated.toNumber()
+      );
+      expect(pool.expiredAllocationWeight.sub(poolBefore.expiredAllocationWeight).toNumber()).to.equal(
+        distributionBefore.allocationWeight
+      );
+
+      const activityLog = await program.account.activityLog.fetch(activityLogPDA);
+      expect(activityLog.actionType).to.deep.equal({ distributionReclaimed: {} });
+    });
+  });
+
+  describe("batch_distribute_from_pool", () => {
+    let batchPoolId: string;
+    let batchPoolPDA: PublicKey;
+    let secondBenAuthority: Keypair;
+
+    before(async () => {
+      batchPoolId = `BATCH-DIST-POOL-${Date.now()}`;
+      [batchPoolPDA] = deriveFundPoolPDA(disasterEventId, batchPoolId, program.programId);
+
+      const params = createMockFundPoolParams({ name: "Batch Distribution Pool" });
+      await program.methods
+        .createFundPool(disasterEventId, batchPoolId, params)
+        .accountsPartial({
+          ngoAuthority: ngoAuthority.publicKey,
+          payer: ngoAuthority.publicKey,
+          tokenMint: usdcMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([ngoAuthority])
+        .rpc();
+
+      secondBenAuthority = Keypair.generate();
+      const benParams = createMockBeneficiaryParams({
+        disasterId: disasterEventId,
+        phoneNumber: "+977-9800003006",
+        nationalId: "BATCH-DIST-BEN",
+      });
+
+      await program.methods
+        .registerBeneficiary(benParams)
+        .accountsPartial({
+          authority: secondBenAuthority.publicKey,
+          fieldWorkerAuthority: fieldWorkerAuthority.publicKey,
+          payer: fieldWorkerAuthority.publicKey,
+          config: platformConfigPDA,
+        })
+        .remainingAccounts([{ pubkey: ngoPDA, isWritable: false, isSigner: false }])
+        .signers([fieldWorkerAuthority])
+        .rpc();
+
+      for (let i = 0; i < 3; i++) {
+        const fw = i === 0 ? fieldWorkerAuthority : i === 1 ? fieldWorker2Authority : fieldWorker3Authority;
+        await program.methods
+          .verifyBeneficiary(secondBenAuthority.publicKey, disasterEventId)
+          .accountsPartial({ fieldWorkerAuthority: fw.publicKey })
+          .signers([fw])
+          .rpc();
+      }
+
+      for (const benAuth of [beneficiaryAuthority, secondBenAuthority]) {
+        await program.methods
+          .registerBeneficiaryForPool(
+            disasterEventId,
+            batchPoolId,
+            { beneficiaryAuthority: benAuth.publicKey }
+          )
+          .accountsPartial({
+            authority: ngoAuthority.publicKey,
+            payer: ngoAuthority.publicKey,
+          })
+          .signers([ngoAuthority])
+          .rpc();
+      }
+
+      await program.methods
+        .lockPoolRegistration(disasterEventId, batchPoolId)
+        .accountsPartial({ authority: ngoAuthority.publicKey })
+        .signers([ngoAuthority])
+        .rpc();
+
+      const donorKeypair = Keypair.generate();
+      await airdropSOL(provider.connection, donorKeypair.publicKey);
+      const donorUsdcAccount = getAssociatedTokenAddressSync(usdcMint, donorKeypair.publicKey);
+      await createAssociatedTokenAccount(provider.connection, admin.payer, usdcMint, donorKeypair.publicKey);
+      await mintTo(provider.connection, admin.payer, usdcMint, donorUsdcAccount, admin.payer, 10000000000);
+
+      const [poolTokenAccount] = derivePoolTokenAccountPDA(disasterEventId, batchPoolId, program.programId);
+      const timestamp = getCurrentTimestamp();
+
+      await program.methods
+        .donateToPool(
+          disasterEventId,
+          batchPoolId,
+          {
+            amount: new anchor.BN(20000000),
+            message: "For batch distribution test",
+            isAnonymous: false,
+          },
+          new anchor.BN(timestamp)
+        )
+        .accountsPartial({
+          donationRecord: deriveDonationRecordPDA(donorKeypair.publicKey, batchPoolPDA, timestamp, program.programId)[0],
+          donor: donorKeypair.publicKey,
+          donorTokenAccount: donorUsdcAccount,
+          poolTokenAccount: poolTokenAccount,
+          platformFeeRecipient: platformFeeRecipient,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .remainingAccounts([{ pubkey: ngoPDA, isWritable: false, isSigner: false }])
+        .signers([donorKeypair])
+        .rpc();
+    });
+
+    it("should distribute to every registered beneficiary and mark pool distributed", async () => {
+      const remaining = [beneficiaryAuthority, secondBenAuthority].flatMap((benAuth) => {
+        const [registrationPDA] = derivePoolRegistrationPDA(batchPoolPDA, benAuth.publicKey, program.programId);
+        const [benPDA] = deriveBeneficiaryPDA(benAuth.publicKey, disasterEventId, program.programId);
+        const [distributionPDA] = deriveDistributionPDA(benAuth.publicKey, batchPoolPDA, program.programId);
+        return [
+          { pubkey: registrationPDA, isWritable: true, isSigner: false },
+          { pubkey: benPDA, isWritable: false, isSigner: false },
+          { pubkey: distributionPDA, isWritable: true, isSigner: false },
+        ];
+      });
+
+      await program.methods
+        .batchDistributeFromPool(disasterEventId, batchPoolId)
+        .accountsPartial({ authority: ngoAuthority.publicKey })
+        .remainingAccounts(remaining)
+        .signers([ngoAuthority])
+        .rpc();
+
+      const pool = await program.account.fundPool.fetch(batchPoolPDA);
+      expect(pool.beneficiaryCount).to.equal(2);
+      expect(pool.isDistributed).to.be.true;
+      expect(pool.distributedAt).to.not.be.null;
+
+      let allocated = 0;
+      for (const benAuth of [beneficiaryAuthority, secondBenAuthority]) {
+        const [distributionPDA] = deriveDistributionPDA(benAuth.publicKey, batchPoolPDA, program.programId);
+        const distribution = await program.account.distribution.fetch(distributionPDA);
+        expect(distribution.pool.toString()).to.equal(batchPoolPDA.toString());
+        allocated += distribution.amountAllocated.toNumber();
+
+        const [registrationPDA] = derivePoolRegistrationPDA(batchPoolPDA, benAuth.publicKey, program.programId);
+        const registration = await program.account.poolRegistration.fetch(registrationPDA);
+        expect(registration.isDistributed).to.be.true;
+      }
+      expect(pool.totalDistributed.toNumber()).to.equal(allocated);
+    });
+
+    it("should fail to distribute again once the pool is distributed", async () => {
+      await expectError(
+        program.methods
+          .distributeFromPool(disasterEventId, batchPoolId, {
+            beneficiaryAuthority: beneficiaryAuthority.publicKey,
+          })
+          .accountsPartial({ authority: ngoAuthority.publicKey })
+          .signers([ngoAuthority])
+          .rpc(),
+        "DistributionAlreadyCompleted"
+      );
+    });
+  });
+
+  describe("close accounts & reclaim rent", () => {
+    const updateConfig = async (overrides: Partial<any>, reason: string) => {
+      await program.methods
+        .updatePlatformConfig({
+          configParams: createMockUpdateConfigParams(overrides),
+          reason,
+          metadata: "{}",
+        })
+        .accountsPartial({
+          admin: admin.publicKey,
+          config: platformConfigPDA,
+        })
+        .rpc();
+    };
+
+    let closePoolId: string;
+
+    it("should close a distributed pool registration and refund rent to its payer", async () => {
+      closePoolId = `CLOSE-REG-POOL-${Date.now()}`;
+      const poolId = closePoolId;
+      const [poolPDA] = deriveFundPoolPDA(disasterEventId, poolId, program.programId);
+      const [registrationPDA] = derivePoolRegistrationPDA(poolPDA, beneficiaryAuthority.publicKey, program.programId);
+
+      await program.methods
+        .createFundPool(
+          disasterEventId,
+          poolId,
+          createMockFundPoolParams({ name: "Close Registration Pool" })
+        )
+        .accountsPartial({
+          ngoAuthority: ngoAuthority.publicKey,
+          payer: ngoAuthority.publicKey,
+          tokenMint: usdcMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([ngoAuthority])
+        .rpc();
+
+      await program.methods
+        .registerBeneficiaryForPool(
+          disasterEventId,
+          poolId,
+          { beneficiaryAuthority: beneficiaryAuthority.publicKey }
+        )
+        .accountsPartial({
+          authority: ngoAuthority.publicKey,
+          payer: ngoAuthority.publicKey,
+        })
+        .signers([ngoAuthority])
+        .rpc();
+
+      const registration = await program.account.poolRegistration.fetch(registrationPDA);
+      expect(registration.rentPayer.toString()).to.equal(ngoAuthority.publicKey.toString());
+
+      await expectError(
+        program.methods
+          .closePoolRegistration(disasterEventId, poolId, beneficiaryAuthority.publicKey)
+          .accountsPartial({ rentPayer: ngoAuthority.publicKey })
+          .rpc(),
+        "AccountStillInUse"
+      );
+
+      await program.methods
+        .lockPoolRegistration(disasterEventId, poolId)
+        .accountsPartial({ authority: ngoAuthority.publicKey })
+        .signers([ngoAuthority])
+        .rpc();
+
+      await program.methods
+        .distributeFromPool(disasterEventId, poolId, {
+          beneficiaryAuthority: beneficiaryAuthority.publicKey,
+        })
+        .accountsPartial({ authority: ngoAuthority.publicKey })
+        .signers([ngoAuthority])
+        .rpc();
+
+      const balanceBefore = await provider.connection.getBalance(ngoAuthority.publicKey);
+      const rent = await provider.connection.getBalance(registrationPDA);
+
+      await program.methods
+        .closePoolRegistration(disasterEventId, poolId, beneficiaryAuthority.publicKey)
+        .accountsPartial({ rentPayer: ngoAuthority.publicKey })
+        .rpc();
+
+      const balanceAfter = await provider.connection.getBalance(ngoAuthority.publicKey);
+      expect(balanceAfter - balanceBefore).to.equal(rent);
+      expect(await provider.connection.getAccountInfo(registrationPDA)).to.be.null;
+    });
+
+    it("should fail to close a distribution that is still claimable", async () => {
+      await expectError(
+        program.methods
+          .closeDistribution(disasterEventId, closePoolId, beneficiaryAuthority.publicKey)
+          .accountsPartial({ rentPayer: ngoAuthority.publicKey, closer: null })
+          .rpc(),
+        "AccountStillInUse"
+      );
+    });
+
+    it("should fail to close a phone registry while the disaster is open", async () => {
+      const phoneNumber = "+977-9800003006";
+      const [phoneRegistryPDA] = derivePhoneRegistryPDA(disasterEventId, phoneNumber, program.programId);
+      const phoneRegistry = await program.account.phoneRegistry.fetch(phoneRegistryPDA);
+
+      await expectError(
+        program.methods
+          .closePhoneRegistry(disasterEventId, phoneNumber)
+          .accountsPartial({ rentPayer: phoneRegistry.rentPayer })
+          .rpc(),
+        "AccountStillInUse"
+      );
+    });
+
+    it("should archive an activity log into the actor's archive root", async () => {
+      const poolId = `ARCHIVE-POOL-${Date.now()}`;
+      const [poolPDA] = deriveFundPoolPDA(disasterEventId, poolId, program.programId);
+      const [archivePDA] = deriveActivityArchivePDA(ngoAuthority.publicKey, program.programId);
+
+      await program.methods
+        .createFundPool(
+          disasterEventId,
+          poolId,
+          createMockFundPoolParams({ name: "Archive Pool" })
+        )
+        .accountsPartial({
+          ngoAuthority: ngoAuthority.publicKey,
+          payer: ngoAuthority.publicKey,
+          tokenMint: usdcMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([ngoAuthority])
+        .rpc();
+
+      const { activityCount } = await program.account.fundPool.fetch(poolPDA);
+      await program.methods
+        .registerBeneficiaryForPool(
+          disasterEventId,
+          poolId,
+          { beneficiaryAuthority: beneficiaryAuthority.publicKey }
+        )
+        .accountsPartial({
+          authority: ngoAuthority.publicKey,
+          payer: ngoAuthority.publicKey,
+        })
+        .signers([ngoAuthority])
+        .rpc();
+
+      const [activityLogPDA] = deriveActivityLogPDA(poolPDA, activityCount, program.programId);
+      const activityLog = await program.account.activityLog.fetch(activityLogPDA);
+      expect(activityLog.rentPayer.toString()).to.equal(ngoAuthority.publicKey.toString());
+
+      await program.methods
+        .initializeActivityArchive()
+        .accountsPartial({ actor: ngoAuthority.publicKey })
+        .signers([ngoAuthority])
+        .rpc();
+
+      await expectError(
+        program.methods
+          .archiveActivityLog()
+          .accountsPartial({
+            activityLog: activityLogPDA,
+            actor: ngoAuthority.publicKey,
+          })
+          .signers([ngoAuthority])
+          .rpc(),
+        "OperationNotAllowed"
+      );
+
+      await updateConfig({ activityArchivalEnabled: true }, "Enable activity archival");
+
+      try {
+        await program.methods
+          .archiveActivityLog()
+          .accountsPartial({
+            activityLog: activityLogPDA,
+            actor: ngoAuthority.publicKey,
+          })
+          .signers([ngoAuthority])
+          .rpc();
+      } finally {
+        await updateConfig({ activityArchivalEnabled: false }, "Disable activity archival");
+      }
+
+      const archive = await program.account.activityArchive.fetch(archivePDA);
+      expect(archive.archivedCount.toNumber()).to.equal(1);
+      expect(Buffer.from(archive.root).equals(Buffer.alloc(32))).to.be.false;
+      expect(await provider.connection.getAccountInfo(activityLogPDA)).to.be.null;
+    });
   });
 });
@@ -6,8 +6,8 @@ import { SaharasolCore } from "../target/types/saharasol_core";
 import {
   derivePlatformConfigPDA,
   deriveNGOPDA,
+  deriveAdminActionPDA,
   airdropSOL,
-  getCurrentTimestamp,
 } from "./helpers/test-utils";
 import { createMockNGOParams } from "./helpers/mock-data";
 import { expectError } from "./helpers/assertions";
@@ -43,19 +43,11 @@ describe("07 - Manager Role and Permissions", () => {
 
   describe("add_manager", () => {
     it("should add first manager successfully", async () => {
-      const timestamp = getCurrentTimestamp();
-      const [adminActionPDA] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("admin-action"),
-          admin.publicKey.toBuffer(),
-          Buffer.from(new anchor.BN(timestamp).toArray("le", 8)),
-        ],
-        program.programId
-      );
+      const { adminActionCount } = await program.account.platformConfig.fetch(platformConfigPDA);
+      const [adminActionPDA] = deriveAdminActionPDA(admin.publicKey, adminActionCount, program.programId);
 
       await program.methods
         .addManager(
-          new anchor.BN(timestamp),
           manager1.publicKey,
           "Adding first manager for platform operations"
         )
@@ -75,28 +67,18 @@ describe("07 - Manager Role and Permissions", () => {
       expect(adminAction.actionType).to.deep.equal({ addManager: {} });
       expect(adminAction.target.toString()).to.equal(manager1.publicKey.toString());
       expect(adminAction.admin.toString()).to.equal(admin.publicKey.toString());
+      expect(adminAction.sequence.toNumber()).to.equal(adminActionCount.toNumber());
+      expect(config.adminActionCount.toNumber()).to.equal(adminActionCount.toNumber() + 1);
     });
 
     it("should add second manager successfully", async () => {
-      const timestamp = getCurrentTimestamp();
-      const [adminActionPDA] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("admin-action"),
-          admin.publicKey.toBuffer(),
-          Buffer.from(new anchor.BN(timestamp).toArray("le", 8)),
-        ],
-        program.programId
-      );
-
       await program.methods
         .addManager(
-          new anchor.BN(timestamp),
           manager2.publicKey,
           "Adding second manager"
         )
         .accountsPartial({
           config: platformConfigPDA,
-          adminAction: adminActionPDA,
           admin: admin.publicKey,
         })
         .rpc();
@@ -107,26 +89,14 @@ describe("07 - Manager Role and Permissions", () => {
     });
 
     it("should fail to add duplicate manager", async () => {
-      const timestamp = getCurrentTimestamp();
-      const [adminActionPDA] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("admin-action"),
-          admin.publicKey.toBuffer(),
-          Buffer.from(new anchor.BN(timestamp).toArray("le", 8)),
-        ],
-        program.programId
-      );
-
       await expectError(
         program.methods
           .addManager(
-            new anchor.BN(timestamp),
             manager1.publicKey,
             "Trying to add duplicate"
           )
           .accountsPartial({
             config: platformConfigPDA,
-            adminAction: adminActionPDA,
             admin: admin.publicKey,
           })
           .rpc(),
@@ -135,26 +105,14 @@ describe("07 - Manager Role and Permissions", () => {
     });
 
     it("should fail when non-admin tries to add manager", async () => {
-      const timestamp = getCurrentTimestamp();
-      const [adminActionPDA] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("admin-action"),
-          manager1.publicKey.toBuffer(),
-          Buffer.from(new anchor.BN(timestamp).toArray("le", 8)),
-        ],
-        program.programId
-      );
-
       await expectError(
         program.methods
           .addManager(
-            new anchor.BN(timestamp),
             Keypair.generate().publicKey,
             "Unauthorized attempt"
           )
           .accountsPartial({
             config: platformConfigPDA,
-            adminAction: adminActionPDA,
             admin: manager1.publicKey,
           })
           .signers([manager1])
@@ -167,19 +125,11 @@ describe("07 - Manager Role and Permissions", () => {
 
   describe("remove_manager", () => {
     it("should remove manager successfully", async () => {
-      const timestamp = getCurrentTimestamp();
-      const [adminActionPDA] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("admin-action"),
-          admin.publicKey.toBuffer(),
-          Buffer.from(new anchor.BN(timestamp).toArray("le", 8)),
-        ],
-        program.programId
-      );
+      const { adminActionCount } = await program.account.platformConfig.fetch(platformConfigPDA);
+      const [adminActionPDA] = deriveAdminActionPDA(admin.publicKey, adminActionCount, program.programId);
 
       await program.methods
         .removeManager(
-          new anchor.BN(timestamp),
           manager2.publicKey,
           "Removing manager2"
         )
@@ -199,26 +149,14 @@ describe("07 - Manager Role and Permissions", () => {
     });
 
     it("should fail to remove non-existent manager", async () => {
-      const timestamp = getCurrentTimestamp();
-      const [adminActionPDA] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("admin-action"),
-          admin.publicKey.toBuffer(),
-          Buffer.from(new anchor.BN(timestamp).toArray("le", 8)),
-        ],
-        program.programId
-      );
-
       await expectError(
         program.methods
           .removeManager(
-            new anchor.BN(timestamp),
             Keypair.generate().publicKey,
             "Trying to remove non-existent manager"
           )
           .accountsPartial({
             config: platformConfigPDA,
-            adminAction: adminActionPDA,
             admin: admin.publicKey,
           })
           .rpc(),
@@ -247,26 +185,14 @@ describe("07 - Manager Role and Permissions", () => {
     });
 
     it("manager should be able to verify NGO", async () => {
-      const actionId = getCurrentTimestamp();
-      const [adminActionPDA] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("admin-action"),
-          manager1.publicKey.toBuffer(),
-          Buffer.from(new anchor.BN(actionId).toArray("le", 8)),
-        ],
-        program.programId
-      );
-
       await program.methods
         .verifyNgo(
           ngoAuthority.publicKey,
-          { reason: "Verified by manager" },
-          new anchor.BN(actionId)
+          { reason: "Verified by manager" }
         )
         .accountsPartial({
           ngo: ngoPDA,
           config: platformConfigPDA,
-          adminAction: adminActionPDA,
           admin: manager1.publicKey,
         })
         .signers([manager1])
@@ -279,26 +205,14 @@ describe("07 - Manager Role and Permissions", () => {
 
 
     it("manager should be able to update NGO status", async () => {
-      const actionId = getCurrentTimestamp();
-      const [adminActionPDA] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("admin-action"),
-          manager1.publicKey.toBuffer(),
-          Buffer.from(new anchor.BN(actionId).toArray("le", 8)),
-        ],
-        program.programId
-      );
-
       await program.methods
         .updateNgoStatus(
           ngoAuthority.publicKey,
-          { isActive: false, reason: "Temporary suspension" },
-          new anchor.BN(actionId)
+          { isActive: false, reason: "Temporary suspension" }
         )
         .accountsPartial({
           ngo: ngoPDA,
           config: platformConfigPDA,
-          adminAction: adminActionPDA,
           admin: manager1.publicKey,
         })
         .signers([manager1])
@@ -308,26 +222,15 @@ describe("07 - Manager Role and Permissions", () => {
       expect(ngo.isActive).to.be.false;
 
       // Reactivate for other tests
-      const actionId2 = getCurrentTimestamp();
-      const [adminActionPDA2] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("admin-action"),
-          manager1.publicKey.toBuffer(),
-          Buffer.from(new anchor.BN(actionId2).toArray("le", 8)),
-        ],
-        program.programId
-      );
 
       await program.methods
         .updateNgoStatus(
           ngoAuthority.publicKey,
-          { isActive: true, reason: "Reactivating" },
-          new anchor.BN(actionId2)
+          { isActive: true, reason: "Reactivating" }
         )
         .accountsPartial({
           ngo: ngoPDA,
           config: platformConfigPDA,
-          adminAction: adminActionPDA2,
           admin: manager1.publicKey,
         })
         .signers([manager1])
@@ -335,26 +238,14 @@ describe("07 - Manager Role and Permissions", () => {
     });
 
     it("manager should NOT be able to add other managers", async () => {
-      const timestamp = getCurrentTimestamp();
-      const [adminActionPDA] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("admin-action"),
-          manager1.publicKey.toBuffer(),
-          Buffer.from(new anchor.BN(timestamp).toArray("le", 8)),
-        ],
-        program.programId
-      );
-
       await expectError(
         program.methods
           .addManager(
-            new anchor.BN(timestamp),
             Keypair.generate().publicKey,
             "Manager trying to add another manager"
           )
           .accountsPartial({
             config: platformConfigPDA,
-            adminAction: adminActionPDA,
             admin: manager1.publicKey,
           })
           .signers([manager1])
@@ -364,26 +255,14 @@ describe("07 - Manager Role and Permissions", () => {
     });
 
     it("manager should NOT be able to remove managers", async () => {
-      const timestamp = getCurrentTimestamp();
-      const [adminActionPDA] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("admin-action"),
-          manager1.publicKey.toBuffer(),
-          Buffer.from(new anchor.BN(timestamp).toArray("le", 8)),
-        ],
-        program.programId
-      );
-
       await expectError(
         program.methods
           .removeManager(
-            new anchor.BN(timestamp),
             manager1.publicKey,
             "Manager trying to remove themselves"
           )
           .accountsPartial({
             config: platformConfigPDA,
-            adminAction: adminActionPDA,
             admin: manager1.publicKey,
           })
           .signers([manager1])
@@ -9,6 +9,7 @@ import {
   deriveDisasterPDA,
   deriveFieldWorkerPDA,
   deriveBeneficiaryPDA,
+  deriveActivityLogPDA,
   airdropSOL,
   getCurrentTimestamp,
   createTokenMint,
@@ -22,6 +23,7 @@ import {
 } from "./helpers/mock-data";
 import { expectError } from "./helpers/assertions";
 import {
+  TOKEN_PROGRAM_ID,
   createAssociatedTokenAccount,
   mintTo,
   getAccount,
@@ -101,26 +103,15 @@ describe("08 - Usage Limits and Fee Rates", () => {
       .rpc();
 
     // Verify the first NGO
-    const actionId = getCurrentTimestamp();
-    const [adminActionPDA] = PublicKey.findProgramAddressSync(
-      [
-        Buffer.from("admin-action"),
-        admin.publicKey.toBuffer(),
-        Buffer.from(new anchor.BN(actionId).toArray("le", 8)),
-      ],
-      program.programId
-    );
 
     await program.methods
       .verifyNgo(
         verifiedNgoAuthority.publicKey,
-        { reason: "Verified for testing" },
-        new anchor.BN(actionId)
+        { reason: "Verified for testing" }
       )
       .accountsPartial({
         ngo: verifiedNgoPDA,
         config: platformConfigPDA,
-        adminAction: adminActionPDA,
         admin: admin.publicKey,
       })
       .rpc();
@@ -131,18 +122,8 @@ describe("08 - Usage Limits and Fee Rates", () => {
       name: "Test Disaster for Limits",
     });
 
-    const timestamp = getCurrentTimestamp();
-    const [activityLogPDA] = PublicKey.findProgramAddressSync(
-      [
-        Buffer.from("activity"),
-        verifiedNgoAuthority.publicKey.toBuffer(),
-        Buffer.from(new anchor.BN(timestamp).toArray("le", 8)),
-      ],
-      program.programId
-    );
-
     await program.methods
-      .initializeDisaster(disasterParams, new anchor.BN(timestamp))
+      .initializeDisaster(disasterParams)
       .accountsPartial({
         authority: verifiedNgoAuthority.publicKey,
         config: platformConfigPDA,
@@ -158,7 +139,6 @@ describe("08 - Usage Limits and Fee Rates", () => {
       // Create 5 pools successfully
       for (let i = 0; i < 5; i++) {
         const poolId = `POOL-UNVER-${i}`;
-        const timestamp = getCurrentTimestamp();
 
         const [poolPDA] = PublicKey.findProgramAddressSync(
           [Buffer.from("pool"), Buffer.from(disasterId), Buffer.from(poolId)],
@@ -170,25 +150,18 @@ describe("08 - Usage Limits and Fee Rates", () => {
           program.programId
         );
 
-        const [activityLogPDA] = PublicKey.findProgramAddressSync(
-          [
-            Buffer.from("activity"),
-            unverifiedNgoAuthority.publicKey.toBuffer(),
-            Buffer.from(new anchor.BN(timestamp).toArray("le", 8)),
-          ],
-          program.programId
-        );
 
         const poolParams = createMockFundPoolParams({
           name: `Unverified Pool ${i}`,
         });
 
         await program.methods
-          .createFundPool(disasterId, poolId, new anchor.BN(timestamp), poolParams)
+          .createFundPool(disasterId, poolId, poolParams)
           .accountsPartial({
             ngoAuthority: unverifiedNgoAuthority.publicKey,
             payer: unverifiedNgoAuthority.publicKey,
             tokenMint: usdcMint,
+            tokenProgram: TOKEN_PROGRAM_ID,
           })
           .signers([unverifiedNgoAuthority])
           .rpc();
@@ -199,7 +172,6 @@ describe("08 - Usage Limits and Fee Rates", () => {
 
       // Try to create 6th pool - should fail
       const poolId = "POOL-UNVER-6";
-      const timestamp = getCurrentTimestamp();
 
       const [poolPDA] = PublicKey.findProgramAddressSync(
         [Buffer.from("pool"), Buffer.from(disasterId), Buffer.from(poolId)],
@@ -211,14 +183,6 @@ describe("08 - Usage Limits and Fee Rates", () => {
         program.programId
       );
 
-      const [activityLogPDA] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("activity"),
-          unverifiedNgoAuthority.publicKey.toBuffer(),
-          Buffer.from(new anchor.BN(timestamp).toArray("le", 8)),
-        ],
-        program.programId
-      );
 
       const poolParams = createMockFundPoolParams({
         name: "Should Fail Pool",
@@ -226,11 +190,12 @@ describe("08 - Usage Limits and Fee Rates", () => {
 
       await expectError(
         program.methods
-          .createFundPool(disasterId, poolId, new anchor.BN(timestamp), poolParams)
+          .createFundPool(disasterId, poolId, poolParams)
           .accountsPartial({
             ngoAuthority: unverifiedNgoAuthority.publicKey,
             payer: unverifiedNgoAuthority.publicKey,
             tokenMint: usdcMint,
+            tokenProgram: TOKEN_PROGRAM_ID,
           })
           .signers([unverifiedNgoAuthority])
           .rpc(),
@@ -243,7 +208,6 @@ describe("08 - Usage Limits and Fee Rates", () => {
       // Create 10 pools successfully
       for (let i = 0; i < 10; i++) {
         const poolId = `POOL-VER-${i}`;
-        const timestamp = getCurrentTimestamp();
 
         const [poolPDA] = PublicKey.findProgramAddressSync(
           [Buffer.from("pool"), Buffer.from(disasterId), Buffer.from(poolId)],
@@ -255,25 +219,18 @@ describe("08 - Usage Limits and Fee Rates", () => {
           program.programId
         );
 
-        const [activityLogPDA] = PublicKey.findProgramAddressSync(
-          [
-            Buffer.from("activity"),
-            verifiedNgoAuthority.publicKey.toBuffer(),
-            Buffer.from(new anchor.BN(timestamp).toArray("le", 8)),
-          ],
-          program.programId
-        );
 
         const poolParams = createMockFundPoolParams({
           name: `Verified Pool ${i}`,
         });
 
         await program.methods
-          .createFundPool(disasterId, poolId, new anchor.BN(timestamp), poolParams)
+          .createFundPool(disasterId, poolId, poolParams)
           .accountsPartial({
             ngoAuthority: verifiedNgoAuthority.publicKey,
             payer: verifiedNgoAuthority.publicKey,
             tokenMint: usdcMint,
+            tokenProgram: TOKEN_PROGRAM_ID,
           })
           .signers([verifiedNgoAuthority])
           .rpc();
@@ -369,14 +326,7 @@ describe("08 - Usage Limits and Fee Rates", () => {
         program.programId
       );
 
-      const [activityLogPDA] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("activity"),
-          donor.publicKey.toBuffer(),
-          Buffer.from(new anchor.BN(timestamp).toArray("le", 8)),
-        ],
-        program.programId
-      );
+      const [activityLogPDA] = deriveActivityLogPDA(donationRecordPDA, 0, program.programId);
 
       const feeRecipientBefore = await getAccount(provider.connection, platformFeeRecipient);
       const poolBefore = await getAccount(provider.connection, unverifiedPoolTokenAccount);
@@ -401,6 +351,7 @@ describe("08 - Usage Limits and Fee Rates", () => {
           platformFeeRecipient: platformFeeRecipient,
           activityLog: activityLogPDA,
           donor: donor.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
         .remainingAccounts([{ pubkey: unverifiedNgoPDA, isWritable: false, isSigner: false }])
         .signers([donor])
@@ -431,14 +382,7 @@ describe("08 - Usage Limits and Fee Rates", () => {
         program.programId
       );
 
-      const [activityLogPDA] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("activity"),
-          donor.publicKey.toBuffer(),
-          Buffer.from(new anchor.BN(timestamp).toArray("le", 8)),
-        ],
-        program.programId
-      );
+      const [activityLogPDA] = deriveActivityLogPDA(donationRecordPDA, 0, program.programId);
 
       const feeRecipientBefore = await getAccount(provider.connection, platformFeeRecipient);
       const poolBefore = await getAccount(provider.connection, verifiedPoolTokenAccount);
@@ -463,6 +407,7 @@ describe("08 - Usage Limits and Fee Rates", () => {
           platformFeeRecipient: platformFeeRecipient,
           activityLog: activityLogPDA,
           donor: donor.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
         .remainingAccounts([{ pubkey: verifiedNgoPDA, isWritable: false, isSigner: false }])
         .signers([donor])
@@ -0,0 +1,293 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { expect } from "chai";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { SaharasolCore } from "../target/types/saharasol_core";
+import {
+  derivePlatformConfigPDA,
+  deriveNGOPDA,
+  deriveFundPoolPDA,
+  derivePoolTokenAccountPDA,
+  deriveActivityLogPDA,
+  derivePriceFeedPDA,
+  deriveStandInOraclePDA,
+  airdropSOL,
+  getCurrentTimestamp,
+  createTokenMint,
+} from "./helpers/test-utils";
+import {
+  createMockNGOParams,
+  createMockDisasterParams,
+  createMockFundPoolParams,
+} from "./helpers/mock-data";
+import { expectError } from "./helpers/assertions";
+import {
+  TOKEN_PROGRAM_ID,
+  createAssociatedTokenAccount,
+  getAssociatedTokenAddressSync,
+  mintTo,
+} from "@solana/spl-token";
+
+describe("09 - Price Feeds and USD Accounting", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.SaharasolCore as Program<SaharasolCore>;
+  const admin = provider.wallet as anchor.Wallet;
+
+  const disasterId = "DISASTER-PRICE-001";
+  const poolId = "POOL-SOL-PRICED";
+
+  // 9-decimal token priced at $150.000000 (price 150_000_000, exponent -6)
+  const tokenPrice = 150_000_000;
+  const tokenExponent = -6;
+
+  let platformConfigPDA: PublicKey;
+  let tokenMint: PublicKey;
+  let priceFeedPDA: PublicKey;
+  let priceOraclePDA: PublicKey;
+  let ngoAuthority: Keypair;
+  let ngoPDA: PublicKey;
+  let poolPDA: PublicKey;
+  let poolTokenAccount: PublicKey;
+  let donor: Keypair;
+  let donorTokenAccount: PublicKey;
+  let platformFeeRecipient: PublicKey;
+
+  const expectedUsd = (netAmount: number) => Math.floor((netAmount * tokenPrice) / 1_000_000_000);
+
+  async function donate(amount: number, withFeed: boolean = true) {
+    const timestamp = getCurrentTimestamp();
+    const [donationRecordPDA] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("donation"),
+        donor.publicKey.toBuffer(),
+        poolPDA.toBuffer(),
+        Buffer.from(new anchor.BN(timestamp).toArray("le", 8)),
+      ],
+      program.programId
+    );
+    const [activityLogPDA] = deriveActivityLogPDA(donationRecordPDA, 0, program.programId);
+
+    return program.methods
+      .donateToPool(
+        disasterId,
+        poolId,
+        { amount: new anchor.BN(amount), isAnonymous: false, message: "Priced donation" },
+        new anchor.BN(timestamp)
+      )
+      .accountsPartial({
+        pool: poolPDA,
+        poolTokenAccount,
+        donationRecord: donationRecordPDA,
+        donorTokenAccount,
+        config: platformConfigPDA,
+        activityLog: activityLogPDA,
+        platformFeeRecipient,
+        priceFeed: withFeed ? priceFeedPDA : null,
+        priceOracle: withFeed ? priceOraclePDA : null,
+        donor: donor.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .remainingAccounts([{ pubkey: ngoPDA, isWritable: false, isSigner: false }])
+      .signers([donor])
+      .rpc();
+  }
+
+  before(async () => {
+    [platformConfigPDA] = derivePlatformConfigPDA(program.programId);
+
+    tokenMint = await createTokenMint(provider.connection, admin.payer, 9);
+    [priceFeedPDA] = derivePriceFeedPDA(tokenMint, program.programId);
+    [priceOraclePDA] = deriveStandInOraclePDA(tokenMint, program.programId);
+
+    await program.methods
+      .addAllowedToken(tokenMint, "Priced token for USD accounting")
+      .accountsPartial({
+        admin: admin.publicKey,
+        config: platformConfigPDA,
+      })
+      .rpc();
+
+    ngoAuthority = Keypair.generate();
+    donor = Keypair.generate();
+    await airdropSOL(provider.connection, ngoAuthority.publicKey);
+    await airdropSOL(provider.connection, donor.publicKey);
+    [ngoPDA] = deriveNGOPDA(ngoAuthority.publicKey, program.programId);
+
+    await program.methods
+      .registerNgo(
+        createMockNGOParams({
+          name: "Price Feed NGO",
+          registrationNumber: "NGO-PRICE-001",
+          email: "price@feeds.org",
+          phoneNumber: "+000-55501234",
+        })
+      )
+      .accountsPartial({
+        authority: ngoAuthority.publicKey,
+        config: platformConfigPDA,
+      })
+      .signers([ngoAuthority])
+      .rpc();
+
+    await program.methods
+      .initializeDisaster(createMockDisasterParams({ eventId: disasterId, name: "Priced Disaster" }))
+      .accountsPartial({
+        authority: admin.publicKey,
+        config: platformConfigPDA,
+      })
+      .rpc();
+
+    [poolPDA] = deriveFundPoolPDA(disasterId, poolId, program.programId);
+    [poolTokenAccount] = derivePoolTokenAccountPDA(disasterId, poolId, program.programId);
+
+    await program.methods
+      .createFundPool(disasterId, poolId, createMockFundPoolParams({ name: "Priced Pool" }))
+      .accountsPartial({
+        ngoAuthority: ngoAuthority.publicKey,
+        payer: ngoAuthority.publicKey,
+        tokenMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([ngoAuthority])
+      .rpc();
+
+    const config = await program.account.platformConfig.fetch(platformConfigPDA);
+    platformFeeRecipient = getAssociatedTokenAddressSync(tokenMint, config.platformFeeRecipient);
+    await createAssociatedTokenAccount(provider.connection, admin.payer, tokenMint, config.platformFeeRecipient);
+
+    donorTokenAccount = await createAssociatedTokenAccount(
+      provider.connection,
+      donor,
+      tokenMint,
+      donor.publicKey
+    );
+    await mintTo(provider.connection, admin.payer, tokenMint, donorTokenAccount, admin.payer, 100_000_000_000);
+  });
+
+  describe("price feed registration", () => {
+    it("should initialize the stand-in oracle", async () => {
+      await program.methods
+        .initializeStandInOracle({ price: new anchor.BN(tokenPrice), exponent: tokenExponent, publishTime: null })
+        .accountsPartial({
+          tokenMint,
+          admin: admin.publicKey,
+        })
+        .rpc();
+
+      const oracle = await program.account.standInOracle.fetch(priceOraclePDA);
+      expect(oracle.tokenMint.toString()).to.equal(tokenMint.toString());
+      expect(oracle.price.toNumber()).to.equal(tokenPrice);
+      expect(oracle.exponent).to.equal(tokenExponent);
+    });
+
+    it("should register a price feed for an allowed token", async () => {
+      await program.methods
+        .registerPriceFeed(
+          { oracle: priceOraclePDA, maxStalenessSeconds: new anchor.BN(300), reason: "Price priced token" }
+        )
+        .accountsPartial({
+          tokenMint,
+          admin: admin.publicKey,
+        })
+        .rpc();
+
+      const feed = await program.account.priceFeed.fetch(priceFeedPDA);
+      expect(feed.oracle.toString()).to.equal(priceOraclePDA.toString());
+      expect(feed.tokenDecimals).to.equal(9);
+      expect(feed.isActive).to.be.true;
+    });
+
+    it("should fail to register a feed for a token that is not allowed", async () => {
+      const otherMint = await createTokenMint(provider.connection, admin.payer, 6);
+
+      await expectError(
+        program.methods
+          .registerPriceFeed(
+            { oracle: priceOraclePDA, maxStalenessSeconds: new anchor.BN(300), reason: "Not allowed" }
+          )
+          .accountsPartial({
+            tokenMint: otherMint,
+            admin: admin.publicKey,
+          })
+          .rpc(),
+        "TokenNotInAllowedList"
+      );
+    });
+  });
+
+  describe("USD aggregates", () => {
+    it("should track USD value of non-USDC donations", async () => {
+      const configBefore = await program.account.platformConfig.fetch(platformConfigPDA);
+      const amount = 2_000_000_000; // 2 tokens
+
+      await donate(amount);
+
+      const pool = await program.account.fundPool.fetch(poolPDA);
+      const netAmount = pool.totalDeposited.toNumber();
+      expect(pool.totalDepositedUsd.toNumber()).to.equal(expectedUsd(netAmount));
+
+      const config = await program.account.platformConfig.fetch(platformConfigPDA);
+      expect(config.totalDonationsUsd.sub(configBefore.totalDonationsUsd).toNumber()).to.equal(
+        expectedUsd(netAmount)
+      );
+    });
+
+    it("should require a price feed for non-USDC donations", async () => {
+      await expectError(donate(1_000_000_000, false), "PriceFeedRequired");
+    });
+
+    it("should reject stale oracle prices", async () => {
+      const publishTime = Math.floor(Date.now() / 1000) - 3600;
+
+      await program.methods
+        .updateStandInOracle({
+          price: new anchor.BN(tokenPrice),
+          exponent: tokenExponent,
+          publishTime: new anchor.BN(publishTime),
+        })
+        .accountsPartial({
+          priceOracle: priceOraclePDA,
+          authority: admin.publicKey,
+        })
+        .rpc();
+
+      await expectError(donate(1_000_000_000), "StalePriceFeed");
+
+      await program.methods
+        .updateStandInOracle({ price: new anchor.BN(tokenPrice), exponent: tokenExponent, publishTime: null })
+        .accountsPartial({
+          priceOracle: priceOraclePDA,
+          authority: admin.publicKey,
+        })
+        .rpc();
+    });
+
+    it("should reject donations while the price feed is inactive", async () => {
+      await program.methods
+        .updatePriceFeed(
+          { oracle: null, maxStalenessSeconds: null, isActive: false, reason: "Pause pricing" }
+        )
+        .accountsPartial({
+          priceFeed: priceFeedPDA,
+          admin: admin.publicKey,
+        })
+        .rpc();
+
+      await expectError(donate(1_000_000_000), "PriceFeedInactive");
+
+      await program.methods
+        .updatePriceFeed(
+          { oracle: null, maxStalenessSeconds: null, isActive: true, reason: "Resume pricing" }
+        )
+        .accountsPartial({
+          priceFeed: priceFeedPDA,
+          admin: admin.publicKey,
+        })
+        .rpc();
+
+      await donate(1_000_000_000);
+    });
+  });
+});
@@ -17,6 +17,31 @@ export function createMockPlatformParams(usdcMint: PublicKey, overrides?: Partia
   };
 }
 
+/**
+ * Create mock params for platform config update (all fields unchanged unless overridden)
+ */
+export function createMockUpdateConfigParams(overrides?: Partial<any>): any {
+  return {
+    platformFeePercentage: null,
+    platformFeeRecipient: null,
+    verificationThreshold: null,
+    maxVerifiers: null,
+    minDonationAmount: null,
+    maxDonationAmount: null,
+    usdcMint: null,
+    isPaused: null,
+    solUsdOracle: null,
+    minClaimWindowSeconds: null,
+    maxClaimWindowSeconds: null,
+    locationDensityThreshold: null,
+    excludeRegisteringVerifier: null,
+    minVerifierNgos: null,
+    verificationWindowSeconds: null,
+    activityArchivalEnabled: null,
+    ...overrides,
+  };
+}
+
 /**
  * Create mock params for NGO registration
  */
@@ -120,5 +145,7 @@ export function createMockFundPoolParams(overrides?: Partial<any>): any {
     minimumDamageSeverity: overrides?.minimumDamageSeverity ?? null,
     targetAmount: overrides?.targetAmount ?? null,
     description: overrides?.description ?? "A test fund pool for disaster relief",
+    claimWindowSeconds: overrides?.claimWindowSeconds ?? null,
+    vestingSchedule: overrides?.vestingSchedule ?? [],
   };
 }
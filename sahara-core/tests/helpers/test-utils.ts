@@ -1,5 +1,25 @@
-import { Connection, PublicKey, Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
-import { TOKEN_PROGRAM_ID, createMint } from "@solana/spl-token";
+import * as anchor from "@coral-xyz/anchor";
+import {
+  Connection,
+  PublicKey,
+  Keypair,
+  LAMPORTS_PER_SOL,
+  SystemProgram,
+  Transaction,
+  TransactionInstruction,
+  sendAndConfirmTransaction,
+} from "@solana/web3.js";
+import {
+  TOKEN_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
+  ExtensionType,
+  createMint,
+  getMintLen,
+  createInitializeMint2Instruction,
+  createInitializePermanentDelegateInstruction,
+  createInitializeTransferFeeConfigInstruction,
+} from "@solana/spl-token";
+import { createHash } from "crypto";
 
 /**
  * Airdrop SOL to an account
@@ -17,6 +37,25 @@ export async function airdropSOL(
   });
 }
 
+/**
+ * Fetch a confirmed transaction and decode the program events it emitted
+ */
+export async function fetchEvents(
+  program: anchor.Program<any>,
+  signature: string
+): Promise<anchor.Event[]> {
+  const connection = program.provider.connection;
+  const latestBlockhash = await connection.getLatestBlockhash();
+  await connection.confirmTransaction({ signature, ...latestBlockhash }, "confirmed");
+
+  const tx = await connection.getTransaction(signature, {
+    commitment: "confirmed",
+    maxSupportedTransactionVersion: 0,
+  });
+  const parser = new anchor.EventParser(program.programId, new anchor.BorshCoder(program.idl));
+  return Array.from(parser.parseLogs(tx?.meta?.logMessages ?? []));
+}
+
 /**
  * Derive PlatformConfig PDA
  */
@@ -64,6 +103,80 @@ export async function createTokenMint(
   );
 }
 
+/**
+ * Create a Token-2022 mint with the given extension initialized ahead of the mint
+ */
+async function createToken2022Mint(
+  connection: Connection,
+  payer: Keypair,
+  extension: ExtensionType,
+  initializeExtension: (mint: PublicKey) => TransactionInstruction,
+  decimals: number
+): Promise<PublicKey> {
+  const mint = Keypair.generate();
+  const mintLen = getMintLen([extension]);
+  const lamports = await connection.getMinimumBalanceForRentExemption(mintLen);
+
+  const tx = new Transaction().add(
+    SystemProgram.createAccount({
+      fromPubkey: payer.publicKey,
+      newAccountPubkey: mint.publicKey,
+      space: mintLen,
+      lamports,
+      programId: TOKEN_2022_PROGRAM_ID,
+    }),
+    initializeExtension(mint.publicKey),
+    createInitializeMint2Instruction(mint.publicKey, decimals, payer.publicKey, null, TOKEN_2022_PROGRAM_ID)
+  );
+  await sendAndConfirmTransaction(connection, tx, [payer, mint]);
+
+  return mint.publicKey;
+}
+
+/**
+ * Create a Token-2022 mint that withholds a transfer fee on every transfer
+ */
+export async function createTransferFeeMint(
+  connection: Connection,
+  payer: Keypair,
+  feeBasisPoints: number,
+  maxFee: bigint,
+  decimals: number = 6
+): Promise<PublicKey> {
+  return createToken2022Mint(
+    connection,
+    payer,
+    ExtensionType.TransferFeeConfig,
+    (mint) =>
+      createInitializeTransferFeeConfigInstruction(
+        mint,
+        payer.publicKey,
+        payer.publicKey,
+        feeBasisPoints,
+        maxFee,
+        TOKEN_2022_PROGRAM_ID
+      ),
+    decimals
+  );
+}
+
+/**
+ * Create a Token-2022 mint with a permanent delegate
+ */
+export async function createPermanentDelegateMint(
+  connection: Connection,
+  payer: Keypair,
+  decimals: number = 6
+): Promise<PublicKey> {
+  return createToken2022Mint(
+    connection,
+    payer,
+    ExtensionType.PermanentDelegate,
+    (mint) => createInitializePermanentDelegateInstruction(mint, payer.publicKey, TOKEN_2022_PROGRAM_ID),
+    decimals
+  );
+}
+
 let timestampCounter = 0;
 
 /**
@@ -126,6 +239,84 @@ export function deriveNationalIdRegistryPDA(
   );
 }
 
+/**
+ * Derive Household Registry PDA
+ */
+export function deriveHouseholdRegistryPDA(
+  disasterId: string,
+  householdId: string,
+  programId: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("household-registry"), Buffer.from(disasterId), Buffer.from(householdId)],
+    programId
+  );
+}
+
+/**
+ * Derive Field Worker Assignment PDA
+ */
+export function deriveFieldWorkerAssignmentPDA(
+  fieldWorkerAuthority: PublicKey,
+  disasterId: string,
+  programId: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("field-worker-assignment"), fieldWorkerAuthority.toBuffer(), Buffer.from(disasterId)],
+    programId
+  );
+}
+
+/**
+ * Derive Location Registry PDA
+ */
+export function deriveLocationRegistryPDA(
+  disasterId: string,
+  geohash: string,
+  programId: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("location-registry"), Buffer.from(disasterId), Buffer.from(geohash)],
+    programId
+  );
+}
+
+/**
+ * Encode coordinates as a geohash (matches Location::geohash on-chain)
+ */
+export function encodeGeohash(latitude: number, longitude: number, precision: number = 7): string {
+  const alphabet = "0123456789bcdefghjkmnpqrstuvwxyz";
+  const latRange = [-90, 90];
+  const lonRange = [-180, 180];
+  let hash = "";
+  let isLon = true;
+  let bit = 0;
+  let index = 0;
+
+  while (hash.length < precision) {
+    const range = isLon ? lonRange : latRange;
+    const value = isLon ? longitude : latitude;
+    const mid = (range[0] + range[1]) / 2;
+    index <<= 1;
+    if (value >= mid) {
+      index |= 1;
+      range[0] = mid;
+    } else {
+      range[1] = mid;
+    }
+    isLon = !isLon;
+
+    bit++;
+    if (bit === 5) {
+      hash += alphabet[index];
+      bit = 0;
+      index = 0;
+    }
+  }
+
+  return hash;
+}
+
 /**
  * Derive Fund Pool PDA
  */
@@ -168,6 +359,83 @@ export function derivePoolRegistrationPDA(
   );
 }
 
+/**
+ * Derive ActivityLog PDA from its stream account and on-chain sequence number
+ */
+export function deriveActivityLogPDA(
+  stream: PublicKey,
+  sequence: anchor.BN | number,
+  programId: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("activity"), stream.toBuffer(), new anchor.BN(sequence).toArrayLike(Buffer, "le", 8)],
+    programId
+  );
+}
+
+/**
+ * Derive AdminAction PDA from the acting admin and the platform's admin action count
+ */
+export function deriveAdminActionPDA(
+  admin: PublicKey,
+  sequence: anchor.BN | number,
+  programId: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("admin-action"), admin.toBuffer(), new anchor.BN(sequence).toArrayLike(Buffer, "le", 8)],
+    programId
+  );
+}
+
+/**
+ * Derive Activity Archive PDA
+ */
+export function deriveActivityArchivePDA(actor: PublicKey, programId: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([Buffer.from("activity-archive"), actor.toBuffer()], programId);
+}
+
+/**
+ * Derive Price Feed PDA
+ */
+export function derivePriceFeedPDA(tokenMint: PublicKey, programId: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([Buffer.from("price-feed"), tokenMint.toBuffer()], programId);
+}
+
+/**
+ * Derive Stand-in Oracle PDA
+ */
+export function deriveStandInOraclePDA(tokenMint: PublicKey, programId: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([Buffer.from("stand-in-oracle"), tokenMint.toBuffer()], programId);
+}
+
+/**
+ * Derive Aid Card Mint PDA
+ */
+export function deriveAidCardMintPDA(beneficiary: PublicKey, programId: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([Buffer.from("aid-card"), beneficiary.toBuffer()], programId);
+}
+
+/**
+ * Derive Token Stats PDA
+ */
+export function deriveTokenStatsPDA(tokenMint: PublicKey, programId: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([Buffer.from("token-stats"), tokenMint.toBuffer()], programId);
+}
+
+/**
+ * Derive Disaster Token Stats PDA
+ */
+export function deriveDisasterTokenStatsPDA(
+  disasterId: string,
+  tokenMint: PublicKey,
+  programId: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("disaster-token-stats"), Buffer.from(disasterId), tokenMint.toBuffer()],
+    programId
+  );
+}
+
 /**
  * Derive Distribution PDA
  */
@@ -181,3 +449,26 @@ export function deriveDistributionPDA(
     programId
   );
 }
+
+/**
+ * Derive DonationRecord PDA (donor seed is the donor key, or the donor commitment for anonymous donations)
+ */
+export function deriveDonationRecordPDA(
+  donorSeed: PublicKey | Buffer,
+  recipient: PublicKey,
+  timestamp: anchor.BN | number,
+  programId: PublicKey
+): [PublicKey, number] {
+  const seed = donorSeed instanceof PublicKey ? donorSeed.toBuffer() : donorSeed;
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("donation"), seed, recipient.toBuffer(), new anchor.BN(timestamp).toArrayLike(Buffer, "le", 8)],
+    programId
+  );
+}
+
+/**
+ * Compute the salted donor commitment stored on anonymous donation records
+ */
+export function createDonorCommitment(donor: PublicKey, salt: Buffer): Buffer {
+  return createHash("sha256").update(Buffer.from("sahara-donor")).update(donor.toBuffer()).update(salt).digest();
+}